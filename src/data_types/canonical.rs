@@ -0,0 +1,91 @@
+use serde::Serialize;
+
+use crate::error::ConversionError;
+use crate::utils::hash::SHA256;
+use crate::utils::jcs;
+
+use super::cred_def::CredentialDefinition;
+use super::rev_reg_def::RevocationRegistryDefinition;
+use super::rev_status_list::RevocationStatusList;
+use super::schema::Schema;
+
+/// Deterministic representation of objects published by issuers
+///
+/// The canonical JSON is independent of map ordering and formatting, so it can be signed, and
+/// its digest used as a stable identifier of the content, e.g. as the name of a `did:web` or
+/// cheqd resource.
+pub trait CanonicalJson: Serialize {
+    /// Serialize following the JSON Canonicalization Scheme
+    /// ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785))
+    fn to_canonical_json(&self) -> Result<Vec<u8>, ConversionError> {
+        jcs::to_vec(self)
+    }
+
+    /// Lowercase hex encoded SHA-256 digest of the canonical JSON
+    fn content_id(&self) -> Result<String, ConversionError> {
+        let digest = SHA256::digest(self.to_canonical_json()?);
+        Ok(digest.iter().map(|b| format!("{b:02x}")).collect())
+    }
+}
+
+impl CanonicalJson for Schema {}
+
+impl CanonicalJson for CredentialDefinition {}
+
+impl CanonicalJson for RevocationRegistryDefinition {}
+
+impl CanonicalJson for RevocationStatusList {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_id_ignores_formatting() {
+        let schema: Schema = serde_json::from_str(
+            r#"{"name":"gvt","version":"1.0","attrNames":["name","age"],"issuerId":"mock:uri"}"#,
+        )
+        .unwrap();
+        let reordered: Schema = serde_json::from_str(
+            r#"{ "issuerId": "mock:uri", "attrNames": ["name", "age"], "version": "1.0", "name": "gvt" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schema.to_canonical_json().unwrap(),
+            br#"{"attrNames":["name","age"],"issuerId":"mock:uri","name":"gvt","version":"1.0"}"#
+        );
+        assert_eq!(
+            schema.content_id().unwrap(),
+            reordered.content_id().unwrap()
+        );
+        assert_eq!(schema.content_id().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_cred_def_content_id_is_stable() {
+        let schema = crate::issuer::create_schema(
+            "gvt",
+            "1.0",
+            "mock:uri".try_into().unwrap(),
+            vec!["name".to_owned(), "age".to_owned(), "sex".to_owned()].into(),
+        )
+        .unwrap();
+        let (cred_def, _, _) = crate::issuer::create_credential_definition(
+            "mock:uri".try_into().unwrap(),
+            &schema,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            crate::types::SignatureType::CL,
+            crate::types::CredentialDefinitionConfig::default(),
+        )
+        .unwrap();
+        let reloaded: CredentialDefinition =
+            serde_json::from_slice(&serde_json::to_vec(&cred_def).unwrap()).unwrap();
+
+        assert_eq!(
+            cred_def.content_id().unwrap(),
+            reloaded.content_id().unwrap()
+        );
+    }
+}
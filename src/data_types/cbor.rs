@@ -0,0 +1,98 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::ConversionError;
+
+/// Encode a data type as CBOR ([RFC 8949](https://www.rfc-editor.org/rfc/rfc8949))
+///
+/// The encoding follows the serde representation of the type, so that any value decodes back to
+/// the same value as when going through JSON. This includes big numbers of keys, proofs and
+/// signatures, which stay encoded as decimal or hexadecimal text strings rather than as byte
+/// strings. Compared to JSON, the only saving is in the structure (map keys, integers and
+/// delimiters), so the size of cryptographic objects is reduced only slightly.
+pub fn to_cbor<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, ConversionError> {
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(value, &mut bytes)
+        .map_err(|err| ConversionError::from_msg(format!("Error encoding CBOR: {err}")))?;
+    Ok(bytes)
+}
+
+/// Decode a data type from CBOR
+pub fn from_cbor<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ConversionError> {
+    ciborium::de::from_reader(bytes)
+        .map_err(|err| ConversionError::from_msg(format!("Error decoding CBOR: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::cred_def::CredentialDefinition;
+    use crate::data_types::pres_request::PresentationRequest;
+    use crate::data_types::rev_status_list::RevocationStatusList;
+    use crate::issuer;
+    use crate::types::{CredentialDefinitionConfig, SignatureType};
+
+    fn assert_round_trip<T: Serialize + DeserializeOwned>(value: &T) {
+        let bytes = to_cbor(value).unwrap();
+        let decoded: T = from_cbor(&bytes).unwrap();
+        assert_eq!(
+            serde_json::to_value(value).unwrap(),
+            serde_json::to_value(&decoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cbor_round_trip() {
+        let schema = issuer::create_schema(
+            "name",
+            "1.0",
+            "mock:uri".try_into().unwrap(),
+            vec!["name".to_owned(), "age".to_owned()].into(),
+        )
+        .unwrap();
+        let (cred_def, cred_def_priv, key_correctness_proof) =
+            issuer::create_credential_definition(
+                "mock:uri".try_into().unwrap(),
+                &schema,
+                "mock:uri".try_into().unwrap(),
+                "tag",
+                SignatureType::CL,
+                CredentialDefinitionConfig::default(),
+            )
+            .unwrap();
+        let cred_offer = issuer::create_credential_offer(
+            "mock:uri".try_into().unwrap(),
+            "mock:uri".try_into().unwrap(),
+            &key_correctness_proof,
+        )
+        .unwrap();
+        let pres_request: PresentationRequest = serde_json::from_value(json!({
+            "nonce": "1234567890",
+            "name": "pres_req",
+            "version": "0.1",
+            "requested_attributes": {"attr1_referent": {"name": "name"}},
+            "requested_predicates": {
+                "predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18,
+                    "restrictions": {"cred_def_id": "mock:uri"}}
+            },
+        }))
+        .unwrap();
+        let rev_status_list: RevocationStatusList = serde_json::from_value(json!({
+            "issuerId": "mock:uri",
+            "revRegDefId": "mock:uri",
+            "revocationList": [0, 1, 1, 0],
+            "currentAccumulator": "1 1379509F4D411630D308A5ABB4F422FCE6737B330B1C5FD286AA5C26F2061E60 1 235535CC45D4816C7686C5A402A230B35A62DDE82B4A652E384FD31912C4E4BB 1 0C94B61595FCAEFC892BB98A27D524C97ED0B7ED1CC49AD6F178A59D4199C9A4 1 172482285606DEE8500FC8A13E6A35EC071F8B84F0EB4CD3DD091C0B4CD30E5E 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000",
+            "timestamp": 1234,
+        }))
+        .unwrap();
+
+        assert_round_trip(&schema);
+        assert_round_trip(&cred_def);
+        assert_round_trip(&cred_def_priv);
+        assert_round_trip(&key_correctness_proof);
+        assert_round_trip(&cred_offer);
+        assert_round_trip(&pres_request);
+        assert_round_trip(&rev_status_list);
+
+        assert!(from_cbor::<CredentialDefinition>(&[0xff]).is_err());
+    }
+}
@@ -5,14 +5,140 @@ use crate::cl::{
     CredentialPrimaryPublicKey, CredentialPrivateKey, CredentialPublicKey,
     CredentialRevocationPublicKey,
 };
-use crate::{error::ConversionError, impl_anoncreds_object_identifier};
+use crate::utils::did_indy;
+use crate::{error::ConversionError, impl_anoncreds_object_identifier, invalid};
 
-use super::{issuer_id::IssuerId, schema::SchemaId};
+use super::{
+    issuer_id::IssuerId,
+    schema::{LegacySchemaId, SchemaId},
+};
 
 pub const CL_SIGNATURE_TYPE: &str = "CL";
 
+/// Separator between the base tag and the version of a rotated credential definition tag
+pub const ROTATION_TAG_SEPARATOR: &str = "-v";
+
 impl_anoncreds_object_identifier!(CredentialDefinitionId);
 
+/// Schema reference of a legacy credential definition identifier
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LegacySchemaRef {
+    /// Ledger sequence number of the schema transaction
+    SeqNo(u64),
+    SchemaId(LegacySchemaId),
+}
+
+/// Parts of a legacy credential definition identifier `<did>:3:CL:<schema ref>:<tag>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegacyCredentialDefinitionId {
+    pub did: IssuerId,
+    pub signature_type: SignatureType,
+    pub schema_ref: LegacySchemaRef,
+    /// Empty for identifiers without a tag
+    pub tag: String,
+}
+
+impl CredentialDefinitionId {
+    /// Decompose a legacy credential definition identifier into its parts
+    pub fn parse_legacy(&self) -> Result<LegacyCredentialDefinitionId, ValidationError> {
+        let caps = LEGACY_CRED_DEF_IDENTIFIER
+            .captures(&self.0)
+            .ok_or_else(|| {
+                invalid!(
+                    "{} is not a legacy credential definition identifier",
+                    self.0
+                )
+            })?;
+
+        let schema_ref = if let Some(seq_no) = caps.name("seq_no") {
+            LegacySchemaRef::SeqNo(
+                seq_no
+                    .as_str()
+                    .parse()
+                    .map_err(|_| invalid!("Invalid schema sequence number in {}", self.0))?,
+            )
+        } else {
+            LegacySchemaRef::SchemaId(SchemaId::new_unchecked(&caps["schema_id"]).parse_legacy()?)
+        };
+
+        Ok(LegacyCredentialDefinitionId {
+            did: IssuerId::new_unchecked(&caps["did"]),
+            signature_type: SignatureType::from_str(&caps["signature_type"])
+                .map_err(|e| invalid!("{}", e))?,
+            schema_ref,
+            tag: caps.name("tag").map_or("", |tag| tag.as_str()).to_owned(),
+        })
+    }
+}
+
+impl CredentialDefinitionId {
+    /// Convert a legacy credential definition identifier to
+    /// `did:indy:<namespace>:<did>/anoncreds/v0/CLAIM_DEF/<schema seq_no>/<tag>`
+    ///
+    /// Only identifiers referencing the schema by its sequence number can be converted.
+    pub fn to_did_indy(&self, namespace: &str) -> Result<Self, ValidationError> {
+        let legacy = self.parse_legacy()?;
+        let seq_no = match legacy.schema_ref {
+            LegacySchemaRef::SeqNo(seq_no) => seq_no,
+            _ => {
+                return Err(invalid!(
+                    "{} does not reference the schema by sequence number",
+                    self.0
+                ))
+            }
+        };
+        Ok(Self(did_indy::format(
+            namespace,
+            &legacy.did.0,
+            Some((
+                did_indy::CRED_DEF_OBJECT,
+                &[&seq_no.to_string(), &legacy.tag],
+            )),
+        )?))
+    }
+
+    /// Convert a `did:indy` credential definition identifier to a legacy identifier. Legacy
+    /// identifiers are returned as is.
+    pub fn to_legacy(&self) -> Result<Self, ValidationError> {
+        if self.is_legacy_cred_def_identifier() {
+            return Ok(self.clone());
+        }
+        let parsed = did_indy::parse_object(&self.0, did_indy::CRED_DEF_OBJECT, 2)?;
+        let (_, segments) = parsed.object.unwrap_or_default();
+        let legacy = LegacyCredentialDefinitionId {
+            did: IssuerId::new_unchecked(parsed.did),
+            signature_type: SignatureType::CL,
+            schema_ref: LegacySchemaRef::SeqNo(
+                segments[0]
+                    .parse()
+                    .map_err(|_| invalid!("Invalid schema sequence number in {}", self.0))?,
+            ),
+            tag: segments[1].to_owned(),
+        };
+        Ok(Self::from(&legacy))
+    }
+}
+
+impl std::fmt::Display for LegacyCredentialDefinitionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let signature_type = match self.signature_type {
+            SignatureType::CL => CL_SIGNATURE_TYPE,
+        };
+        write!(f, "{}:3:{}:", self.did, signature_type)?;
+        match &self.schema_ref {
+            LegacySchemaRef::SeqNo(seq_no) => write!(f, "{seq_no}")?,
+            LegacySchemaRef::SchemaId(schema_id) => write!(f, "{schema_id}")?,
+        }
+        write!(f, ":{}", self.tag)
+    }
+}
+
+impl From<&LegacyCredentialDefinitionId> for CredentialDefinitionId {
+    fn from(id: &LegacyCredentialDefinitionId) -> Self {
+        Self(id.to_string())
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignatureType {
     CL,
@@ -82,6 +208,96 @@ impl Validatable for CredentialDefinition {
     }
 }
 
+/// Derive the tag of a rotated credential definition from the tag of the one it replaces
+///
+/// `tag` becomes `tag-v2`, and an existing version suffix is incremented, so `tag-v1` becomes
+/// `tag-v2` and `tag-v2` becomes `tag-v3`.
+pub fn rotated_tag(tag: &str) -> String {
+    if let Some((base, version)) = tag.rsplit_once(ROTATION_TAG_SEPARATOR) {
+        let canonical = !version.starts_with('0') && version.chars().all(|c| c.is_ascii_digit());
+        match version.parse::<u32>() {
+            Ok(version) if canonical && version >= 1 => {
+                return format!("{base}{ROTATION_TAG_SEPARATOR}{}", version + 1);
+            }
+            _ => (),
+        }
+    }
+    format!("{tag}{ROTATION_TAG_SEPARATOR}2")
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RotationReason {
+    /// The private key of the superseded credential definition is (possibly) compromised
+    KeyCompromise,
+    /// Regular key rotation, the superseded credential definition is still trustworthy
+    Scheduled,
+}
+
+/// Policy applied by a verifier to credentials issued under a superseded credential definition
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SupersededPolicy {
+    /// Accept all superseded credential definitions
+    Accept,
+    /// Reject credential definitions superseded because of a key compromise
+    RejectCompromised,
+    /// Reject all superseded credential definitions
+    Reject,
+}
+
+impl Default for SupersededPolicy {
+    fn default() -> Self {
+        Self::RejectCompromised
+    }
+}
+
+/// Machine readable record linking a rotated credential definition to the one it replaces
+///
+/// The identifier of the new credential definition is assigned by the VDR, so the record is
+/// keyed by the superseded identifier and the new tag.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialDefinitionSupersedes {
+    pub supersedes: CredentialDefinitionId,
+    pub schema_id: SchemaId,
+    pub issuer_id: IssuerId,
+    pub previous_tag: String,
+    pub tag: String,
+    pub reason: RotationReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+impl CredentialDefinitionSupersedes {
+    /// Whether credentials issued under the superseded credential definition are still accepted
+    #[must_use]
+    pub fn is_accepted(&self, policy: SupersededPolicy) -> bool {
+        match policy {
+            SupersededPolicy::Accept => true,
+            SupersededPolicy::RejectCompromised => self.reason != RotationReason::KeyCompromise,
+            SupersededPolicy::Reject => false,
+        }
+    }
+}
+
+impl Validatable for CredentialDefinitionSupersedes {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.supersedes.validate()?;
+        self.schema_id.validate()?;
+        self.issuer_id.validate()?;
+
+        if self.tag == self.previous_tag {
+            return Err(invalid!(
+                "Rotated credential definition must not reuse the tag {}",
+                self.tag
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CredentialDefinitionPrivate {
     pub value: CredentialPrivateKey,
@@ -171,6 +387,97 @@ mod test_cred_def {
         assert!(key_correctness_proof.try_clone().is_ok());
     }
 
+    #[test]
+    fn should_parse_legacy_cred_def_id() {
+        let cred_def_id =
+            CredentialDefinitionId::new("DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default").unwrap();
+        let legacy = cred_def_id.parse_legacy().unwrap();
+
+        assert_eq!(legacy.did.0, "DXoTtQJNtXtiwWaZAK3rB1");
+        assert_eq!(legacy.signature_type, SignatureType::CL);
+        assert_eq!(legacy.schema_ref, LegacySchemaRef::SeqNo(98153));
+        assert_eq!(legacy.tag, "default");
+        assert_eq!(CredentialDefinitionId::from(&legacy), cred_def_id);
+
+        let cred_def_id = CredentialDefinitionId::new(
+            "DXoTtQJNtXtiwWaZAK3rB1:3:CL:DXoTtQJNtXtiwWaZAK3rB1:2:gvt:1.0:default",
+        )
+        .unwrap();
+        let legacy = cred_def_id.parse_legacy().unwrap();
+
+        match &legacy.schema_ref {
+            LegacySchemaRef::SchemaId(schema_id) => {
+                assert_eq!(schema_id.name, "gvt");
+                assert_eq!(schema_id.version, "1.0");
+            }
+            other => panic!("Unexpected schema reference {other:?}"),
+        }
+        assert_eq!(legacy.tag, "default");
+        assert_eq!(CredentialDefinitionId::from(&legacy), cred_def_id);
+
+        assert!(
+            CredentialDefinitionId::new_unchecked("did:web:xyz/resource/cred-def")
+                .parse_legacy()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_convert_cred_def_id_to_did_indy() {
+        let legacy =
+            CredentialDefinitionId::new("DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default").unwrap();
+        let did_indy = legacy.to_did_indy("sovrin").unwrap();
+
+        assert_eq!(
+            did_indy.0,
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/CLAIM_DEF/98153/default"
+        );
+        assert_eq!(did_indy.to_legacy().unwrap(), legacy);
+
+        assert!(CredentialDefinitionId::new_unchecked(
+            "DXoTtQJNtXtiwWaZAK3rB1:3:CL:DXoTtQJNtXtiwWaZAK3rB1:2:gvt:1.0:default"
+        )
+        .to_did_indy("sovrin")
+        .is_err());
+    }
+
+    #[test]
+    fn should_derive_rotated_tag() {
+        assert_eq!(rotated_tag("default-tag"), "default-tag-v2");
+        assert_eq!(rotated_tag("default-tag-v2"), "default-tag-v3");
+        assert_eq!(rotated_tag("default-tag-v19"), "default-tag-v20");
+        assert_eq!(rotated_tag("tag-v1"), "tag-v2");
+        assert_eq!(rotated_tag("tag-v0"), "tag-v0-v2");
+        assert_eq!(rotated_tag("tag-v02"), "tag-v02-v2");
+        assert_eq!(rotated_tag("tag-vx"), "tag-vx-v2");
+    }
+
+    #[test]
+    fn should_apply_superseded_policy() {
+        let mut record = CredentialDefinitionSupersedes {
+            supersedes: "did:example/cred-def".try_into().unwrap(),
+            schema_id: "did:example/schema".try_into().unwrap(),
+            issuer_id: "did:example".try_into().unwrap(),
+            previous_tag: "default-tag".to_owned(),
+            tag: "default-tag-v2".to_owned(),
+            reason: RotationReason::Scheduled,
+            timestamp: None,
+        };
+
+        assert!(record.validate().is_ok());
+        assert!(record.is_accepted(SupersededPolicy::Accept));
+        assert!(record.is_accepted(SupersededPolicy::RejectCompromised));
+        assert!(!record.is_accepted(SupersededPolicy::Reject));
+
+        record.reason = RotationReason::KeyCompromise;
+        assert!(record.is_accepted(SupersededPolicy::Accept));
+        assert!(!record.is_accepted(SupersededPolicy::RejectCompromised));
+        assert!(!record.is_accepted(SupersededPolicy::Reject));
+
+        record.tag = record.previous_tag.clone();
+        assert!(record.validate().is_err());
+    }
+
     #[test]
     fn should_create_cred_def_from_json() {
         let json = serde_json::json!({
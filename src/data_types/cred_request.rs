@@ -75,6 +75,11 @@ impl CredentialRequest {
         Ok(s)
     }
 
+    #[must_use]
+    pub const fn cred_def_id(&self) -> &CredentialDefinitionId {
+        &self.cred_def_id
+    }
+
     pub fn entropy(&self) -> Result<String> {
         self.entropy.clone().map_or_else(
             || {
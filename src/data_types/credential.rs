@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -46,6 +46,37 @@ impl Credential {
             witness: self.witness.clone(),
         })
     }
+
+    /// The names of the attributes in the credential, sorted
+    #[must_use]
+    pub fn attribute_names(&self) -> Vec<&str> {
+        let mut names = self.values.0.keys().map(String::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// The raw and encoded value of an attribute
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&AttributeValues> {
+        self.values.0.get(name)
+    }
+
+    /// The identifiers and raw attribute values of the credential, without the signature
+    #[must_use]
+    pub fn summary(&self) -> CredentialSummary {
+        CredentialSummary {
+            schema_id: self.schema_id.clone(),
+            cred_def_id: self.cred_def_id.clone(),
+            rev_reg_id: self.rev_reg_id.clone(),
+            rev_reg_index: self.signature.extract_index(),
+            attrs: self
+                .values
+                .0
+                .iter()
+                .map(|(name, value)| (name.clone(), value.raw.clone()))
+                .collect(),
+        }
+    }
 }
 
 impl Validatable for Credential {
@@ -82,6 +113,18 @@ pub struct CredentialInfo {
 
 pub type ShortCredentialValues = HashMap<String, String>;
 
+/// A view of a [`Credential`] which is safe to display, omitting the signature
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CredentialSummary {
+    pub schema_id: SchemaId,
+    pub cred_def_id: CredentialDefinitionId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rev_reg_id: Option<RevocationRegistryDefinitionId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rev_reg_index: Option<u32>,
+    pub attrs: BTreeMap<String, String>,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CredentialValues(pub HashMap<String, AttributeValues>);
 
@@ -1,11 +1,97 @@
 use crate::impl_anoncreds_object_identifier;
+use crate::utils::did_indy;
+
+pub use crate::utils::validation::DidMethods;
 
 impl_anoncreds_object_identifier!(IssuerId);
 
+impl IssuerId {
+    /// Convert a legacy DID to `did:indy:<namespace>:<did>`
+    pub fn to_did_indy(&self, namespace: &str) -> Result<Self, ValidationError> {
+        if !self.is_legacy_did_identifier() {
+            return Err(crate::invalid!("{} is not a legacy DID", self.0));
+        }
+        Ok(Self(did_indy::format(namespace, &self.0, None)?))
+    }
+
+    /// Convert `did:indy:<namespace>:<did>` to a legacy DID. Legacy DIDs are returned as is.
+    pub fn to_legacy(&self) -> Result<Self, ValidationError> {
+        if self.is_legacy_did_identifier() {
+            return Ok(self.clone());
+        }
+        let parsed = did_indy::parse(&self.0)?;
+        if parsed.object.is_some() {
+            return Err(crate::invalid!("{} is not a did:indy DID", self.0));
+        }
+        Ok(Self(parsed.did.to_owned()))
+    }
+
+    /// Namespace of a `did:indy` identifier
+    pub fn did_indy_namespace(&self) -> Option<&str> {
+        did_indy::parse(&self.0).ok().map(|parsed| parsed.namespace)
+    }
+}
+
 #[cfg(test)]
 mod test_issuer_identifiers {
     use super::*;
 
+    #[test]
+    fn should_convert_between_legacy_and_did_indy() {
+        let legacy = IssuerId::new("DXoTtQJNtXtiwWaZAK3rB1").unwrap();
+        let did_indy = legacy.to_did_indy("sovrin:staging").unwrap();
+
+        assert_eq!(did_indy.0, "did:indy:sovrin:staging:DXoTtQJNtXtiwWaZAK3rB1");
+        assert!(did_indy.validate().is_ok());
+        assert_eq!(did_indy.did_indy_namespace(), Some("sovrin:staging"));
+        assert_eq!(did_indy.to_legacy().unwrap(), legacy);
+        assert_eq!(legacy.to_legacy().unwrap(), legacy);
+
+        assert!(did_indy.to_did_indy("sovrin").is_err());
+        assert!(IssuerId::new_unchecked("did:web:example.com")
+            .to_legacy()
+            .is_err());
+    }
+
+    #[test]
+    fn should_validate_did_syntax() {
+        assert!(IssuerId::new("did:web:example.com")
+            .unwrap()
+            .validate_did()
+            .is_ok());
+        assert!(IssuerId::new("did:example")
+            .unwrap()
+            .validate_did()
+            .is_err());
+        assert!(IssuerId::new("mock:uri").unwrap().validate_did().is_err());
+        assert!(IssuerId::new("did:web:exa mple.com").is_ok());
+        assert!(IssuerId::new("did:web:exa mple.com")
+            .unwrap()
+            .validate_strict()
+            .is_err());
+        assert!(IssuerId::new("did:web:example.com")
+            .unwrap()
+            .validate_strict()
+            .is_ok());
+    }
+
+    #[test]
+    fn should_validate_did_methods() {
+        let methods = DidMethods::try_from(&["indy"][..]).unwrap();
+        let did_web = IssuerId::new("did:web:example.com").unwrap();
+        assert!(did_web.validate_strict_with_methods(&methods).is_err());
+        assert!(did_web.validate().is_ok());
+        assert!(did_web.validate_strict().is_ok());
+        assert!(IssuerId::new("did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1")
+            .unwrap()
+            .validate_strict_with_methods(&methods)
+            .is_ok());
+        assert!(IssuerId::new("DXoTtQJNtXtiwWaZAK3rB1")
+            .unwrap()
+            .validate_strict_with_methods(&methods)
+            .is_ok());
+    }
+
     #[test]
     fn should_validate_new_and_legacy_identifiers() {
         let valid_uri_identifier_1 = "did:uri:new";
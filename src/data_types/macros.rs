@@ -4,7 +4,7 @@ macro_rules! impl_anoncreds_object_identifier {
         use $crate::error::ValidationError;
         use $crate::utils::validation::{
             Validatable, LEGACY_CRED_DEF_IDENTIFIER, LEGACY_DID_IDENTIFIER,
-            LEGACY_SCHEMA_IDENTIFIER, URI_IDENTIFIER,
+            LEGACY_REV_REG_DEF_IDENTIFIER, LEGACY_SCHEMA_IDENTIFIER, URI_IDENTIFIER,
         };
 
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize, Default)]
@@ -33,26 +33,73 @@ macro_rules! impl_anoncreds_object_identifier {
                 LEGACY_SCHEMA_IDENTIFIER.captures(&self.0).is_some()
             }
 
+            pub fn is_legacy_rev_reg_def_identifier(&self) -> bool {
+                LEGACY_REV_REG_DEF_IDENTIFIER.captures(&self.0).is_some()
+            }
+
+            fn legacy_regex() -> Result<&'static regex::Regex, ValidationError> {
+                match stringify!($i) {
+                    "IssuerId" => Ok(&LEGACY_DID_IDENTIFIER),
+                    "CredentialDefinitionId" => Ok(&LEGACY_CRED_DEF_IDENTIFIER),
+                    "SchemaId" => Ok(&LEGACY_SCHEMA_IDENTIFIER),
+                    "RevocationRegistryDefinitionId" => Ok(&LEGACY_REV_REG_DEF_IDENTIFIER),
+                    invalid_name => Err($crate::invalid!(
+                        "type: {} does not have a validation regex",
+                        invalid_name,
+                    )),
+                }
+            }
+
             pub fn is_uri(&self) -> bool {
                 URI_IDENTIFIER.captures(&self.0).is_some()
             }
+
+            pub fn is_did(&self) -> bool {
+                $crate::utils::validation::is_did_identifier(&self.0)
+            }
+
+            /// Validate the identifier as a DID (URL)
+            pub fn validate_did(&self) -> Result<(), ValidationError> {
+                $crate::utils::validation::validate_did(
+                    &self.0,
+                    &$crate::utils::validation::DidMethods::new(),
+                )
+            }
+
+            /// Validate the identifier as a legacy identifier or as a URI following
+            /// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-3), which
+            /// must follow the DID syntax when using the `did` scheme
+            pub fn validate_strict(&self) -> Result<(), ValidationError> {
+                self.validate_strict_with_methods(&$crate::utils::validation::DidMethods::new())
+            }
+
+            /// Validate the identifier as `validate_strict` does, also requiring DIDs to use one
+            /// of `methods` unless it is empty
+            pub fn validate_strict_with_methods(
+                &self,
+                methods: &$crate::utils::validation::DidMethods,
+            ) -> Result<(), ValidationError> {
+                if Self::legacy_regex()?.captures(&self.0).is_some() {
+                    return Ok(());
+                }
+                if $crate::utils::validation::is_strict_uri_identifier(&self.0) {
+                    if self.0.starts_with("did:") {
+                        return $crate::utils::validation::validate_did(&self.0, methods);
+                    }
+                    return Ok(());
+                }
+
+                Err($crate::invalid!(
+                    "type: {}, identifier: {} is not a valid URI",
+                    stringify!($i),
+                    self.0
+                ))
+            }
         }
 
         impl Validatable for $i {
             fn validate(&self) -> Result<(), ValidationError> {
-                let legacy_regex = match stringify!($i) {
-                    "IssuerId" => &LEGACY_DID_IDENTIFIER,
-                    "CredentialDefinitionId" => &LEGACY_CRED_DEF_IDENTIFIER,
-                    "SchemaId" => &LEGACY_SCHEMA_IDENTIFIER,
-                    // TODO: we do not have correct validation for a revocation registry definition id
-                    "RevocationRegistryDefinitionId" => &LEGACY_DID_IDENTIFIER,
-                    invalid_name => {
-                        return Err($crate::invalid!(
-                            "type: {} does not have a validation regex",
-                            invalid_name,
-                        ))
-                    }
-                };
+                let legacy_regex = Self::legacy_regex()?;
 
                 if $crate::utils::validation::URI_IDENTIFIER
                     .captures(&self.0)
@@ -39,3 +39,10 @@ pub mod macros;
 
 /// Identifier wrapper for the issuer
 pub mod issuer_id;
+
+/// Canonical JSON and content identifiers of ledger objects
+pub mod canonical;
+
+/// Binary CBOR encoding of the data types
+#[cfg(feature = "cbor")]
+pub mod cbor;
@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
+use regex::Regex;
 use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
@@ -22,7 +23,102 @@ pub struct PresentationRequestPayload {
     pub requested_attributes: HashMap<String, AttributeInfo>,
     #[serde(default)]
     pub requested_predicates: HashMap<String, PredicateInfo>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub requested_ranges: HashMap<String, RangePredicateInfo>,
+    /// Names of attributes that must hold the same hidden value in every credential of the
+    /// presentation, in addition to the link secret.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub common_attributes: Vec<String>,
     pub non_revoked: Option<NonRevokedInterval>,
+    /// Timestamp after which the request can no longer be answered (version 2.0 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Identity of the verifier and purpose of the request (version 2.0 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifier: Option<VerifierInfo>,
+    /// Minimum number of credentials the presentation must be built from (version 2.0 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_credentials: Option<u32>,
+    /// Whether the prover may keep requested attributes hidden (version 2.0 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disclosure_policy: Option<DisclosurePolicy>,
+}
+
+impl PresentationRequestPayload {
+    /// The predicates requested for a predicate or range referent. A range expands into its
+    /// lower and upper bound predicates.
+    #[must_use]
+    pub fn predicates_for_referent(&self, referent: &str) -> Option<Vec<PredicateInfo>> {
+        if let Some(predicate) = self.requested_predicates.get(referent) {
+            Some(vec![predicate.clone()])
+        } else {
+            self.requested_ranges
+                .get(referent)
+                .map(|range| range.predicates().to_vec())
+        }
+    }
+
+    /// The referents of the requested predicates and ranges
+    pub fn predicate_referents(&self) -> impl Iterator<Item = &String> {
+        self.requested_predicates
+            .keys()
+            .chain(self.requested_ranges.keys())
+    }
+
+    /// Whether the prover may omit the attribute, predicate or range with this referent
+    #[must_use]
+    pub fn is_optional(&self, referent: &str) -> bool {
+        self.requested_attributes
+            .get(referent)
+            .map(|info| info.optional)
+            .or_else(|| {
+                self.requested_predicates
+                    .get(referent)
+                    .map(|info| info.optional)
+            })
+            .or_else(|| {
+                self.requested_ranges
+                    .get(referent)
+                    .map(|info| info.optional)
+            })
+            .unwrap_or(false)
+    }
+
+    #[must_use]
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.map_or(false, |expires_at| now > expires_at)
+    }
+
+    #[must_use]
+    pub fn disclosure_policy(&self) -> DisclosurePolicy {
+        self.disclosure_policy.clone().unwrap_or_default()
+    }
+}
+
+/// Verifier metadata shown to the holder when answering a request
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VerifierInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisclosurePolicy {
+    /// The prover chooses which requested attributes are revealed
+    Selective,
+    /// All requested attributes must be revealed or self-attested
+    RevealAll,
+}
+
+impl Default for DisclosurePolicy {
+    fn default() -> Self {
+        Self::Selective
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -136,10 +232,8 @@ impl NonRevokedInterval {
         // - the new `from` value is later, smaller interval
         // - the new `from` value is Some if previouly was None
         match (self.from, to_compare.from) {
-            (Some(old_from), Some(new_from)) => {
-                if old_from.lt(&new_from) {
-                    self.from = to_compare.from;
-                }
+            (Some(old_from), Some(new_from)) if old_from.lt(&new_from) => {
+                self.from = to_compare.from;
             }
             (None, Some(_)) => self.from = to_compare.from,
             _ => (),
@@ -148,10 +242,8 @@ impl NonRevokedInterval {
         // - the new `to` value is earlier, smaller interval
         // - the new `to` value is Some if previouly was None
         match (self.to, to_compare.to) {
-            (Some(old_to), Some(new_to)) => {
-                if new_to.lt(&old_to) {
-                    self.to = to_compare.to;
-                }
+            (Some(old_to), Some(new_to)) if new_to.lt(&old_to) => {
+                self.to = to_compare.to;
             }
             (None, Some(_)) => self.to = to_compare.to,
             _ => (),
@@ -181,19 +273,245 @@ pub struct AttributeInfo {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub names: Option<Vec<String>>,
+    /// Members of an attribute group which are revealed only if the credential has them
+    /// (version 2.0 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional_names: Option<Vec<String>>,
     pub restrictions: Option<Query>,
     pub non_revoked: Option<NonRevokedInterval>,
+    /// The prover may leave the attribute out of the presentation (version 2.0 only)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+    /// Whether and in which format the attribute may be self-attested (version 2.0 only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_attested: Option<SelfAttestedPolicy>,
+}
+
+/// Constraints on a self-attested answer to a requested attribute
+///
+/// ```json
+/// {"allowed": false}
+/// {"pattern": "\\+?[0-9 -]+"}
+/// {"values": ["yes", "no"]}
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct SelfAttestedPolicy {
+    #[serde(default = "default_true")]
+    pub allowed: bool,
+    /// Regular expression the whole value must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Accepted values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+}
+
+impl SelfAttestedPolicy {
+    /// Check a self-attested value against the policy
+    pub fn check(&self, value: &str) -> Result<(), ValidationError> {
+        if !self.allowed {
+            return Err(invalid!("Self-attested values are not allowed"));
+        }
+        if let Some(pattern) = &self.pattern {
+            if !Self::regex(pattern)?.is_match(value) {
+                return Err(invalid!(
+                    "Self-attested value does not match the pattern `{}`",
+                    pattern
+                ));
+            }
+        }
+        if let Some(values) = &self.values {
+            if !values.iter().any(|accepted| accepted == value) {
+                return Err(invalid!("Self-attested value is not one of {:?}", values));
+            }
+        }
+        Ok(())
+    }
+
+    fn regex(pattern: &str) -> Result<Regex, ValidationError> {
+        Regex::new(&format!("^(?:{pattern})$"))
+            .map_err(|err| invalid!("Invalid self-attested pattern `{}`: {}", pattern, err))
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        if !self.allowed && (self.pattern.is_some() || self.values.is_some()) {
+            return Err(invalid!(
+                "Presentation request validation failed: a self-attested format can not be set when self-attestation is not allowed"
+            ));
+        }
+        if self.values.as_ref().map_or(false, Vec::is_empty) {
+            return Err(invalid!(
+                "Presentation request validation failed: there are no accepted self-attested values"
+            ));
+        }
+        if let Some(pattern) = &self.pattern {
+            Self::regex(pattern)?;
+        }
+        Ok(())
+    }
+}
+
+impl AttributeInfo {
+    /// Resolve the optional members of an attribute group, keeping those for which `present`
+    /// holds as regular members
+    #[must_use]
+    pub fn with_optional_names(&self, present: impl Fn(&str) -> bool) -> Self {
+        let mut info = self.clone();
+        if let Some(optional_names) = info.optional_names.take() {
+            info.names
+                .get_or_insert_with(Vec::new)
+                .extend(optional_names.into_iter().filter(|name| present(name)));
+        }
+        info
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct PredicateInfo {
     pub name: String,
     pub p_type: PredicateTypes,
-    pub p_value: i32,
+    /// Predicate value. The CL range proofs only support 32-bit signed values, larger values
+    /// are rejected by [`PredicateInfo::range_value`].
+    pub p_value: i64,
     pub restrictions: Option<Query>,
     pub non_revoked: Option<NonRevokedInterval>,
+    /// The prover may leave the predicate out of the presentation (version 2.0 only)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+}
+
+impl PredicateInfo {
+    /// The predicate value as supported by the CL range proofs
+    pub fn range_value(&self) -> Result<i32, ValidationError> {
+        i32::try_from(self.p_value).map_err(|_| {
+            invalid!(
+                "Predicate value {} for attribute `{}` is out of range, predicate values must be 32-bit signed integers ({} to {})",
+                self.p_value,
+                self.name,
+                i32::MIN,
+                i32::MAX
+            )
+        })
+    }
+}
+
+/// A range over one attribute, proven with two predicates on the same credential
+///
+/// ```json
+/// {"name": "age", "lower": {"p_type": ">=", "p_value": 18}, "upper": {"p_type": "<", "p_value": 65}}
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RangePredicateInfo {
+    pub name: String,
+    /// Lower bound, either `>=` or `>`
+    pub lower: RangeBound,
+    /// Upper bound, either `<=` or `<`
+    pub upper: RangeBound,
+    pub restrictions: Option<Query>,
+    pub non_revoked: Option<NonRevokedInterval>,
+    /// The prover may leave the range out of the presentation (version 2.0 only)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RangeBound {
+    pub p_type: PredicateTypes,
+    pub p_value: i64,
+}
+
+impl RangeBound {
+    #[must_use]
+    pub const fn new(p_type: PredicateTypes, p_value: i64) -> Self {
+        Self { p_type, p_value }
+    }
+}
+
+impl RangePredicateInfo {
+    #[must_use]
+    pub fn new(name: impl Into<String>, lower: RangeBound, upper: RangeBound) -> Self {
+        Self {
+            name: name.into(),
+            lower,
+            upper,
+            restrictions: None,
+            non_revoked: None,
+            optional: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_restrictions(mut self, restrictions: Query) -> Self {
+        self.restrictions = Some(restrictions);
+        self
+    }
+
+    #[must_use]
+    pub fn with_non_revoked(mut self, non_revoked: NonRevokedInterval) -> Self {
+        self.non_revoked = Some(non_revoked);
+        self
+    }
+
+    #[must_use]
+    pub const fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// The lower and upper bound predicates
+    #[must_use]
+    pub fn predicates(&self) -> [PredicateInfo; 2] {
+        [&self.lower, &self.upper].map(|bound| PredicateInfo {
+            name: self.name.clone(),
+            p_type: bound.p_type.clone(),
+            p_value: bound.p_value,
+            restrictions: self.restrictions.clone(),
+            non_revoked: self.non_revoked.clone(),
+            optional: self.optional,
+        })
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        if self.name.is_empty() {
+            return Err(invalid!(
+                "Presentation request validation failed: there is empty requested range: {:?}",
+                self
+            ));
+        }
+        if !matches!(self.lower.p_type, PredicateTypes::GE | PredicateTypes::GT) {
+            return Err(invalid!(
+                "Presentation request validation failed: the lower bound of the range for `{}` must be `>=` or `>`",
+                self.name
+            ));
+        }
+        if !matches!(self.upper.p_type, PredicateTypes::LE | PredicateTypes::LT) {
+            return Err(invalid!(
+                "Presentation request validation failed: the upper bound of the range for `{}` must be `<=` or `<`",
+                self.name
+            ));
+        }
+        let [lower, upper] = self.predicates();
+        let min =
+            i64::from(lower.range_value()?) + i64::from(self.lower.p_type == PredicateTypes::GT);
+        let max =
+            i64::from(upper.range_value()?) - i64::from(self.upper.p_type == PredicateTypes::LT);
+        if min > max {
+            return Err(invalid!(
+                "Presentation request validation failed: the range for `{}` is empty",
+                self.name
+            ));
+        }
+        Ok(())
+    }
 }
 
+/// Comparison predicates supported by the CL range proofs.
+///
+/// Set membership (or non-membership) of a hidden attribute value is not available: it needs
+/// either a disjunction of equality proofs or an accumulator proof over attribute values, and
+/// `anoncreds-clsignatures` supports neither (its accumulator only covers revocation indices).
+/// A set of consecutive integers can be requested as a range instead, see
+/// [`RangePredicateInfo`].
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum PredicateTypes {
     #[serde(rename = ">=")]
@@ -235,8 +553,11 @@ impl Validatable for PresentationRequest {
         let value = self.value();
         let version = self.version();
 
-        if value.requested_attributes.is_empty() && value.requested_predicates.is_empty() {
-            return Err(invalid!("Presentation request validation failed: both `requested_attributes` and `requested_predicates` are empty"));
+        if value.requested_attributes.is_empty()
+            && value.requested_predicates.is_empty()
+            && value.requested_ranges.is_empty()
+        {
+            return Err(invalid!("Presentation request validation failed: `requested_attributes`, `requested_predicates` and `requested_ranges` are all empty"));
         }
 
         for requested_attribute in value.requested_attributes.values() {
@@ -259,6 +580,22 @@ impl Validatable for PresentationRequest {
                 return Err(invalid!("Presentation request validation failed: there is a requested attribute with both name and names: {:?}", requested_attribute));
             }
 
+            if requested_attribute.optional {
+                _check_v2_feature("optional", &version)?;
+            }
+
+            if let Some(ref self_attested) = requested_attribute.self_attested {
+                _check_v2_feature("self_attested", &version)?;
+                self_attested.validate()?;
+            }
+
+            if let Some(ref optional_names) = requested_attribute.optional_names {
+                _check_v2_feature("optional_names", &version)?;
+                if !has_names || optional_names.is_empty() {
+                    return Err(invalid!("Presentation request validation failed: optional members must be part of a non-empty attribute group: {:?}", requested_attribute));
+                }
+            }
+
             if let Some(ref restrictions) = requested_attribute.restrictions {
                 _process_operator(restrictions, &version)?;
             }
@@ -271,11 +608,72 @@ impl Validatable for PresentationRequest {
                     requested_predicate
                 ));
             }
+            requested_predicate.range_value()?;
+            if requested_predicate.optional {
+                _check_v2_feature("optional", &version)?;
+            }
             if let Some(ref restrictions) = requested_predicate.restrictions {
                 _process_operator(restrictions, &version)?;
             }
         }
 
+        if !value.requested_ranges.is_empty() {
+            _check_v2_feature("requested_ranges", &version)?;
+        }
+        for (referent, requested_range) in &value.requested_ranges {
+            if value.requested_predicates.contains_key(referent) {
+                return Err(invalid!(
+                    "Presentation request validation failed: referent `{}` is used for both a predicate and a range",
+                    referent
+                ));
+            }
+            requested_range.validate()?;
+            if requested_range.optional {
+                _check_v2_feature("optional", &version)?;
+            }
+            if let Some(ref restrictions) = requested_range.restrictions {
+                _process_operator(restrictions, &version)?;
+            }
+        }
+
+        if value.expires_at.is_some() {
+            _check_v2_feature("expires_at", &version)?;
+        }
+        if value.verifier.is_some() {
+            _check_v2_feature("verifier", &version)?;
+        }
+        if let Some(min_credentials) = value.min_credentials {
+            _check_v2_feature("min_credentials", &version)?;
+            if min_credentials == 0 {
+                return Err(invalid!(
+                    "Presentation request validation failed: `min_credentials` must be at least 1"
+                ));
+            }
+        }
+        if value.disclosure_policy.is_some() {
+            _check_v2_feature("disclosure_policy", &version)?;
+        }
+
+        if !value.common_attributes.is_empty() {
+            _check_v2_feature("common_attributes", &version)?;
+        }
+        let mut common_attributes = HashSet::new();
+        for common_attribute in &value.common_attributes {
+            let name = common_attribute.replace(' ', "").to_lowercase();
+            if name.is_empty() || name == "master_secret" {
+                return Err(invalid!(
+                    "Presentation request validation failed: invalid common attribute: {:?}",
+                    common_attribute
+                ));
+            }
+            if !common_attributes.insert(name) {
+                return Err(invalid!(
+                    "Presentation request validation failed: duplicate common attribute: {:?}",
+                    common_attribute
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -334,6 +732,25 @@ fn _check_restriction(
     Ok(())
 }
 
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+fn _check_v2_feature(
+    field: &str,
+    version: &PresentationRequestVersion,
+) -> Result<(), ValidationError> {
+    if *version == PresentationRequestVersion::V1 {
+        return Err(invalid!("Presentation request validation failed: `{}` can not be used for presentation request of the first version. \
+                    Please, set \"ver\":\"2.0\" to use it.", field));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -376,6 +793,211 @@ mod tests {
         }
     }
 
+    #[test]
+    fn predicate_value_range() {
+        let req_json = |p_value: i64| {
+            json!({
+                "nonce": "123456",
+                "name": "name",
+                "version": "1.0",
+                "requested_attributes": {},
+                "requested_predicates": {
+                    "predicate1_referent": {"name": "balance", "p_type": ">=", "p_value": p_value}
+                },
+            })
+        };
+
+        let req: PresentationRequest =
+            serde_json::from_value(req_json(i64::from(i32::MAX))).unwrap();
+        req.validate().unwrap();
+
+        let req: PresentationRequest = serde_json::from_value(req_json(3_000_000_000)).unwrap();
+        let err = req.validate().unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+    }
+
+    #[test]
+    fn range_predicate_validation() {
+        let req_json = |lower: serde_json::Value, upper: serde_json::Value| {
+            json!({
+                "nonce": "123456",
+                "name": "name",
+                "version": "1.0",
+                "ver": "2.0",
+                "requested_attributes": {},
+                "requested_ranges": {
+                    "range1_referent": {"name": "age", "lower": lower, "upper": upper}
+                },
+            })
+        };
+
+        let req: PresentationRequest = serde_json::from_value(req_json(
+            json!({"p_type": ">=", "p_value": 18}),
+            json!({"p_type": "<", "p_value": 65}),
+        ))
+        .unwrap();
+        req.validate().unwrap();
+        let payload = req.value();
+        let predicates = payload.predicates_for_referent("range1_referent").unwrap();
+        assert_eq!(predicates.len(), 2);
+
+        // Bound types in the wrong position
+        let req: PresentationRequest = serde_json::from_value(req_json(
+            json!({"p_type": "<", "p_value": 18}),
+            json!({"p_type": ">", "p_value": 65}),
+        ))
+        .unwrap();
+        req.validate().unwrap_err();
+
+        // Empty range
+        let req: PresentationRequest = serde_json::from_value(req_json(
+            json!({"p_type": ">", "p_value": 18}),
+            json!({"p_type": "<", "p_value": 19}),
+        ))
+        .unwrap();
+        req.validate().unwrap_err();
+    }
+
+    #[test]
+    fn common_attributes_validation() {
+        let req_json = |common_attributes: serde_json::Value| {
+            json!({
+                "nonce": "123456",
+                "name": "name",
+                "version": "1.0",
+                "ver": "2.0",
+                "requested_attributes": {
+                    "attr1_referent": {"name": "role"}
+                },
+                "common_attributes": common_attributes,
+            })
+        };
+
+        let req: PresentationRequest = serde_json::from_value(req_json(json!(["name"]))).unwrap();
+        req.validate().unwrap();
+
+        let req: PresentationRequest =
+            serde_json::from_value(req_json(json!(["master_secret"]))).unwrap();
+        req.validate().unwrap_err();
+
+        let req: PresentationRequest =
+            serde_json::from_value(req_json(json!(["Name", "name"]))).unwrap();
+        req.validate().unwrap_err();
+    }
+
+    #[test]
+    fn v2_features_require_v2_request() {
+        let req_json = |ver: &str, extra: serde_json::Value| {
+            let mut req = json!({
+                "nonce": "123456",
+                "name": "name",
+                "version": "1.0",
+                "ver": ver,
+                "requested_attributes": {
+                    "attr1_referent": {"names": ["name"], "optional_names": ["middle_name"]}
+                },
+            });
+            req.as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            req
+        };
+        let extra = json!({
+            "expires_at": 1_700_000_000u64,
+            "verifier": {"id": "did:example:verifier", "purpose": "age check"},
+            "min_credentials": 1,
+            "disclosure_policy": "reveal_all",
+        });
+
+        let req: PresentationRequest = serde_json::from_value(req_json("2.0", extra)).unwrap();
+        req.validate().unwrap();
+        let payload = req.value();
+        assert!(payload.is_expired(1_700_000_001));
+        assert!(!payload.is_expired(1_700_000_000));
+        assert_eq!(payload.disclosure_policy(), DisclosurePolicy::RevealAll);
+
+        let req: PresentationRequest = serde_json::from_value(req_json("1.0", json!({}))).unwrap();
+        req.validate().unwrap_err();
+        for (field, value) in [
+            ("expires_at", json!(1_700_000_000u64)),
+            ("verifier", json!({"name": "verifier"})),
+            ("min_credentials", json!(1)),
+            ("disclosure_policy", json!("selective")),
+            (
+                "requested_ranges",
+                json!({"range1_referent": {
+                    "name": "age",
+                    "lower": {"p_type": ">=", "p_value": 18},
+                    "upper": {"p_type": "<", "p_value": 65}
+                }}),
+            ),
+            ("common_attributes", json!(["name"])),
+        ] {
+            let mut req = req_json("1.0", json!({ field: value }));
+            req["requested_attributes"]["attr1_referent"]
+                .as_object_mut()
+                .unwrap()
+                .remove("optional_names");
+            let req: PresentationRequest = serde_json::from_value(req).unwrap();
+            let err = req.validate().unwrap_err();
+            assert!(err.to_string().contains(field));
+        }
+
+        let req: PresentationRequest = serde_json::from_value(json!({
+            "nonce": "123456",
+            "name": "name",
+            "version": "1.0",
+            "requested_attributes": {
+                "attr1_referent": {"name": "middle_name", "optional": true}
+            },
+        }))
+        .unwrap();
+        let err = req.validate().unwrap_err();
+        assert!(err.to_string().contains("optional"));
+    }
+
+    #[test]
+    fn self_attested_policy() {
+        let policy = |value: serde_json::Value| -> SelfAttestedPolicy {
+            serde_json::from_value(value).unwrap()
+        };
+
+        let forbidden = policy(json!({"allowed": false}));
+        forbidden.validate().unwrap();
+        forbidden.check("anything").unwrap_err();
+
+        let phone = policy(json!({"pattern": "\\+?[0-9 -]+"}));
+        phone.validate().unwrap();
+        phone.check("+1 555-0100").unwrap();
+        phone.check("call me").unwrap_err();
+        phone.check("555 or call me").unwrap_err();
+
+        let answer = policy(json!({"values": ["yes", "no"]}));
+        answer.check("no").unwrap();
+        answer.check("maybe").unwrap_err();
+
+        policy(json!({"pattern": "("})).validate().unwrap_err();
+        policy(json!({"values": []})).validate().unwrap_err();
+        policy(json!({"allowed": false, "values": ["yes"]}))
+            .validate()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn optional_names_resolution() {
+        let info: AttributeInfo = serde_json::from_value(json!({
+            "names": ["name", "age"],
+            "optional_names": ["middle_name", "height"]
+        }))
+        .unwrap();
+        let resolved = info.with_optional_names(|name| name == "height");
+        assert_eq!(
+            resolved.names.unwrap(),
+            vec!["name".to_string(), "age".to_string(), "height".to_string()]
+        );
+        assert_eq!(resolved.optional_names, None);
+    }
+
     #[test]
     fn override_works() {
         let mut interval = NonRevokedInterval::default();
@@ -1,14 +1,130 @@
 use std::str::FromStr;
 
 use crate::cl::{RevocationKeyPrivate, RevocationKeyPublic};
-use crate::{error::ConversionError, impl_anoncreds_object_identifier};
+use crate::utils::did_indy;
+use crate::{error::ConversionError, impl_anoncreds_object_identifier, invalid};
 
-use super::{cred_def::CredentialDefinitionId, issuer_id::IssuerId};
+use super::{
+    cred_def::{
+        CredentialDefinitionId, LegacyCredentialDefinitionId, LegacySchemaRef, SignatureType,
+    },
+    issuer_id::IssuerId,
+};
 
 pub const CL_ACCUM: &str = "CL_ACCUM";
 
 impl_anoncreds_object_identifier!(RevocationRegistryDefinitionId);
 
+/// Parts of a legacy revocation registry definition identifier
+/// `<did>:4:<cred def id>:CL_ACCUM:<tag>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegacyRevocationRegistryDefinitionId {
+    pub did: IssuerId,
+    pub cred_def_id: LegacyCredentialDefinitionId,
+    pub registry_type: RegistryType,
+    pub tag: String,
+}
+
+impl RevocationRegistryDefinitionId {
+    /// Decompose a legacy revocation registry definition identifier into its parts
+    pub fn parse_legacy(&self) -> Result<LegacyRevocationRegistryDefinitionId, ValidationError> {
+        let caps = LEGACY_REV_REG_DEF_IDENTIFIER
+            .captures(&self.0)
+            .ok_or_else(|| {
+                invalid!(
+                    "{} is not a legacy revocation registry definition identifier",
+                    self.0
+                )
+            })?;
+
+        Ok(LegacyRevocationRegistryDefinitionId {
+            did: IssuerId::new_unchecked(&caps["did"]),
+            cred_def_id: CredentialDefinitionId::new_unchecked(&caps["cred_def_id"])
+                .parse_legacy()?,
+            registry_type: RegistryType::from_str(&caps["registry_type"])
+                .map_err(|e| invalid!("{}", e))?,
+            tag: caps["tag"].to_owned(),
+        })
+    }
+}
+
+impl RevocationRegistryDefinitionId {
+    /// Convert a legacy revocation registry definition identifier to
+    /// `did:indy:<namespace>:<did>/anoncreds/v0/REV_REG_DEF/<schema seq_no>/<cred def tag>/<tag>`
+    pub fn to_did_indy(&self, namespace: &str) -> Result<Self, ValidationError> {
+        let legacy = self.parse_legacy()?;
+        let seq_no = match legacy.cred_def_id.schema_ref {
+            LegacySchemaRef::SeqNo(seq_no) => seq_no,
+            _ => {
+                return Err(invalid!(
+                    "{} does not reference the schema by sequence number",
+                    self.0
+                ))
+            }
+        };
+        if legacy.cred_def_id.did != legacy.did {
+            return Err(invalid!(
+                "{} references a credential definition of another DID",
+                self.0
+            ));
+        }
+        Ok(Self(did_indy::format(
+            namespace,
+            &legacy.did.0,
+            Some((
+                did_indy::REV_REG_DEF_OBJECT,
+                &[&seq_no.to_string(), &legacy.cred_def_id.tag, &legacy.tag],
+            )),
+        )?))
+    }
+
+    /// Convert a `did:indy` revocation registry definition identifier to a legacy identifier.
+    /// Legacy identifiers are returned as is.
+    pub fn to_legacy(&self) -> Result<Self, ValidationError> {
+        if self.is_legacy_rev_reg_def_identifier() {
+            return Ok(self.clone());
+        }
+        let parsed = did_indy::parse_object(&self.0, did_indy::REV_REG_DEF_OBJECT, 3)?;
+        let (_, segments) = parsed.object.unwrap_or_default();
+        let did = IssuerId::new_unchecked(parsed.did);
+        let legacy = LegacyRevocationRegistryDefinitionId {
+            did: did.clone(),
+            cred_def_id: LegacyCredentialDefinitionId {
+                did,
+                signature_type: SignatureType::CL,
+                schema_ref: LegacySchemaRef::SeqNo(
+                    segments[0]
+                        .parse()
+                        .map_err(|_| invalid!("Invalid schema sequence number in {}", self.0))?,
+                ),
+                tag: segments[1].to_owned(),
+            },
+            registry_type: RegistryType::CL_ACCUM,
+            tag: segments[2].to_owned(),
+        };
+        Ok(Self::from(&legacy))
+    }
+}
+
+impl std::fmt::Display for LegacyRevocationRegistryDefinitionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let registry_type = match self.registry_type {
+            RegistryType::CL_ACCUM => CL_ACCUM,
+        };
+        write!(
+            f,
+            "{}:4:{}:{}:{}",
+            self.did, self.cred_def_id, registry_type, self.tag
+        )
+    }
+}
+
+impl From<&LegacyRevocationRegistryDefinitionId> for RevocationRegistryDefinitionId {
+    fn from(id: &LegacyRevocationRegistryDefinitionId) -> Self {
+        Self(id.to_string())
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum RegistryType {
@@ -64,3 +180,60 @@ impl Validatable for RevocationRegistryDefinition {
 pub struct RevocationRegistryDefinitionPrivate {
     pub value: RevocationKeyPrivate,
 }
+
+#[cfg(test)]
+mod test_rev_reg_def_identifiers {
+    use super::*;
+
+    const LEGACY_ID: &str =
+        "DXoTtQJNtXtiwWaZAK3rB1:4:DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default:CL_ACCUM:tag";
+
+    #[test]
+    fn should_validate_legacy_identifier() {
+        assert!(RevocationRegistryDefinitionId::new(LEGACY_ID)
+            .unwrap()
+            .is_legacy_rev_reg_def_identifier());
+        assert!(
+            !RevocationRegistryDefinitionId::new("did:web:xyz/resource/rev-reg-def")
+                .unwrap()
+                .is_legacy_rev_reg_def_identifier()
+        );
+        assert!(RevocationRegistryDefinitionId::new("DXoTtQJNtXtiwWaZAK3rB1").is_err());
+    }
+
+    #[test]
+    fn should_parse_legacy_identifier() {
+        let rev_reg_def_id = RevocationRegistryDefinitionId::new(LEGACY_ID).unwrap();
+        let legacy = rev_reg_def_id.parse_legacy().unwrap();
+
+        assert_eq!(legacy.did.0, "DXoTtQJNtXtiwWaZAK3rB1");
+        assert_eq!(
+            CredentialDefinitionId::from(&legacy.cred_def_id).0,
+            "DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default"
+        );
+        assert_eq!(legacy.registry_type, RegistryType::CL_ACCUM);
+        assert_eq!(legacy.tag, "tag");
+        assert_eq!(
+            RevocationRegistryDefinitionId::from(&legacy),
+            rev_reg_def_id
+        );
+
+        assert!(
+            RevocationRegistryDefinitionId::new_unchecked("did:web:xyz/resource/rev-reg-def")
+                .parse_legacy()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_convert_to_did_indy() {
+        let legacy = RevocationRegistryDefinitionId::new(LEGACY_ID).unwrap();
+        let did_indy = legacy.to_did_indy("sovrin").unwrap();
+
+        assert_eq!(
+            did_indy.0,
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/REV_REG_DEF/98153/default/tag"
+        );
+        assert_eq!(did_indy.to_legacy().unwrap(), legacy);
+    }
+}
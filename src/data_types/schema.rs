@@ -1,4 +1,5 @@
-use crate::impl_anoncreds_object_identifier;
+use crate::utils::did_indy;
+use crate::{impl_anoncreds_object_identifier, invalid};
 
 use std::collections::HashSet;
 
@@ -8,6 +9,70 @@ pub const MAX_ATTRIBUTES_COUNT: usize = 125;
 
 impl_anoncreds_object_identifier!(SchemaId);
 
+/// Parts of a legacy schema identifier `<did>:2:<name>:<version>`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegacySchemaId {
+    pub did: IssuerId,
+    pub name: String,
+    pub version: String,
+}
+
+impl SchemaId {
+    /// Decompose a legacy schema identifier into its parts
+    pub fn parse_legacy(&self) -> Result<LegacySchemaId, ValidationError> {
+        let caps = LEGACY_SCHEMA_IDENTIFIER
+            .captures(&self.0)
+            .ok_or_else(|| invalid!("{} is not a legacy schema identifier", self.0))?;
+
+        Ok(LegacySchemaId {
+            did: IssuerId::new_unchecked(&caps["did"]),
+            name: caps["name"].to_owned(),
+            version: caps["version"].to_owned(),
+        })
+    }
+}
+
+impl SchemaId {
+    /// Convert a legacy schema identifier to
+    /// `did:indy:<namespace>:<did>/anoncreds/v0/SCHEMA/<name>/<version>`
+    pub fn to_did_indy(&self, namespace: &str) -> Result<Self, ValidationError> {
+        let legacy = self.parse_legacy()?;
+        Ok(Self(did_indy::format(
+            namespace,
+            &legacy.did.0,
+            Some((did_indy::SCHEMA_OBJECT, &[&legacy.name, &legacy.version])),
+        )?))
+    }
+
+    /// Convert a `did:indy` schema identifier to a legacy identifier. Legacy identifiers are
+    /// returned as is.
+    pub fn to_legacy(&self) -> Result<Self, ValidationError> {
+        if self.is_legacy_schema_identifier() {
+            return Ok(self.clone());
+        }
+        let parsed = did_indy::parse_object(&self.0, did_indy::SCHEMA_OBJECT, 2)?;
+        let (_, segments) = parsed.object.unwrap_or_default();
+        let legacy = LegacySchemaId {
+            did: IssuerId::new_unchecked(parsed.did),
+            name: segments[0].to_owned(),
+            version: segments[1].to_owned(),
+        };
+        Ok(Self::from(&legacy))
+    }
+}
+
+impl std::fmt::Display for LegacySchemaId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:2:{}:{}", self.did, self.name, self.version)
+    }
+}
+
+impl From<&LegacySchemaId> for SchemaId {
+    fn from(id: &LegacySchemaId) -> Self {
+        Self(id.to_string())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Schema {
@@ -146,4 +211,43 @@ mod test_schema_validation {
         let schema: Schema = serde_json::from_value(schema_json).unwrap();
         assert!(schema.validate().is_err());
     }
+
+    #[test]
+    fn test_parse_legacy_schema_id() {
+        let schema_id = SchemaId::new("DXoTtQJNtXtiwWaZAK3rB1:2:gvt:1.0").unwrap();
+        let legacy = schema_id.parse_legacy().unwrap();
+
+        assert_eq!(legacy.did.0, "DXoTtQJNtXtiwWaZAK3rB1");
+        assert_eq!(legacy.name, "gvt");
+        assert_eq!(legacy.version, "1.0");
+        assert_eq!(SchemaId::from(&legacy), schema_id);
+
+        assert!(SchemaId::new_unchecked("did:web:xyz/resource/schema")
+            .parse_legacy()
+            .is_err());
+    }
+
+    #[test]
+    fn test_convert_schema_id_to_did_indy() {
+        let legacy = SchemaId::new("DXoTtQJNtXtiwWaZAK3rB1:2:gvt:1.0").unwrap();
+        let did_indy = legacy.to_did_indy("sovrin").unwrap();
+
+        assert_eq!(
+            did_indy.0,
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/gvt/1.0"
+        );
+        assert_eq!(did_indy.to_legacy().unwrap(), legacy);
+        assert!(did_indy.to_did_indy("sovrin").is_err());
+        assert!(SchemaId::new_unchecked(
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/gvt"
+        )
+        .to_legacy()
+        .is_err());
+
+        let legacy = SchemaId::new("DXoTtQJNtXtiwWaZAK3rB1:2:my schema:1.0").unwrap();
+        let did_indy = legacy.to_did_indy("sovrin").unwrap();
+        assert!(SchemaId::new(did_indy.0.as_str()).is_ok());
+        assert!(did_indy.validate_strict().is_err());
+        assert!(legacy.validate_strict().is_ok());
+    }
 }
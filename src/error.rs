@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::error::Error as StdError;
 use std::fmt::{self, Display, Formatter};
 use std::result::Result as StdResult;
@@ -20,6 +21,8 @@ pub enum ErrorKind {
     InvalidUserRevocId,
     ProofRejected,
     RevocationRegistryFull,
+    // Operation aborted through a cancellation token
+    Cancelled,
 }
 
 impl ErrorKind {
@@ -34,6 +37,7 @@ impl ErrorKind {
             Self::InvalidUserRevocId => "Invalid revocation accumulator index",
             Self::ProofRejected => "Proof rejected",
             Self::RevocationRegistryFull => "Revocation registry full",
+            Self::Cancelled => "Operation cancelled",
         }
     }
 }
@@ -50,6 +54,8 @@ pub struct Error {
     kind: ErrorKind,
     pub cause: Option<Box<dyn StdError + Send + Sync + 'static>>,
     pub message: Option<String>,
+    /// Details of what failed, such as the referent or object being processed
+    pub context: BTreeMap<String, String>,
     // backtrace (when supported)
 }
 
@@ -59,6 +65,7 @@ impl Error {
             kind,
             cause: None,
             message: Some(msg.into()),
+            context: BTreeMap::new(),
         }
     }
 
@@ -67,6 +74,7 @@ impl Error {
             kind,
             cause: None,
             message: msg.map(Into::into),
+            context: BTreeMap::new(),
         }
     }
 
@@ -81,6 +89,26 @@ impl Error {
         self.cause = Some(err.into());
         self
     }
+
+    /// Attach a detail of what failed. Context added closer to the failure takes precedence.
+    #[must_use]
+    pub fn with_context(mut self, key: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.context
+            .entry(key.into())
+            .or_insert_with(|| value.to_string());
+        self
+    }
+
+    /// Messages of the chain of underlying errors, the outermost first
+    pub fn causes(&self) -> Vec<String> {
+        let mut causes = Vec::new();
+        let mut source = self.source();
+        while let Some(err) = source {
+            causes.push(err.to_string());
+            source = err.source();
+        }
+        causes
+    }
 }
 
 impl fmt::Display for Error {
@@ -118,6 +146,7 @@ impl From<ErrorKind> for Error {
             kind,
             cause: None,
             message: None,
+            context: BTreeMap::new(),
         }
     }
 }
@@ -188,6 +217,7 @@ macro_rules! err_map {
     };
 }
 
+#[allow(dead_code)]
 pub trait ResultExt<T, E> {
     fn map_err_string(self) -> StdResult<T, String>;
     fn map_input_err<F, M>(self, mapfn: F) -> Result<T>
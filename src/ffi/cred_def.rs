@@ -1,6 +1,7 @@
+use std::os::raw::c_char;
 use std::str::FromStr;
 
-use ffi_support::FfiStr;
+use ffi_support::{rust_string_to_c, FfiStr};
 
 use super::error::{catch_error, ErrorCode};
 use super::object::ObjectHandle;
@@ -72,15 +73,42 @@ impl_anoncreds_object_from_json!(
     CredentialDefinition,
     anoncreds_credential_definition_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    CredentialDefinition,
+    anoncreds_credential_definition_from_cbor
+);
+
+/// Get the issuer identifier of a credential definition
+#[no_mangle]
+pub extern "C" fn anoncreds_credential_definition_get_issuer_id(
+    handle: ObjectHandle,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let cred_def = handle.load()?;
+        let cred_def = cred_def.cast_ref::<CredentialDefinition>()?;
+        unsafe { *result_p = rust_string_to_c(cred_def.issuer_id.to_string()) };
+        Ok(())
+    })
+}
 
 impl_anoncreds_object!(CredentialDefinitionPrivate, "CredentialDefinitionPrivate");
 impl_anoncreds_object_from_json!(
     CredentialDefinitionPrivate,
     anoncreds_credential_definition_private_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    CredentialDefinitionPrivate,
+    anoncreds_credential_definition_private_from_cbor
+);
 
 impl_anoncreds_object!(KeyCorrectnessProof, "KeyCorrectnessProof");
 impl_anoncreds_object_from_json!(
     KeyCorrectnessProof,
     anoncreds_key_correctness_proof_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    KeyCorrectnessProof,
+    anoncreds_key_correctness_proof_from_cbor
+);
@@ -60,9 +60,14 @@ pub extern "C" fn anoncreds_create_credential_request(
 
 impl_anoncreds_object!(CredentialRequest, "CredentialRequest");
 impl_anoncreds_object_from_json!(CredentialRequest, anoncreds_credential_request_from_json);
+impl_anoncreds_object_from_cbor!(CredentialRequest, anoncreds_credential_request_from_cbor);
 
 impl_anoncreds_object!(CredentialRequestMetadata, "CredentialRequestMetadata");
 impl_anoncreds_object_from_json!(
     CredentialRequestMetadata,
     anoncreds_credential_request_metadata_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    CredentialRequestMetadata,
+    anoncreds_credential_request_metadata_from_cbor
+);
@@ -63,6 +63,7 @@ impl<'a> TryFrom<&'a RevocationConfig> for CredentialRevocationConfig<'a> {
 
 #[no_mangle]
 pub extern "C" fn anoncreds_create_credential(
+    schema: ObjectHandle,
     cred_def: ObjectHandle,
     cred_def_private: ObjectHandle,
     cred_offer: ObjectHandle,
@@ -118,6 +119,7 @@ pub extern "C" fn anoncreds_create_credential(
         };
 
         let cred = create_credential(
+            schema.load()?.cast_ref()?,
             cred_def.load()?.cast_ref()?,
             cred_def_private.load()?.cast_ref()?,
             cred_offer.load()?.cast_ref()?,
@@ -200,6 +202,7 @@ pub extern "C" fn anoncreds_process_credential(
 
 impl_anoncreds_object!(Credential, "Credential");
 impl_anoncreds_object_from_json!(Credential, anoncreds_credential_from_json);
+impl_anoncreds_object_from_cbor!(Credential, anoncreds_credential_from_cbor);
 
 #[no_mangle]
 pub extern "C" fn anoncreds_credential_get_attribute(
@@ -228,3 +231,66 @@ pub extern "C" fn anoncreds_credential_get_attribute(
         Ok(())
     })
 }
+
+/// Get the attribute names of a credential as a JSON array
+#[no_mangle]
+pub extern "C" fn anoncreds_credential_get_attribute_names(
+    handle: ObjectHandle,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let cred = handle.load()?;
+        let cred = cred.cast_ref::<Credential>()?;
+        let names = serde_json::to_string(&cred.attribute_names())
+            .map_err(err_map!("Error serializing attribute names"))?;
+        unsafe { *result_p = rust_string_to_c(names) };
+        Ok(())
+    })
+}
+
+/// Get the raw value of a credential attribute, or the encoded value when `encoded` is non-zero
+#[no_mangle]
+pub extern "C" fn anoncreds_credential_get_attribute_value(
+    handle: ObjectHandle,
+    name: FfiStr,
+    encoded: i8,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let name = name
+            .as_opt_str()
+            .ok_or_else(|| err_msg!("Missing attribute name"))?;
+        let cred = handle.load()?;
+        let cred = cred.cast_ref::<Credential>()?;
+        let value = cred.attribute(name).ok_or_else(|| {
+            err_msg!("Credential has no attribute '{}'", name).with_context("attribute", name)
+        })?;
+        let val = if encoded == 0 {
+            value.raw.clone()
+        } else {
+            value.encoded.clone()
+        };
+        unsafe { *result_p = rust_string_to_c(val) };
+        Ok(())
+    })
+}
+
+/// Get a JSON summary of a credential with its identifiers and raw attribute values, omitting
+/// the signature
+#[no_mangle]
+pub extern "C" fn anoncreds_credential_get_summary(
+    handle: ObjectHandle,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let cred = handle.load()?;
+        let cred = cred.cast_ref::<Credential>()?;
+        let summary = serde_json::to_string(&cred.summary())
+            .map_err(err_map!("Error serializing credential summary"))?;
+        unsafe { *result_p = rust_string_to_c(summary) };
+        Ok(())
+    })
+}
@@ -1,14 +1,15 @@
 use crate::error::{Error, ErrorKind, Result};
 
+use std::cell::RefCell;
 use std::os::raw::c_char;
 use std::panic::{catch_unwind, UnwindSafe};
-use std::sync::RwLock;
 
 use ffi_support::rust_string_to_c;
 
-use once_cell::sync::Lazy;
-
-static LAST_ERROR: Lazy<RwLock<Option<Error>>> = Lazy::new(|| RwLock::new(None));
+thread_local! {
+    /// Error of the last failed call made on the current thread
+    static LAST_ERROR: RefCell<Option<Error>> = RefCell::new(None);
+}
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize)]
 #[repr(usize)]
@@ -22,6 +23,7 @@ pub enum ErrorCode {
     InvalidUserRevocId = 6,
     ProofRejected = 7,
     RevocationRegistryFull = 8,
+    Cancelled = 9,
 }
 
 impl From<ErrorKind> for ErrorCode {
@@ -35,6 +37,7 @@ impl From<ErrorKind> for ErrorCode {
             ErrorKind::InvalidUserRevocId => Self::InvalidUserRevocId,
             ErrorKind::ProofRejected => Self::ProofRejected,
             ErrorKind::RevocationRegistryFull => Self::RevocationRegistryFull,
+            ErrorKind::Cancelled => Self::Cancelled,
         }
     }
 }
@@ -76,11 +79,22 @@ where
     }
 }
 
+/// Take the error of the last failed call on the current thread as JSON
+///
+/// Besides the error `code` and `message`, the JSON holds the error `kind`, the messages of the
+/// underlying `causes` and the `context` of the failure, e.g. the referent which failed.
 pub fn get_current_error_json() -> String {
-    if let Some(err) = Option::take(&mut *LAST_ERROR.write().unwrap()) {
+    if let Some(err) = LAST_ERROR.with(|last| last.borrow_mut().take()) {
         let message = err.to_string();
         let code = ErrorCode::from(err.kind()) as usize;
-        serde_json::json!({"code": code, "message": message}).to_string()
+        serde_json::json!({
+            "code": code,
+            "message": message,
+            "kind": format!("{:?}", err.kind()),
+            "causes": err.causes(),
+            "context": err.context,
+        })
+        .to_string()
     } else {
         r#"{"code":0,"message":null}"#.to_owned()
     }
@@ -91,6 +105,34 @@ pub fn set_last_error(error: Option<Error>) -> ErrorCode {
     let code = error
         .as_ref()
         .map_or(ErrorCode::Success, |err| err.kind().into());
-    *LAST_ERROR.write().unwrap() = error;
+    LAST_ERROR.with(|last| *last.borrow_mut() = error);
     code
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_per_thread() {
+        set_last_error(Some(
+            err_msg!(ProofRejected, "Rejected")
+                .with_cause(std::io::Error::new(std::io::ErrorKind::Other, "Cause"))
+                .with_context("referent", "attr1_referent"),
+        ));
+        let other = std::thread::spawn(get_current_error_json).join().unwrap();
+        assert_eq!(other, r#"{"code":0,"message":null}"#);
+
+        let json: serde_json::Value = serde_json::from_str(&get_current_error_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": 7,
+                "message": "Proof rejected: Rejected [Cause]",
+                "kind": "ProofRejected",
+                "causes": ["Cause"],
+                "context": {"referent": "attr1_referent"},
+            })
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::os::raw::c_char;
 
-use ffi_support::{rust_string_to_c, ByteBuffer};
+use ffi_support::{rust_string_to_c, ByteBuffer, FfiStr};
 use zeroize::Zeroize;
 
 pub static LIB_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -32,9 +32,13 @@ mod credential;
 mod link_secret;
 mod pres_req;
 mod presentation;
+mod progress;
 mod revocation;
 mod schema;
 
+use self::util::FfiStrList;
+use crate::data_types::issuer_id::{DidMethods, IssuerId};
+
 #[no_mangle]
 pub extern "C" fn anoncreds_set_default_logger() -> ErrorCode {
     catch_error(|| {
@@ -44,6 +48,26 @@ pub extern "C" fn anoncreds_set_default_logger() -> ErrorCode {
     })
 }
 
+/// Strictly validate an issuer identifier, requiring DIDs to use one of `did_methods` unless the
+/// list is empty
+#[no_mangle]
+pub extern "C" fn anoncreds_validate_issuer_id(
+    issuer_id: FfiStr,
+    did_methods: FfiStrList,
+) -> ErrorCode {
+    catch_error(|| {
+        let issuer_id = issuer_id
+            .as_opt_str()
+            .ok_or_else(|| err_msg!("Missing issuer_id"))?;
+        let mut methods = DidMethods::new();
+        for method in did_methods.to_string_vec()? {
+            methods.register(&method)?;
+        }
+        IssuerId::new_unchecked(issuer_id).validate_strict_with_methods(&methods)?;
+        Ok(())
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn anoncreds_version() -> *mut c_char {
     rust_string_to_c(LIB_VERSION.to_owned())
@@ -1,13 +1,16 @@
 use std::any::TypeId;
+use std::cell::RefCell;
 use std::cmp::Eq;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use std::os::raw::c_char;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
-use ffi_support::{rust_string_to_c, ByteBuffer};
+use ffi_support::{rust_string_to_c, ByteBuffer, FfiStr};
 use once_cell::sync::Lazy;
 use serde::Serialize;
 
@@ -15,18 +18,58 @@ use super::error::{catch_error, ErrorCode};
 use crate::error::Result;
 use crate::new_handle_type;
 
-pub static FFI_OBJECTS: Lazy<Mutex<BTreeMap<ObjectHandle, AnoncredsObject>>> =
+pub static FFI_OBJECTS: Lazy<Mutex<BTreeMap<ObjectHandle, StoredObject>>> =
     Lazy::new(|| Mutex::new(BTreeMap::new()));
 
+/// Log the creation and release of every object, and the objects left alive on
+/// [`anoncreds_object_report_leaks`]
+static LEAK_DEBUG: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Scopes opened on the current thread, the innermost last
+    static ACTIVE_SCOPES: RefCell<Vec<ScopeHandle>> = RefCell::new(Vec::new());
+}
+
 new_handle_type!(ObjectHandle, FFI_OBJECT_COUNTER);
 
+new_handle_type!(ScopeHandle, FFI_SCOPE_COUNTER);
+
+/// An object in the store, along with the scope it was created in
+#[derive(Clone, Debug)]
+pub struct StoredObject {
+    pub object: AnoncredsObject,
+    pub scope: Option<ScopeHandle>,
+}
+
+/// Summary of a live object, as returned by [`anoncreds_object_list_handles`]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveObject {
+    pub handle: usize,
+    pub type_name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<usize>,
+}
+
 impl ObjectHandle {
     pub(crate) fn create<O: AnyAnoncredsObject + 'static>(value: O) -> Result<Self> {
         let handle = Self::next();
+        let object = StoredObject {
+            object: AnoncredsObject::new(value),
+            scope: ScopeHandle::current(),
+        };
+        if LEAK_DEBUG.load(Ordering::Relaxed) {
+            debug!(
+                "Created {} {} in scope {:?}",
+                object.object.type_name(),
+                handle,
+                object.scope.map(|scope| scope.0)
+            );
+        }
         FFI_OBJECTS
             .lock()
             .map_err(|_| err_msg!("Error locking object store"))?
-            .insert(handle, AnoncredsObject::new(value));
+            .insert(handle, object);
         Ok(handle)
     }
 
@@ -35,8 +78,8 @@ impl ObjectHandle {
             .lock()
             .map_err(|_| err_msg!("Error locking object store"))?
             .get(&self)
-            .cloned()
-            .ok_or_else(|| err_msg!("Invalid object handle"))
+            .map(|stored| stored.object.clone())
+            .ok_or_else(|| err_msg!("Invalid object handle").with_context("handle", self.0))
     }
 
     pub(crate) fn opt_load(self) -> Result<Option<AnoncredsObject>> {
@@ -48,22 +91,97 @@ impl ObjectHandle {
                     .lock()
                     .map_err(|_| err_msg!("Error locking object store"))?
                     .get(&self)
-                    .cloned()
-                    .ok_or_else(|| err_msg!("Invalid object handle")),
+                    .map(|stored| stored.object.clone())
+                    .ok_or_else(|| {
+                        err_msg!("Invalid object handle").with_context("handle", self.0)
+                    }),
             )
             .transpose()
         }
     }
 
     pub(crate) fn remove(self) -> Result<AnoncredsObject> {
-        FFI_OBJECTS
+        let stored = FFI_OBJECTS
             .lock()
             .map_err(|_| err_msg!("Error locking object store"))?
             .remove(&self)
-            .ok_or_else(|| err_msg!("Invalid object handle"))
+            .ok_or_else(|| err_msg!("Invalid object handle").with_context("handle", self.0))?;
+        if LEAK_DEBUG.load(Ordering::Relaxed) {
+            debug!("Freed {} {}", stored.object.type_name(), self);
+        }
+        Ok(stored.object)
+    }
+}
+
+impl ScopeHandle {
+    /// Open a new scope on the current thread. Objects created on this thread are assigned to
+    /// the innermost open scope.
+    pub(crate) fn begin() -> Self {
+        let scope = Self::next();
+        ACTIVE_SCOPES.with(|scopes| scopes.borrow_mut().push(scope));
+        scope
+    }
+
+    /// Close the scope on the current thread. Its objects stay alive until freed.
+    pub(crate) fn end(self) -> Result<()> {
+        ACTIVE_SCOPES.with(|scopes| {
+            let mut scopes = scopes.borrow_mut();
+            let pos = scopes
+                .iter()
+                .rposition(|scope| *scope == self)
+                .ok_or_else(|| err_msg!("{} is not open on this thread", self))?;
+            scopes.remove(pos);
+            Ok(())
+        })
+    }
+
+    pub(crate) fn current() -> Option<Self> {
+        ACTIVE_SCOPES.with(|scopes| scopes.borrow().last().copied())
+    }
+
+    /// Free all objects created in the scope, returning the number of freed objects
+    pub(crate) fn free_objects(self) -> Result<usize> {
+        let mut objects = FFI_OBJECTS
+            .lock()
+            .map_err(|_| err_msg!("Error locking object store"))?;
+        let before = objects.len();
+        objects.retain(|_, stored| stored.scope != Some(self));
+        let freed = before - objects.len();
+        if LEAK_DEBUG.load(Ordering::Relaxed) {
+            debug!("Freed {} objects of {}", freed, self);
+        }
+        Ok(freed)
     }
 }
 
+/// Summaries of the live objects, optionally restricted to a type name
+pub(crate) fn live_objects(type_name: Option<&str>) -> Result<Vec<LiveObject>> {
+    Ok(FFI_OBJECTS
+        .lock()
+        .map_err(|_| err_msg!("Error locking object store"))?
+        .iter()
+        .filter(|(_, stored)| type_name.map_or(true, |name| stored.object.type_name() == name))
+        .map(|(handle, stored)| LiveObject {
+            handle: handle.0,
+            type_name: stored.object.type_name(),
+            scope: stored.scope.map(|scope| scope.0),
+        })
+        .collect())
+}
+
+/// Number of live objects by type name
+pub(crate) fn live_object_counts() -> Result<BTreeMap<&'static str, usize>> {
+    let mut counts = BTreeMap::new();
+    for stored in FFI_OBJECTS
+        .lock()
+        .map_err(|_| err_msg!("Error locking object store"))?
+        .values()
+    {
+        *counts.entry(stored.object.type_name()).or_default() += 1;
+    }
+    Ok(counts)
+}
+
 #[derive(Clone, Debug)]
 #[repr(transparent)]
 pub struct AnoncredsObject(Arc<dyn AnyAnoncredsObject>);
@@ -82,7 +200,9 @@ impl AnoncredsObject {
                 "Expected {} instance, received {}",
                 result.type_name(),
                 self.0.type_name()
-            ))
+            )
+            .with_context("expected_type", result.type_name())
+            .with_context("type", self.0.type_name()))
         }
     }
 
@@ -99,6 +219,8 @@ impl Hash for AnoncredsObject {
 
 pub trait ToJson {
     fn to_json(&self) -> Result<Vec<u8>>;
+
+    fn to_json_value(&self) -> Result<serde_json::Value>;
 }
 
 impl ToJson for AnoncredsObject {
@@ -106,6 +228,11 @@ impl ToJson for AnoncredsObject {
     fn to_json(&self) -> Result<Vec<u8>> {
         self.0.to_json()
     }
+
+    #[inline]
+    fn to_json_value(&self) -> Result<serde_json::Value> {
+        self.0.to_json_value()
+    }
 }
 
 impl<T> ToJson for T
@@ -115,9 +242,57 @@ where
     fn to_json(&self) -> Result<Vec<u8>> {
         serde_json::to_vec(self).map_err(err_map!("Error serializing object"))
     }
+
+    fn to_json_value(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(self).map_err(err_map!("Error serializing object"))
+    }
 }
 
-pub trait AnyAnoncredsObject: Debug + ToJson + Send + Sync {
+impl AnoncredsObject {
+    /// Look up a field of the JSON form of the object by JSON pointer, such as `/attrNames/0`.
+    /// The leading slash may be omitted.
+    pub fn get_field(&self, path: &str) -> Result<Option<serde_json::Value>> {
+        let value = self.to_json_value()?;
+        let field = if path.is_empty() || path.starts_with('/') {
+            value.pointer(path)
+        } else {
+            value.pointer(&format!("/{path}"))
+        };
+        Ok(field.cloned())
+    }
+}
+
+#[cfg(feature = "cbor")]
+pub trait ToCbor {
+    fn to_cbor(&self) -> Result<Vec<u8>>;
+}
+
+// Without the `cbor` feature the bound is satisfied by every object type
+#[cfg(not(feature = "cbor"))]
+pub trait ToCbor {}
+
+#[cfg(not(feature = "cbor"))]
+impl<T: ?Sized> ToCbor for T {}
+
+#[cfg(feature = "cbor")]
+impl ToCbor for AnoncredsObject {
+    #[inline]
+    fn to_cbor(&self) -> Result<Vec<u8>> {
+        self.0.to_cbor()
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<T> ToCbor for T
+where
+    T: Serialize,
+{
+    fn to_cbor(&self) -> Result<Vec<u8>> {
+        crate::data_types::cbor::to_cbor(self).map_err(err_map!("Error serializing object"))
+    }
+}
+
+pub trait AnyAnoncredsObject: Debug + ToJson + ToCbor + Send + Sync {
     fn type_name(&self) -> &'static str;
 
     #[doc(hidden)]
@@ -157,6 +332,25 @@ macro_rules! impl_anoncreds_object_from_json {
     };
 }
 
+macro_rules! impl_anoncreds_object_from_cbor {
+    ($ident:path, $method:ident) => {
+        #[cfg(feature = "cbor")]
+        #[no_mangle]
+        pub extern "C" fn $method(
+            cbor: ffi_support::ByteBuffer,
+            result_p: *mut $crate::ffi::object::ObjectHandle,
+        ) -> $crate::ffi::error::ErrorCode {
+            $crate::ffi::error::catch_error(|| {
+                check_useful_c_ptr!(result_p);
+                let obj = $crate::data_types::cbor::from_cbor::<$ident>(cbor.as_slice())?;
+                let handle = $crate::ffi::object::ObjectHandle::create(obj)?;
+                unsafe { *result_p = handle };
+                Ok(())
+            })
+        }
+    };
+}
+
 #[no_mangle]
 pub extern "C" fn anoncreds_object_get_json(
     handle: ObjectHandle,
@@ -171,6 +365,21 @@ pub extern "C" fn anoncreds_object_get_json(
     })
 }
 
+#[cfg(feature = "cbor")]
+#[no_mangle]
+pub extern "C" fn anoncreds_object_get_cbor(
+    handle: ObjectHandle,
+    result_p: *mut ByteBuffer,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let obj = handle.load()?;
+        let cbor = obj.to_cbor()?;
+        unsafe { *result_p = ByteBuffer::from_vec(cbor) };
+        Ok(())
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn anoncreds_object_get_type_name(
     handle: ObjectHandle,
@@ -185,11 +394,173 @@ pub extern "C" fn anoncreds_object_get_type_name(
     })
 }
 
+/// Get a field of an object by JSON pointer as a JSON string, or null when it is not present
+#[no_mangle]
+pub extern "C" fn anoncreds_object_get_field(
+    handle: ObjectHandle,
+    path: FfiStr,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let path = path.as_opt_str().unwrap_or_default();
+        let field = handle.load()?.get_field(path)?;
+        let val = match field {
+            Some(field) => rust_string_to_c(
+                serde_json::to_string(&field).map_err(err_map!("Error serializing field"))?,
+            ),
+            None => ptr::null_mut(),
+        };
+        unsafe { *result_p = val };
+        Ok(())
+    })
+}
+
+/// Get a string field of an object by JSON pointer, or null when it is not present or null
+#[no_mangle]
+pub extern "C" fn anoncreds_object_get_field_str(
+    handle: ObjectHandle,
+    path: FfiStr,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let path = path.as_opt_str().unwrap_or_default();
+        let val = match handle.load()?.get_field(path)? {
+            Some(serde_json::Value::String(s)) => rust_string_to_c(s),
+            None | Some(serde_json::Value::Null) => ptr::null_mut(),
+            Some(_) => return Err(err_msg!("Field is not a string").with_context("path", path)),
+        };
+        unsafe { *result_p = val };
+        Ok(())
+    })
+}
+
+/// Get an integer field of an object by JSON pointer
+#[no_mangle]
+pub extern "C" fn anoncreds_object_get_field_i64(
+    handle: ObjectHandle,
+    path: FfiStr,
+    result_p: *mut i64,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let path = path.as_opt_str().unwrap_or_default();
+        let val = handle
+            .load()?
+            .get_field(path)?
+            .ok_or_else(|| err_msg!("Field not found").with_context("path", path))?
+            .as_i64()
+            .ok_or_else(|| err_msg!("Field is not an integer").with_context("path", path))?;
+        unsafe { *result_p = val };
+        Ok(())
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn anoncreds_object_free(handle: ObjectHandle) {
     handle.remove().ok();
 }
 
+/// Count the live objects of a type, or of all types when `type_name` is null
+#[no_mangle]
+pub extern "C" fn anoncreds_object_count(type_name: FfiStr, result_p: *mut usize) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let count = live_objects(type_name.as_opt_str())?.len();
+        unsafe { *result_p = count };
+        Ok(())
+    })
+}
+
+/// Get the number of live objects by type name as a JSON object
+#[no_mangle]
+pub extern "C" fn anoncreds_object_get_counts(result_p: *mut *const c_char) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let counts = serde_json::to_string(&live_object_counts()?)
+            .map_err(err_map!("Error serializing object counts"))?;
+        unsafe { *result_p = rust_string_to_c(counts) };
+        Ok(())
+    })
+}
+
+/// List the live objects of a type, or of all types when `type_name` is null, as a JSON array
+/// of `{"handle", "typeName", "scope"}` entries
+#[no_mangle]
+pub extern "C" fn anoncreds_object_list_handles(
+    type_name: FfiStr,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let objects = serde_json::to_string(&live_objects(type_name.as_opt_str())?)
+            .map_err(err_map!("Error serializing object list"))?;
+        unsafe { *result_p = rust_string_to_c(objects) };
+        Ok(())
+    })
+}
+
+/// Open a scope on the calling thread. Objects created on this thread until the scope is ended
+/// belong to it and can be released together with [`anoncreds_object_free_scope`].
+#[no_mangle]
+pub extern "C" fn anoncreds_object_scope_begin(result_p: *mut ScopeHandle) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let scope = ScopeHandle::begin();
+        unsafe { *result_p = scope };
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn anoncreds_object_scope_end(scope: ScopeHandle) -> ErrorCode {
+    catch_error(|| scope.end())
+}
+
+/// Free all objects created in a scope, returning the number of freed objects
+#[no_mangle]
+pub extern "C" fn anoncreds_object_free_scope(
+    scope: ScopeHandle,
+    result_p: *mut usize,
+) -> ErrorCode {
+    catch_error(|| {
+        let freed = scope.free_objects()?;
+        if !result_p.is_null() {
+            unsafe { *result_p = freed };
+        }
+        Ok(())
+    })
+}
+
+/// Enable or disable logging of object lifetimes
+#[no_mangle]
+pub extern "C" fn anoncreds_object_set_leak_debug(enabled: i8) -> ErrorCode {
+    LEAK_DEBUG.store(enabled != 0, Ordering::Relaxed);
+    ErrorCode::Success
+}
+
+/// Report the objects left alive, to be called on shutdown. Every live object is logged as a
+/// warning when leak debugging is enabled.
+#[no_mangle]
+pub extern "C" fn anoncreds_object_report_leaks(result_p: *mut usize) -> ErrorCode {
+    catch_error(|| {
+        let objects = live_objects(None)?;
+        if LEAK_DEBUG.load(Ordering::Relaxed) {
+            for object in &objects {
+                warn!(
+                    "{} ObjectHandle({}) left alive in scope {:?}",
+                    object.type_name, object.handle, object.scope
+                );
+            }
+        }
+        if !result_p.is_null() {
+            unsafe { *result_p = objects.len() };
+        }
+        Ok(())
+    })
+}
+
 #[repr(transparent)]
 pub struct AnoncredsObjectList(Vec<AnoncredsObject>);
 
@@ -214,7 +585,7 @@ impl AnoncredsObjectList {
         Ok(refs)
     }
 
-    pub fn refs_map<'a, I, T>(&'a self, ids: &'a [I]) -> Result<HashMap<&I, &T>>
+    pub fn refs_map<'a, I, T>(&'a self, ids: &'a [I]) -> Result<HashMap<&'a I, &'a T>>
     where
         T: AnyAnoncredsObject + 'static,
         I: Eq + Hash,
@@ -241,3 +612,83 @@ impl DerefMut for AnoncredsObjectList {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issuer::create_schema;
+
+    fn new_schema() -> ObjectHandle {
+        ObjectHandle::create(
+            create_schema(
+                "name",
+                "1.0",
+                "mock:uri".try_into().unwrap(),
+                vec!["attr".to_owned()].into(),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_free_objects_by_scope() {
+        let outer = ScopeHandle::begin();
+        let in_outer = new_schema();
+        let inner = ScopeHandle::begin();
+        let in_inner = new_schema();
+        inner.end().unwrap();
+        let after_inner = new_schema();
+        outer.end().unwrap();
+        assert!(outer.end().is_err());
+
+        let scopes = live_objects(Some("Schema"))
+            .unwrap()
+            .into_iter()
+            .filter(|object| {
+                [in_outer, in_inner, after_inner].contains(&ObjectHandle(object.handle))
+            })
+            .map(|object| object.scope)
+            .collect::<Vec<_>>();
+        assert_eq!(scopes, vec![Some(outer.0), Some(inner.0), Some(outer.0)]);
+
+        assert_eq!(inner.free_objects().unwrap(), 1);
+        assert!(in_inner.load().is_err());
+        assert_eq!(outer.free_objects().unwrap(), 2);
+        assert!(in_outer.load().is_err());
+        assert!(after_inner.load().is_err());
+    }
+
+    #[test]
+    fn test_get_field() {
+        let schema = new_schema();
+        let obj = schema.load().unwrap();
+        assert_eq!(
+            obj.get_field("attrNames").unwrap(),
+            Some(serde_json::json!(["attr"]))
+        );
+        assert_eq!(
+            obj.get_field("/attrNames/0").unwrap(),
+            Some(serde_json::json!("attr"))
+        );
+        assert_eq!(
+            obj.get_field("issuerId").unwrap(),
+            Some(serde_json::json!("mock:uri"))
+        );
+        assert_eq!(obj.get_field("missing").unwrap(), None);
+        schema.remove().unwrap();
+    }
+
+    #[test]
+    fn test_schema_get_attr_names() {
+        let schema = new_schema();
+        let mut names: *const c_char = ptr::null();
+        assert_eq!(
+            crate::ffi::schema::anoncreds_schema_get_attr_names(schema, &mut names),
+            ErrorCode::Success
+        );
+        let names = unsafe { std::ffi::CString::from_raw(names as *mut c_char) };
+        assert_eq!(names.to_str().unwrap(), r#"["attr"]"#);
+        schema.remove().unwrap();
+    }
+}
@@ -10,6 +10,10 @@ impl_anoncreds_object_from_json!(
     PresentationRequest,
     anoncreds_presentation_request_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    PresentationRequest,
+    anoncreds_presentation_request_from_cbor
+);
 
 #[no_mangle]
 pub extern "C" fn anoncreds_generate_nonce(nonce_p: *mut *const c_char) -> ErrorCode {
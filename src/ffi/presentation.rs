@@ -11,13 +11,54 @@ use crate::data_types::schema::{Schema, SchemaId};
 use crate::error::Result;
 use crate::services::prover::create_presentation;
 use crate::services::types::PresentCredentials;
-use crate::services::verifier::verify_presentation;
-use ffi_support::FfiStr;
+use crate::services::verifier::{omitted_referents, verify_presentation};
+use ffi_support::{rust_string_to_c, FfiStr};
 use std::collections::HashMap;
+use std::os::raw::c_char;
 
 impl_anoncreds_object!(Presentation, "Presentation");
 impl_anoncreds_object_from_json!(Presentation, anoncreds_presentation_from_json);
+impl_anoncreds_object_from_cbor!(Presentation, anoncreds_presentation_from_cbor);
 
+/// Get the identifiers of a presentation as a JSON array
+#[no_mangle]
+pub extern "C" fn anoncreds_presentation_get_identifiers(
+    handle: ObjectHandle,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let presentation = handle.load()?;
+        let presentation = presentation.cast_ref::<Presentation>()?;
+        let identifiers = serde_json::to_string(&presentation.identifiers)
+            .map_err(err_map!("Error serializing presentation identifiers"))?;
+        unsafe { *result_p = rust_string_to_c(identifiers) };
+        Ok(())
+    })
+}
+
+/// Get the optional referents of a presentation request left out of a presentation as a JSON
+/// array
+#[no_mangle]
+pub extern "C" fn anoncreds_presentation_get_omitted_referents(
+    presentation: ObjectHandle,
+    pres_req: ObjectHandle,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let omitted = omitted_referents(
+            presentation.load()?.cast_ref()?,
+            pres_req.load()?.cast_ref()?,
+        );
+        let omitted = serde_json::to_string(&omitted)
+            .map_err(err_map!("Error serializing omitted referents"))?;
+        unsafe { *result_p = rust_string_to_c(omitted) };
+        Ok(())
+    })
+}
+
+/// Deprecated in favour of [`FfiCredentialEntryV2`], which has a 64-bit timestamp
 #[derive(Debug)]
 #[repr(C)]
 pub struct FfiCredentialEntry {
@@ -27,6 +68,26 @@ pub struct FfiCredentialEntry {
 }
 
 impl FfiCredentialEntry {
+    fn load(&self) -> Result<CredentialEntry> {
+        FfiCredentialEntryV2 {
+            credential: self.credential,
+            timestamp: i64::from(self.timestamp),
+            rev_state: self.rev_state,
+        }
+        .load()
+    }
+}
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct FfiCredentialEntryV2 {
+    credential: ObjectHandle,
+    /// Timestamp of the revocation state, negative when there is none
+    timestamp: i64,
+    rev_state: ObjectHandle,
+}
+
+impl FfiCredentialEntryV2 {
     fn load(&self) -> Result<CredentialEntry> {
         let credential = self.credential.load()?;
         let timestamp = if self.timestamp < 0 {
@@ -58,6 +119,7 @@ struct CredentialEntry {
     rev_state: Option<AnoncredsObject>,
 }
 
+/// Deprecated in favour of [`anoncreds_create_presentation_v2`], which takes 64-bit timestamps
 #[no_mangle]
 pub extern "C" fn anoncreds_create_presentation(
     pres_req: ObjectHandle,
@@ -73,136 +135,187 @@ pub extern "C" fn anoncreds_create_presentation(
     presentation_p: *mut ObjectHandle,
 ) -> ErrorCode {
     catch_error(|| {
-        check_useful_c_ptr!(presentation_p);
-
-        let link_secret = link_secret
-            .as_opt_str()
-            .ok_or_else(|| err_msg!("Missing link secret"))?;
-        let link_secret = LinkSecret::try_from(link_secret)?;
+        let entries = credentials.try_collect(FfiCredentialEntry::load)?;
+        create_presentation_with_entries(
+            pres_req,
+            entries,
+            credentials_prove,
+            self_attest_names,
+            self_attest_values,
+            link_secret,
+            schemas,
+            schema_ids,
+            cred_defs,
+            cred_def_ids,
+            presentation_p,
+        )
+    })
+}
 
-        if self_attest_names.len() != self_attest_values.len() {
-            return Err(err_msg!(
-                "Inconsistent lengths for self-attested value parameters"
-            ));
-        }
+#[no_mangle]
+pub extern "C" fn anoncreds_create_presentation_v2(
+    pres_req: ObjectHandle,
+    credentials: FfiList<FfiCredentialEntryV2>,
+    credentials_prove: FfiList<FfiCredentialProve>,
+    self_attest_names: FfiStrList,
+    self_attest_values: FfiStrList,
+    link_secret: FfiStr,
+    schemas: FfiList<ObjectHandle>,
+    schema_ids: FfiStrList,
+    cred_defs: FfiList<ObjectHandle>,
+    cred_def_ids: FfiStrList,
+    presentation_p: *mut ObjectHandle,
+) -> ErrorCode {
+    catch_error(|| {
+        let entries = credentials.try_collect(FfiCredentialEntryV2::load)?;
+        create_presentation_with_entries(
+            pres_req,
+            entries,
+            credentials_prove,
+            self_attest_names,
+            self_attest_values,
+            link_secret,
+            schemas,
+            schema_ids,
+            cred_defs,
+            cred_def_ids,
+            presentation_p,
+        )
+    })
+}
 
-        if schemas.len() != schema_ids.len() {
-            return Err(err_msg!("Inconsistent lengths for schemas and schemas ids"));
-        }
+#[allow(clippy::too_many_arguments)]
+fn create_presentation_with_entries(
+    pres_req: ObjectHandle,
+    entries: Vec<CredentialEntry>,
+    credentials_prove: FfiList<FfiCredentialProve>,
+    self_attest_names: FfiStrList,
+    self_attest_values: FfiStrList,
+    link_secret: FfiStr,
+    schemas: FfiList<ObjectHandle>,
+    schema_ids: FfiStrList,
+    cred_defs: FfiList<ObjectHandle>,
+    cred_def_ids: FfiStrList,
+    presentation_p: *mut ObjectHandle,
+) -> Result<()> {
+    check_useful_c_ptr!(presentation_p);
+
+    let link_secret = link_secret
+        .as_opt_str()
+        .ok_or_else(|| err_msg!("Missing link secret"))?;
+    let link_secret = LinkSecret::try_from(link_secret)?;
+
+    if self_attest_names.len() != self_attest_values.len() {
+        return Err(err_msg!(
+            "Inconsistent lengths for self-attested value parameters"
+        ));
+    }
 
-        if cred_defs.len() != cred_def_ids.len() {
-            return Err(err_msg!(
-                "Inconsistent lengths for cred defs and cred def ids"
-            ));
-        }
+    if schemas.len() != schema_ids.len() {
+        return Err(err_msg!("Inconsistent lengths for schemas and schemas ids"));
+    }
 
-        let entries = {
-            let credentials = credentials.as_slice();
-            credentials.iter().try_fold(
-                Vec::with_capacity(credentials.len()),
-                |mut r, ffi_entry| {
-                    r.push(ffi_entry.load()?);
-                    Result::Ok(r)
-                },
-            )?
-        };
+    if cred_defs.len() != cred_def_ids.len() {
+        return Err(err_msg!(
+            "Inconsistent lengths for cred defs and cred def ids"
+        ));
+    }
 
-        let self_attested = if self_attest_names.is_empty() {
-            None
-        } else {
-            let mut self_attested = HashMap::new();
-            for (name, raw) in self_attest_names
-                .as_slice()
-                .iter()
-                .zip(self_attest_values.as_slice())
-            {
-                let name = name
-                    .as_opt_str()
-                    .ok_or_else(|| err_msg!("Missing attribute name"))?
-                    .to_string();
-                let raw = raw
-                    .as_opt_str()
-                    .ok_or_else(|| err_msg!("Missing attribute raw value"))?
-                    .to_string();
-                self_attested.insert(name, raw);
+    let self_attested = if self_attest_names.is_empty() {
+        None
+    } else {
+        let mut self_attested = HashMap::new();
+        for (name, raw) in self_attest_names
+            .as_slice()
+            .iter()
+            .zip(self_attest_values.as_slice())
+        {
+            let name = name
+                .as_opt_str()
+                .ok_or_else(|| err_msg!("Missing attribute name"))?
+                .to_string();
+            let raw = raw
+                .as_opt_str()
+                .ok_or_else(|| err_msg!("Missing attribute raw value"))?
+                .to_string();
+            self_attested.insert(name, raw);
+        }
+        Some(self_attested)
+    };
+
+    let mut present_creds = PresentCredentials::default();
+
+    for (entry_idx, entry) in entries.iter().enumerate() {
+        let mut add_cred = present_creds.add_credential(
+            entry.credential.cast_ref()?,
+            entry.timestamp,
+            entry
+                .rev_state
+                .as_ref()
+                .map(AnoncredsObject::cast_ref)
+                .transpose()?,
+        );
+
+        for prove in credentials_prove.as_slice() {
+            if prove.entry_idx < 0 {
+                return Err(err_msg!("Invalid credential index"));
+            }
+            if prove.entry_idx as usize != entry_idx {
+                continue;
             }
-            Some(self_attested)
-        };
 
-        let mut present_creds = PresentCredentials::default();
-
-        for (entry_idx, entry) in entries.iter().enumerate() {
-            let mut add_cred = present_creds.add_credential(
-                entry.credential.cast_ref()?,
-                entry.timestamp,
-                entry
-                    .rev_state
-                    .as_ref()
-                    .map(AnoncredsObject::cast_ref)
-                    .transpose()?,
-            );
-
-            for prove in credentials_prove.as_slice() {
-                if prove.entry_idx < 0 {
-                    return Err(err_msg!("Invalid credential index"));
-                }
-                if prove.entry_idx as usize != entry_idx {
-                    continue;
-                }
-
-                let referent = prove
-                    .referent
-                    .as_opt_str()
-                    .ok_or_else(|| err_msg!("Missing referent for credential proof info"))?
-                    .to_string();
-
-                if prove.is_predicate == 0 {
-                    add_cred.add_requested_attribute(referent, prove.reveal != 0);
-                } else {
-                    add_cred.add_requested_predicate(referent);
-                }
+            let referent = prove
+                .referent
+                .as_opt_str()
+                .ok_or_else(|| err_msg!("Missing referent for credential proof info"))?
+                .to_string();
+
+            if prove.is_predicate == 0 {
+                add_cred.add_requested_attribute(referent, prove.reveal != 0);
+            } else {
+                add_cred.add_requested_predicate(referent);
             }
         }
+    }
 
-        let mut schema_identifiers: Vec<SchemaId> = vec![];
-        for schema_id in &schema_ids.to_string_vec()? {
-            let s = SchemaId::new(schema_id.as_str())?;
-            schema_identifiers.push(s);
-        }
+    let mut schema_identifiers: Vec<SchemaId> = vec![];
+    for schema_id in &schema_ids.to_string_vec()? {
+        let s = SchemaId::new(schema_id.as_str())?;
+        schema_identifiers.push(s);
+    }
 
-        let mut cred_def_identifiers: Vec<CredentialDefinitionId> = vec![];
-        for cred_def_id in &cred_def_ids.to_string_vec()? {
-            let cred_def_id = CredentialDefinitionId::new(cred_def_id.as_str())?;
-            cred_def_identifiers.push(cred_def_id);
-        }
+    let mut cred_def_identifiers: Vec<CredentialDefinitionId> = vec![];
+    for cred_def_id in &cred_def_ids.to_string_vec()? {
+        let cred_def_id = CredentialDefinitionId::new(cred_def_id.as_str())?;
+        cred_def_identifiers.push(cred_def_id);
+    }
 
-        let schemas = AnoncredsObjectList::load(schemas.as_slice())?;
-        let schemas = schemas
-            .refs_map::<SchemaId, Schema>(&schema_identifiers)?
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
-
-        let cred_defs = AnoncredsObjectList::load(cred_defs.as_slice())?;
-        let cred_defs = cred_defs
-            .refs_map::<CredentialDefinitionId, CredentialDefinition>(&cred_def_identifiers)?
-            .into_iter()
-            .map(|(k, v)| v.try_clone().map(|v| (k.clone(), v)))
-            .collect::<Result<_>>()?;
-
-        let presentation = create_presentation(
-            pres_req.load()?.cast_ref()?,
-            present_creds,
-            self_attested,
-            &link_secret,
-            &schemas,
-            &cred_defs,
-        )?;
-
-        let presentation = ObjectHandle::create(presentation)?;
-        unsafe { *presentation_p = presentation };
-        Ok(())
-    })
+    let schemas = AnoncredsObjectList::load(schemas.as_slice())?;
+    let schemas = schemas
+        .refs_map::<SchemaId, Schema>(&schema_identifiers)?
+        .into_iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+
+    let cred_defs = AnoncredsObjectList::load(cred_defs.as_slice())?;
+    let cred_defs = cred_defs
+        .refs_map::<CredentialDefinitionId, CredentialDefinition>(&cred_def_identifiers)?
+        .into_iter()
+        .map(|(k, v)| v.try_clone().map(|v| (k.clone(), v)))
+        .collect::<Result<_>>()?;
+
+    let presentation = create_presentation(
+        pres_req.load()?.cast_ref()?,
+        present_creds,
+        self_attested,
+        &link_secret,
+        &schemas,
+        &cred_defs,
+    )?;
+
+    let presentation = ObjectHandle::create(presentation)?;
+    unsafe { *presentation_p = presentation };
+    Ok(())
 }
 
 /// Optional value for overriding the non-revoked interval in the [`PresentationRequest`]
@@ -225,30 +338,60 @@ pub extern "C" fn anoncreds_create_presentation(
 ///
 #[derive(Debug)]
 #[repr(C)]
-pub struct FfiNonrevokedIntervalOverride<'a> {
+pub struct FfiNonrevokedIntervalOverrideV2<'a> {
     rev_reg_def_id: FfiStr<'a>,
     /// Timestamp in the `PresentationRequest`
-    requested_from_ts: i32,
+    requested_from_ts: i64,
     /// Timestamp from which verifier accepts,
     /// should be less than `req_timestamp`
+    override_rev_status_list_ts: i64,
+}
+
+impl<'a> FfiNonrevokedIntervalOverrideV2<'a> {
+    fn load(&self) -> Result<(RevocationRegistryDefinitionId, u64, u64)> {
+        load_interval_override(
+            &self.rev_reg_def_id,
+            self.requested_from_ts,
+            self.override_rev_status_list_ts,
+        )
+    }
+}
+
+/// Deprecated in favour of [`FfiNonrevokedIntervalOverrideV2`], which has 64-bit timestamps
+#[derive(Debug)]
+#[repr(C)]
+pub struct FfiNonrevokedIntervalOverride<'a> {
+    rev_reg_def_id: FfiStr<'a>,
+    requested_from_ts: i32,
     override_rev_status_list_ts: i32,
 }
 
 impl<'a> FfiNonrevokedIntervalOverride<'a> {
     fn load(&self) -> Result<(RevocationRegistryDefinitionId, u64, u64)> {
-        let id = RevocationRegistryDefinitionId::new(self.rev_reg_def_id.as_str().to_owned())?;
-        let requested_from_ts = self
-            .requested_from_ts
-            .try_into()
-            .map_err(|_| err_msg!("Invalid req timestamp "))?;
-        let override_rev_status_list_ts = self
-            .override_rev_status_list_ts
-            .try_into()
-            .map_err(|_| err_msg!("Invalid override timestamp "))?;
-        Ok((id, requested_from_ts, override_rev_status_list_ts))
+        load_interval_override(
+            &self.rev_reg_def_id,
+            i64::from(self.requested_from_ts),
+            i64::from(self.override_rev_status_list_ts),
+        )
     }
 }
 
+fn load_interval_override(
+    rev_reg_def_id: &FfiStr,
+    requested_from_ts: i64,
+    override_rev_status_list_ts: i64,
+) -> Result<(RevocationRegistryDefinitionId, u64, u64)> {
+    let id = RevocationRegistryDefinitionId::new(rev_reg_def_id.as_str().to_owned())?;
+    let requested_from_ts = requested_from_ts
+        .try_into()
+        .map_err(|_| err_msg!("Invalid req timestamp "))?;
+    let override_rev_status_list_ts = override_rev_status_list_ts
+        .try_into()
+        .map_err(|_| err_msg!("Invalid override timestamp "))?;
+    Ok((id, requested_from_ts, override_rev_status_list_ts))
+}
+
+/// Deprecated in favour of [`anoncreds_verify_presentation_v2`], which takes 64-bit timestamps
 #[no_mangle]
 pub extern "C" fn anoncreds_verify_presentation(
     presentation: ObjectHandle,
@@ -264,106 +407,160 @@ pub extern "C" fn anoncreds_verify_presentation(
     result_p: *mut i8,
 ) -> ErrorCode {
     catch_error(|| {
-        if schemas.len() != schema_ids.len() {
-            return Err(err_msg!("Inconsistent lengths for schemas and schemas ids"));
-        }
+        let override_entries =
+            nonrevoked_interval_override.try_collect(FfiNonrevokedIntervalOverride::load)?;
+        verify_presentation_with_overrides(
+            presentation,
+            pres_req,
+            schemas,
+            schema_ids,
+            cred_defs,
+            cred_def_ids,
+            rev_reg_defs,
+            rev_reg_def_ids,
+            rev_status_list,
+            override_entries,
+            result_p,
+        )
+    })
+}
 
-        if cred_defs.len() != cred_def_ids.len() {
-            return Err(err_msg!(
-                "Inconsistent lengths for cred defs and cred def ids"
-            ));
-        }
+#[no_mangle]
+pub extern "C" fn anoncreds_verify_presentation_v2(
+    presentation: ObjectHandle,
+    pres_req: ObjectHandle,
+    schemas: FfiList<ObjectHandle>,
+    schema_ids: FfiStrList,
+    cred_defs: FfiList<ObjectHandle>,
+    cred_def_ids: FfiStrList,
+    rev_reg_defs: FfiList<ObjectHandle>,
+    rev_reg_def_ids: FfiStrList,
+    rev_status_list: FfiList<ObjectHandle>,
+    nonrevoked_interval_override: FfiList<FfiNonrevokedIntervalOverrideV2>,
+    result_p: *mut i8,
+) -> ErrorCode {
+    catch_error(|| {
+        let override_entries =
+            nonrevoked_interval_override.try_collect(FfiNonrevokedIntervalOverrideV2::load)?;
+        verify_presentation_with_overrides(
+            presentation,
+            pres_req,
+            schemas,
+            schema_ids,
+            cred_defs,
+            cred_def_ids,
+            rev_reg_defs,
+            rev_reg_def_ids,
+            rev_status_list,
+            override_entries,
+            result_p,
+        )
+    })
+}
 
-        if rev_reg_defs.len() != rev_reg_def_ids.len() {
-            return Err(err_msg!(
-                "Inconsistent lengths for rev reg defs and rev reg def ids"
-            ));
-        }
+#[allow(clippy::too_many_arguments)]
+fn verify_presentation_with_overrides(
+    presentation: ObjectHandle,
+    pres_req: ObjectHandle,
+    schemas: FfiList<ObjectHandle>,
+    schema_ids: FfiStrList,
+    cred_defs: FfiList<ObjectHandle>,
+    cred_def_ids: FfiStrList,
+    rev_reg_defs: FfiList<ObjectHandle>,
+    rev_reg_def_ids: FfiStrList,
+    rev_status_list: FfiList<ObjectHandle>,
+    override_entries: Vec<(RevocationRegistryDefinitionId, u64, u64)>,
+    result_p: *mut i8,
+) -> Result<()> {
+    if schemas.len() != schema_ids.len() {
+        return Err(err_msg!("Inconsistent lengths for schemas and schemas ids"));
+    }
 
-        let mut schema_identifiers: Vec<SchemaId> = vec![];
-        for schema_id in schema_ids.as_slice().iter() {
-            let s = SchemaId::new(schema_id.as_str())?;
-            schema_identifiers.push(s);
-        }
+    if cred_defs.len() != cred_def_ids.len() {
+        return Err(err_msg!(
+            "Inconsistent lengths for cred defs and cred def ids"
+        ));
+    }
 
-        let mut cred_def_identifiers: Vec<CredentialDefinitionId> = vec![];
-        for cred_def_id in cred_def_ids.as_slice().iter() {
-            let cred_def_id = CredentialDefinitionId::new(cred_def_id.as_str())?;
-            cred_def_identifiers.push(cred_def_id);
-        }
+    if rev_reg_defs.len() != rev_reg_def_ids.len() {
+        return Err(err_msg!(
+            "Inconsistent lengths for rev reg defs and rev reg def ids"
+        ));
+    }
 
-        let mut rev_reg_def_identifiers: Vec<RevocationRegistryDefinitionId> = vec![];
-        for rev_reg_def_id in rev_reg_def_ids.as_slice().iter() {
-            let rev_reg_def_id = RevocationRegistryDefinitionId::new(rev_reg_def_id.as_str())?;
-            rev_reg_def_identifiers.push(rev_reg_def_id);
-        }
+    let mut schema_identifiers: Vec<SchemaId> = vec![];
+    for schema_id in schema_ids.as_slice().iter() {
+        let s = SchemaId::new(schema_id.as_str())?;
+        schema_identifiers.push(s);
+    }
 
-        let schemas = AnoncredsObjectList::load(schemas.as_slice())?;
-        let schemas = schemas
-            .refs_map::<SchemaId, Schema>(&schema_identifiers)?
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect();
-
-        let cred_defs = AnoncredsObjectList::load(cred_defs.as_slice())?;
-        let cred_defs = cred_defs
-            .refs_map::<CredentialDefinitionId, CredentialDefinition>(&cred_def_identifiers)?
-            .into_iter()
-            .map(|(k, v)| v.try_clone().map(|v| (k.clone(), v)))
-            .collect::<Result<_>>()?;
-
-        let rev_reg_defs = AnoncredsObjectList::load(rev_reg_defs.as_slice())?;
-        let rev_reg_defs = rev_reg_defs
-            .refs_map::<RevocationRegistryDefinitionId, RevocationRegistryDefinition>(
-                &rev_reg_def_identifiers,
-            )?
-            .into_iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
-            .collect::<HashMap<_, _>>();
-
-        let rev_reg_defs = if rev_reg_defs.is_empty() {
-            None
-        } else {
-            Some(&rev_reg_defs)
-        };
+    let mut cred_def_identifiers: Vec<CredentialDefinitionId> = vec![];
+    for cred_def_id in cred_def_ids.as_slice().iter() {
+        let cred_def_id = CredentialDefinitionId::new(cred_def_id.as_str())?;
+        cred_def_identifiers.push(cred_def_id);
+    }
 
-        let rev_status_list: AnoncredsObjectList =
-            AnoncredsObjectList::load(rev_status_list.as_slice())?;
-        let rev_status_list: Result<Vec<&RevocationStatusList>> = rev_status_list.refs();
-        let rev_status_list = rev_status_list.ok();
-
-        let override_entries = {
-            let override_ffi_entries = nonrevoked_interval_override.as_slice();
-            override_ffi_entries.iter().try_fold(
-                Vec::with_capacity(override_ffi_entries.len()),
-                |mut v, entry| -> Result<Vec<(RevocationRegistryDefinitionId, u64, u64)>> {
-                    v.push(entry.load()?);
-                    Ok(v)
-                },
-            )?
-        };
-        let mut map_nonrevoked_interval_override = HashMap::new();
-        for (id, req_timestamp, override_timestamp) in &override_entries {
-            map_nonrevoked_interval_override
-                .entry(id.clone())
-                .or_insert_with(HashMap::new)
-                .insert(*req_timestamp, *override_timestamp);
-        }
+    let mut rev_reg_def_identifiers: Vec<RevocationRegistryDefinitionId> = vec![];
+    for rev_reg_def_id in rev_reg_def_ids.as_slice().iter() {
+        let rev_reg_def_id = RevocationRegistryDefinitionId::new(rev_reg_def_id.as_str())?;
+        rev_reg_def_identifiers.push(rev_reg_def_id);
+    }
 
-        let rev_status_lists = rev_status_list
-            .as_ref()
-            .map(|v| v.iter().copied().cloned().collect());
+    let schemas = AnoncredsObjectList::load(schemas.as_slice())?;
+    let schemas = schemas
+        .refs_map::<SchemaId, Schema>(&schema_identifiers)?
+        .into_iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+
+    let cred_defs = AnoncredsObjectList::load(cred_defs.as_slice())?;
+    let cred_defs = cred_defs
+        .refs_map::<CredentialDefinitionId, CredentialDefinition>(&cred_def_identifiers)?
+        .into_iter()
+        .map(|(k, v)| v.try_clone().map(|v| (k.clone(), v)))
+        .collect::<Result<_>>()?;
+
+    let rev_reg_defs = AnoncredsObjectList::load(rev_reg_defs.as_slice())?;
+    let rev_reg_defs = rev_reg_defs
+        .refs_map::<RevocationRegistryDefinitionId, RevocationRegistryDefinition>(
+            &rev_reg_def_identifiers,
+        )?
+        .into_iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let rev_reg_defs = if rev_reg_defs.is_empty() {
+        None
+    } else {
+        Some(&rev_reg_defs)
+    };
+
+    let rev_status_list: AnoncredsObjectList =
+        AnoncredsObjectList::load(rev_status_list.as_slice())?;
+    let rev_status_list: Result<Vec<&RevocationStatusList>> = rev_status_list.refs();
+    let rev_status_list = rev_status_list.ok();
+
+    let mut map_nonrevoked_interval_override = HashMap::new();
+    for (id, req_timestamp, override_timestamp) in &override_entries {
+        map_nonrevoked_interval_override
+            .entry(id.clone())
+            .or_insert_with(HashMap::new)
+            .insert(*req_timestamp, *override_timestamp);
+    }
 
-        let verify = verify_presentation(
-            presentation.load()?.cast_ref()?,
-            pres_req.load()?.cast_ref()?,
-            &schemas,
-            &cred_defs,
-            rev_reg_defs,
-            rev_status_lists,
-            Some(&map_nonrevoked_interval_override),
-        )?;
-        unsafe { *result_p = i8::from(verify) };
-        Ok(())
-    })
+    let rev_status_lists = rev_status_list
+        .as_ref()
+        .map(|v| v.iter().copied().cloned().collect());
+
+    let verify = verify_presentation(
+        presentation.load()?.cast_ref()?,
+        pres_req.load()?.cast_ref()?,
+        &schemas,
+        &cred_defs,
+        rev_reg_defs,
+        rev_status_lists,
+        Some(&map_nonrevoked_interval_override),
+    )?;
+    unsafe { *result_p = i8::from(verify) };
+    Ok(())
 }
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use super::error::{catch_error, ErrorCode};
+use crate::error::Result;
+use crate::new_handle_type;
+use crate::services::progress::{CancellationToken, Progress};
+
+/// Called with the callback id, the number of completed steps and the total number of steps
+pub type ProgressCallback = extern "C" fn(cb_id: i64, done: u64, total: u64);
+
+new_handle_type!(CancellationHandle, FFI_CANCELLATION_COUNTER);
+
+static FFI_CANCELLATION_TOKENS: Lazy<Mutex<BTreeMap<CancellationHandle, CancellationToken>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+impl CancellationHandle {
+    fn load(self) -> Result<CancellationToken> {
+        FFI_CANCELLATION_TOKENS
+            .lock()
+            .map_err(|_| err_msg!("Error locking cancellation token store"))?
+            .get(&self)
+            .cloned()
+            .ok_or_else(|| err_msg!("Invalid cancellation token handle: {}", self))
+    }
+
+    fn opt_load(self) -> Result<Option<CancellationToken>> {
+        if self.0 == 0 {
+            Ok(None)
+        } else {
+            self.load().map(Some)
+        }
+    }
+}
+
+/// Run `f` reporting to the optional callback and observing the optional cancellation token
+pub(crate) fn with_progress<T>(
+    callback: Option<ProgressCallback>,
+    cb_id: i64,
+    cancellation: CancellationHandle,
+    f: impl FnOnce(&Progress<'_>) -> Result<T>,
+) -> Result<T> {
+    let cancellation = cancellation.opt_load()?;
+    let report = |done, total| {
+        if let Some(callback) = callback {
+            callback(cb_id, done, total);
+        }
+    };
+    let mut progress = Progress::new().with_callback(&report);
+    if let Some(cancellation) = cancellation.as_ref() {
+        progress = progress.with_cancellation(cancellation);
+    }
+    f(&progress)
+}
+
+#[no_mangle]
+pub extern "C" fn anoncreds_cancellation_token_create(
+    result_p: *mut CancellationHandle,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let handle = CancellationHandle::next();
+        FFI_CANCELLATION_TOKENS
+            .lock()
+            .map_err(|_| err_msg!("Error locking cancellation token store"))?
+            .insert(handle, CancellationToken::new());
+        unsafe { *result_p = handle };
+        Ok(())
+    })
+}
+
+/// Cancel the operations observing the token. Can be called from any thread.
+#[no_mangle]
+pub extern "C" fn anoncreds_cancellation_token_cancel(handle: CancellationHandle) -> ErrorCode {
+    catch_error(|| {
+        handle.load()?.cancel();
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn anoncreds_cancellation_token_free(handle: CancellationHandle) {
+    if let Ok(mut tokens) = FFI_CANCELLATION_TOKENS.lock() {
+        tokens.remove(&handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_through_handle() {
+        let mut handle = CancellationHandle::invalid();
+        assert_eq!(
+            anoncreds_cancellation_token_create(&mut handle),
+            ErrorCode::Success
+        );
+        assert_eq!(
+            anoncreds_cancellation_token_cancel(handle),
+            ErrorCode::Success
+        );
+        let result = with_progress(None, 0, handle, |progress| progress.check_cancelled());
+        assert_kind!(Cancelled, result);
+
+        anoncreds_cancellation_token_free(handle);
+        assert_eq!(
+            anoncreds_cancellation_token_cancel(handle),
+            ErrorCode::Input
+        );
+    }
+}
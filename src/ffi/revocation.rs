@@ -1,5 +1,6 @@
 use super::error::{catch_error, ErrorCode};
 use super::object::{AnoncredsObject, ObjectHandle};
+use super::progress::{with_progress, CancellationHandle, ProgressCallback};
 use super::util::FfiList;
 use crate::data_types::rev_status_list::RevocationStatusList;
 use crate::data_types::{
@@ -8,13 +9,17 @@ use crate::data_types::{
         RegistryType, RevocationRegistryDefinition, RevocationRegistryDefinitionPrivate,
     },
 };
+use crate::error::Result;
 use crate::issuer;
-use crate::services::issuer::create_revocation_registry_def;
-use crate::services::prover::create_or_update_revocation_state;
-use crate::services::tails::TailsFileWriter;
+use crate::services::issuer::create_revocation_registry_def_with_progress;
+use crate::services::prover::create_or_update_revocation_state_with_progress;
+use crate::services::tails::{
+    InMemoryTailsWriter, TailsFileWriter, TailsStreamWriter, TailsWriter,
+};
 use crate::services::types::CredentialRevocationState;
-use ffi_support::{rust_string_to_c, FfiStr};
+use ffi_support::{rust_string_to_c, ByteBuffer, FfiStr};
 use std::collections::BTreeSet;
+use std::io::{self, BufWriter, Write};
 use std::os::raw::c_char;
 use std::str::FromStr;
 
@@ -28,6 +33,36 @@ pub extern "C" fn anoncreds_create_revocation_status_list(
     issuance_by_default: i8,
     timestamp: i64,
     rev_status_list_p: *mut ObjectHandle,
+) -> ErrorCode {
+    anoncreds_create_revocation_status_list_with_progress(
+        cred_def,
+        rev_reg_def_id,
+        rev_reg_def,
+        reg_rev_priv,
+        _issuer_id,
+        issuance_by_default,
+        timestamp,
+        None,
+        0,
+        CancellationHandle::invalid(),
+        rev_status_list_p,
+    )
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn anoncreds_create_revocation_status_list_with_progress(
+    cred_def: ObjectHandle,
+    rev_reg_def_id: FfiStr,
+    rev_reg_def: ObjectHandle,
+    reg_rev_priv: ObjectHandle,
+    _issuer_id: FfiStr,
+    issuance_by_default: i8,
+    timestamp: i64,
+    progress_cb: Option<ProgressCallback>,
+    progress_cb_id: i64,
+    cancellation: CancellationHandle,
+    rev_status_list_p: *mut ObjectHandle,
 ) -> ErrorCode {
     catch_error(|| {
         check_useful_c_ptr!(rev_status_list_p);
@@ -42,14 +77,18 @@ pub extern "C" fn anoncreds_create_revocation_status_list(
             Some(timestamp as u64)
         };
 
-        let rev_status_list = issuer::create_revocation_status_list(
-            cred_def.load()?.cast_ref()?,
-            rev_reg_def_id,
-            rev_reg_def.load()?.cast_ref()?,
-            reg_rev_priv.load()?.cast_ref()?,
-            issuance_by_default != 0,
-            timestamp,
-        )?;
+        let rev_status_list =
+            with_progress(progress_cb, progress_cb_id, cancellation, |progress| {
+                issuer::create_revocation_status_list_with_progress(
+                    cred_def.load()?.cast_ref()?,
+                    rev_reg_def_id,
+                    rev_reg_def.load()?.cast_ref()?,
+                    reg_rev_priv.load()?.cast_ref()?,
+                    issuance_by_default != 0,
+                    timestamp,
+                    progress,
+                )
+            })?;
 
         let rev_status_list_handle = ObjectHandle::create(rev_status_list)?;
 
@@ -139,47 +178,233 @@ pub extern "C" fn anoncreds_create_revocation_registry_def(
     tails_dir_path: FfiStr,
     reg_def_p: *mut ObjectHandle,
     reg_def_private_p: *mut ObjectHandle,
+) -> ErrorCode {
+    anoncreds_create_revocation_registry_def_with_progress(
+        cred_def,
+        cred_def_id,
+        _issuer_id,
+        tag,
+        rev_reg_type,
+        max_cred_num,
+        tails_dir_path,
+        None,
+        0,
+        CancellationHandle::invalid(),
+        reg_def_p,
+        reg_def_private_p,
+    )
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn anoncreds_create_revocation_registry_def_with_progress(
+    cred_def: ObjectHandle,
+    cred_def_id: FfiStr,
+    _issuer_id: FfiStr,
+    tag: FfiStr,
+    rev_reg_type: FfiStr,
+    max_cred_num: i64,
+    tails_dir_path: FfiStr,
+    progress_cb: Option<ProgressCallback>,
+    progress_cb_id: i64,
+    cancellation: CancellationHandle,
+    reg_def_p: *mut ObjectHandle,
+    reg_def_private_p: *mut ObjectHandle,
 ) -> ErrorCode {
     catch_error(|| {
-        check_useful_c_ptr!(reg_def_p);
-        check_useful_c_ptr!(reg_def_private_p);
-        let tag = tag.as_opt_str().ok_or_else(|| err_msg!("Missing tag"))?;
-        let cred_def_id = cred_def_id
-            .as_opt_str()
-            .ok_or_else(|| err_msg!("Missing cred def id"))?
-            .try_into()?;
-        let rev_reg_type = {
-            let rtype = rev_reg_type
-                .as_opt_str()
-                .ok_or_else(|| err_msg!("Missing registry type"))?;
-            RegistryType::from_str(rtype).map_err(err_map!(Input))?
-        };
         let mut tails_writer = TailsFileWriter::new(tails_dir_path.into_opt_string());
-        let (reg_def, reg_def_private) = create_revocation_registry_def(
-            cred_def.load()?.cast_ref()?,
+        create_revocation_registry_def_with_writer(
+            cred_def,
             cred_def_id,
             tag,
             rev_reg_type,
-            max_cred_num
-                .try_into()
-                .map_err(|_| err_msg!("Invalid maximum credential count"))?,
+            max_cred_num,
             &mut tails_writer,
+            progress_cb,
+            progress_cb_id,
+            cancellation,
+            reg_def_p,
+            reg_def_private_p,
+        )
+    })
+}
+
+/// Create a revocation registry definition, returning the tails in `tails_p` instead of writing
+/// them to a file
+///
+/// The tails location defaults to the tails hash.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn anoncreds_create_revocation_registry_def_in_memory(
+    cred_def: ObjectHandle,
+    cred_def_id: FfiStr,
+    tag: FfiStr,
+    rev_reg_type: FfiStr,
+    max_cred_num: i64,
+    tails_location: FfiStr,
+    progress_cb: Option<ProgressCallback>,
+    progress_cb_id: i64,
+    cancellation: CancellationHandle,
+    reg_def_p: *mut ObjectHandle,
+    reg_def_private_p: *mut ObjectHandle,
+    tails_p: *mut ByteBuffer,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(tails_p);
+        let mut tails_writer = InMemoryTailsWriter::new();
+        if let Some(location) = tails_location.as_opt_str() {
+            tails_writer = tails_writer.with_location(location);
+        }
+        create_revocation_registry_def_with_writer(
+            cred_def,
+            cred_def_id,
+            tag,
+            rev_reg_type,
+            max_cred_num,
+            &mut tails_writer,
+            progress_cb,
+            progress_cb_id,
+            cancellation,
+            reg_def_p,
+            reg_def_private_p,
         )?;
-        let reg_def = ObjectHandle::create(reg_def)?;
-        let reg_def_private = ObjectHandle::create(reg_def_private)?;
-        unsafe {
-            *reg_def_p = reg_def;
-            *reg_def_private_p = reg_def_private;
-        };
+        unsafe { *tails_p = ByteBuffer::from_vec(tails_writer.into_tails()) };
         Ok(())
     })
 }
 
+/// Called with the callback id and a chunk of the tails, returns zero when the chunk was written
+pub type TailsWriteCallback = extern "C" fn(cb_id: i64, data: *const u8, len: usize) -> i8;
+
+#[derive(Debug)]
+struct CallbackTailsSink {
+    callback: TailsWriteCallback,
+    cb_id: i64,
+}
+
+impl Write for CallbackTailsSink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if (self.callback)(self.cb_id, buf.as_ptr(), buf.len()) == 0 {
+            Ok(buf.len())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Tails write callback failed",
+            ))
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Create a revocation registry definition, streaming the tails to `write_cb`
+///
+/// The tails location defaults to the tails hash.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn anoncreds_create_revocation_registry_def_with_sink(
+    cred_def: ObjectHandle,
+    cred_def_id: FfiStr,
+    tag: FfiStr,
+    rev_reg_type: FfiStr,
+    max_cred_num: i64,
+    tails_location: FfiStr,
+    write_cb: Option<TailsWriteCallback>,
+    write_cb_id: i64,
+    progress_cb: Option<ProgressCallback>,
+    progress_cb_id: i64,
+    cancellation: CancellationHandle,
+    reg_def_p: *mut ObjectHandle,
+    reg_def_private_p: *mut ObjectHandle,
+) -> ErrorCode {
+    catch_error(|| {
+        let callback = write_cb.ok_or_else(|| err_msg!("Missing tails write callback"))?;
+        let sink = BufWriter::new(CallbackTailsSink {
+            callback,
+            cb_id: write_cb_id,
+        });
+        let mut tails_writer = TailsStreamWriter::new(sink);
+        if let Some(location) = tails_location.as_opt_str() {
+            tails_writer = tails_writer.with_location(location);
+        }
+        create_revocation_registry_def_with_writer(
+            cred_def,
+            cred_def_id,
+            tag,
+            rev_reg_type,
+            max_cred_num,
+            &mut tails_writer,
+            progress_cb,
+            progress_cb_id,
+            cancellation,
+            reg_def_p,
+            reg_def_private_p,
+        )
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_revocation_registry_def_with_writer<TW: TailsWriter>(
+    cred_def: ObjectHandle,
+    cred_def_id: FfiStr,
+    tag: FfiStr,
+    rev_reg_type: FfiStr,
+    max_cred_num: i64,
+    tails_writer: &mut TW,
+    progress_cb: Option<ProgressCallback>,
+    progress_cb_id: i64,
+    cancellation: CancellationHandle,
+    reg_def_p: *mut ObjectHandle,
+    reg_def_private_p: *mut ObjectHandle,
+) -> Result<()> {
+    check_useful_c_ptr!(reg_def_p);
+    check_useful_c_ptr!(reg_def_private_p);
+    let tag = tag.as_opt_str().ok_or_else(|| err_msg!("Missing tag"))?;
+    let cred_def_id = cred_def_id
+        .as_opt_str()
+        .ok_or_else(|| err_msg!("Missing cred def id"))?
+        .try_into()?;
+    let rev_reg_type = {
+        let rtype = rev_reg_type
+            .as_opt_str()
+            .ok_or_else(|| err_msg!("Missing registry type"))?;
+        RegistryType::from_str(rtype).map_err(err_map!(Input))?
+    };
+    let max_cred_num = max_cred_num
+        .try_into()
+        .map_err(|_| err_msg!("Invalid maximum credential count"))?;
+    let (reg_def, reg_def_private) =
+        with_progress(progress_cb, progress_cb_id, cancellation, |progress| {
+            create_revocation_registry_def_with_progress(
+                cred_def.load()?.cast_ref()?,
+                cred_def_id,
+                tag,
+                rev_reg_type,
+                max_cred_num,
+                tails_writer,
+                progress,
+            )
+        })?;
+    let reg_def = ObjectHandle::create(reg_def)?;
+    let reg_def_private = ObjectHandle::create(reg_def_private)?;
+    unsafe {
+        *reg_def_p = reg_def;
+        *reg_def_private_p = reg_def_private;
+    };
+    Ok(())
+}
+
 impl_anoncreds_object!(RevocationRegistryDefinition, "RevocationRegistryDefinition");
 impl_anoncreds_object_from_json!(
     RevocationRegistryDefinition,
     anoncreds_revocation_registry_definition_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    RevocationRegistryDefinition,
+    anoncreds_revocation_registry_definition_from_cbor
+);
 
 #[no_mangle]
 pub extern "C" fn anoncreds_revocation_registry_definition_get_attribute(
@@ -210,15 +435,45 @@ impl_anoncreds_object_from_json!(
     RevocationRegistryDefinitionPrivate,
     anoncreds_revocation_registry_definition_private_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    RevocationRegistryDefinitionPrivate,
+    anoncreds_revocation_registry_definition_private_from_cbor
+);
 
 impl_anoncreds_object!(RevocationRegistry, "RevocationRegistry");
 impl_anoncreds_object_from_json!(RevocationRegistry, anoncreds_revocation_registry_from_json);
+impl_anoncreds_object_from_cbor!(RevocationRegistry, anoncreds_revocation_registry_from_cbor);
 
 impl_anoncreds_object!(RevocationStatusList, "RevocationStatusList");
 impl_anoncreds_object_from_json!(
     RevocationStatusList,
     anoncreds_revocation_status_list_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    RevocationStatusList,
+    anoncreds_revocation_status_list_from_cbor
+);
+
+/// Get the timestamp of a revocation status list
+///
+/// Fails when the status list has not been given a timestamp.
+#[no_mangle]
+pub extern "C" fn anoncreds_revocation_status_list_get_timestamp(
+    handle: ObjectHandle,
+    result_p: *mut i64,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let list = handle.load()?;
+        let list = list.cast_ref::<RevocationStatusList>()?;
+        let timestamp = list
+            .timestamp()
+            .ok_or_else(|| err_msg!("Revocation status list has no timestamp"))?;
+        let timestamp = i64::try_from(timestamp).map_err(|_| err_msg!("Timestamp out of range"))?;
+        unsafe { *result_p = timestamp };
+        Ok(())
+    })
+}
 
 #[no_mangle]
 pub extern "C" fn anoncreds_create_or_update_revocation_state(
@@ -229,6 +484,34 @@ pub extern "C" fn anoncreds_create_or_update_revocation_state(
     rev_state: ObjectHandle,
     old_rev_status_list: ObjectHandle,
     rev_state_p: *mut ObjectHandle,
+) -> ErrorCode {
+    anoncreds_create_or_update_revocation_state_with_progress(
+        rev_reg_def,
+        rev_status_list,
+        rev_reg_index,
+        tails_path,
+        rev_state,
+        old_rev_status_list,
+        None,
+        0,
+        CancellationHandle::invalid(),
+        rev_state_p,
+    )
+}
+
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn anoncreds_create_or_update_revocation_state_with_progress(
+    rev_reg_def: ObjectHandle,
+    rev_status_list: ObjectHandle,
+    rev_reg_index: i64,
+    tails_path: FfiStr,
+    rev_state: ObjectHandle,
+    old_rev_status_list: ObjectHandle,
+    progress_cb: Option<ProgressCallback>,
+    progress_cb_id: i64,
+    cancellation: CancellationHandle,
+    rev_state_p: *mut ObjectHandle,
 ) -> ErrorCode {
     catch_error(|| {
         check_useful_c_ptr!(rev_state_p);
@@ -237,22 +520,26 @@ pub extern "C" fn anoncreds_create_or_update_revocation_state(
         let tails_path = tails_path
             .as_opt_str()
             .ok_or_else(|| err_msg!("Missing tails file path"))?;
-        let rev_state = create_or_update_revocation_state(
-            tails_path,
-            rev_reg_def.load()?.cast_ref()?,
-            rev_status_list.load()?.cast_ref()?,
-            rev_reg_index
-                .try_into()
-                .map_err(|_| err_msg!("Invalid credential revocation index"))?,
-            prev_rev_state
-                .as_ref()
-                .map(AnoncredsObject::cast_ref)
-                .transpose()?,
-            prev_rev_status_list
-                .as_ref()
-                .map(AnoncredsObject::cast_ref)
-                .transpose()?,
-        )?;
+        let rev_reg_index = rev_reg_index
+            .try_into()
+            .map_err(|_| err_msg!("Invalid credential revocation index"))?;
+        let rev_state = with_progress(progress_cb, progress_cb_id, cancellation, |progress| {
+            create_or_update_revocation_state_with_progress(
+                tails_path,
+                rev_reg_def.load()?.cast_ref()?,
+                rev_status_list.load()?.cast_ref()?,
+                rev_reg_index,
+                prev_rev_state
+                    .as_ref()
+                    .map(AnoncredsObject::cast_ref)
+                    .transpose()?,
+                prev_rev_status_list
+                    .as_ref()
+                    .map(AnoncredsObject::cast_ref)
+                    .transpose()?,
+                progress,
+            )
+        })?;
         let rev_state = ObjectHandle::create(rev_state)?;
         unsafe { *rev_state_p = rev_state };
         Ok(())
@@ -264,3 +551,7 @@ impl_anoncreds_object_from_json!(
     CredentialRevocationState,
     anoncreds_revocation_state_from_json
 );
+impl_anoncreds_object_from_cbor!(
+    CredentialRevocationState,
+    anoncreds_revocation_state_from_cbor
+);
@@ -1,4 +1,6 @@
-use ffi_support::FfiStr;
+use std::os::raw::c_char;
+
+use ffi_support::{rust_string_to_c, FfiStr};
 
 use super::error::{catch_error, ErrorCode};
 use super::object::ObjectHandle;
@@ -40,3 +42,21 @@ pub extern "C" fn anoncreds_create_schema(
 
 impl_anoncreds_object!(Schema, "Schema");
 impl_anoncreds_object_from_json!(Schema, anoncreds_schema_from_json);
+impl_anoncreds_object_from_cbor!(Schema, anoncreds_schema_from_cbor);
+
+/// Get the attribute names of a schema as a JSON array
+#[no_mangle]
+pub extern "C" fn anoncreds_schema_get_attr_names(
+    handle: ObjectHandle,
+    result_p: *mut *const c_char,
+) -> ErrorCode {
+    catch_error(|| {
+        check_useful_c_ptr!(result_p);
+        let schema = handle.load()?;
+        let schema = schema.cast_ref::<Schema>()?;
+        let names = serde_json::to_string(&schema.attr_names)
+            .map_err(err_map!("Error serializing attribute names"))?;
+        unsafe { *result_p = rust_string_to_c(names) };
+        Ok(())
+    })
+}
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::cl::{
     bn::BigNumber, CredentialSchema, CredentialValues, Issuer, NonCredentialSchema,
@@ -70,6 +71,69 @@ pub fn build_credential_values(
     Ok(res)
 }
 
+/// Check credential values against the attribute names of the schema
+///
+/// All missing and unexpected attributes, and all attributes whose encoded value does not match
+/// the encoding of the raw value, are reported in a single error.
+pub fn check_credential_values<S: AsRef<str>>(
+    attr_names: &[S],
+    credential_values: &HashMap<String, AttributeValues>,
+) -> Result<()> {
+    let mismatches = credential_values_mismatches(attr_names, credential_values)?;
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(err_msg!(
+            "Credential values do not match the schema: {}",
+            mismatches.join(", ")
+        ))
+    }
+}
+
+/// Describe every mismatch between credential values and the attribute names of the schema,
+/// ordered by attribute name
+pub fn credential_values_mismatches<S: AsRef<str>>(
+    attr_names: &[S],
+    credential_values: &HashMap<String, AttributeValues>,
+) -> Result<Vec<String>> {
+    let expected: HashSet<String> = attr_names
+        .iter()
+        .map(|name| attr_common_view(name.as_ref()))
+        .collect();
+
+    let mut mismatches = Vec::new();
+    let mut received = HashSet::with_capacity(credential_values.len());
+    let mut names: Vec<&String> = credential_values.keys().collect();
+    names.sort();
+
+    for name in names {
+        let common_name = attr_common_view(name);
+        if !received.insert(common_name.clone()) {
+            mismatches.push(format!("`{name}`: duplicate attribute"));
+        } else if !expected.contains(&common_name) {
+            mismatches.push(format!("`{name}`: not in schema"));
+        }
+        let values = &credential_values[name];
+        if encode_credential_attribute(&values.raw)? != values.encoded {
+            mismatches.push(format!("`{name}`: encoded value does not match raw value"));
+        }
+    }
+
+    let mut missing: Vec<&String> = expected.difference(&received).collect();
+    missing.sort();
+    for name in missing {
+        mismatches.push(format!("`{name}`: missing value"));
+    }
+
+    Ok(mismatches)
+}
+
+/// Encode a raw attribute value for signing
+///
+/// Integers which fit in 32 bits are encoded as themselves, and can be used in predicates. Any
+/// other value, including larger integers, is encoded as the SHA-256 digest of its UTF-8 bytes
+/// read as a big-endian unsigned integer, in decimal.
 pub fn encode_credential_attribute(raw_value: &str) -> Result<String> {
     if let Ok(val) = raw_value.parse::<i32>() {
         Ok(val.to_string())
@@ -123,7 +187,7 @@ pub fn build_sub_proof_request(
         sub_proof_request_builder.add_predicate(
             &attr_common_view(&predicate.name),
             &p_type,
-            predicate.p_value,
+            predicate.range_value()?,
         )?;
     }
 
@@ -138,6 +202,14 @@ pub fn new_nonce() -> Result<Nonce> {
     Nonce::new().map_err(err_map!(Unexpected))
 }
 
+/// Current time as seconds since the Unix epoch
+pub fn current_timestamp() -> Result<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .map_err(err_map!(Unexpected, "System time is before the Unix epoch"))
+}
+
 pub fn get_revealed_attributes_for_credential(
     sub_proof_index: usize,
     requested_proof: &RequestedProof,
@@ -176,8 +248,9 @@ pub fn get_revealed_attributes_for_credential(
                 sub_proof_index == revealed_attr_info.sub_proof_index as usize
                     && pres_req.requested_attributes.contains_key(attr_referent)
             })
-            .map(|(attr_referent, _)| {
-                let info = pres_req.requested_attributes[attr_referent].clone();
+            .map(|(attr_referent, revealed_attr_info)| {
+                let info = pres_req.requested_attributes[attr_referent]
+                    .with_optional_names(|name| revealed_attr_info.values.contains_key(name));
                 if let Some(int) = &info.non_revoked {
                     match non_revoked_interval.as_mut() {
                         Some(ni) => {
@@ -211,14 +284,12 @@ pub fn get_predicates_for_credential(
     let predicates_for_credential = requested_proof
         .predicates
         .iter()
-        .filter(|&(predicate_referent, requested_referent)| {
+        .filter(|&(_, requested_referent)| {
             sub_proof_index == requested_referent.sub_proof_index as usize
-                && pres_req
-                    .requested_predicates
-                    .contains_key(predicate_referent)
         })
-        .map(|(predicate_referent, _)| {
-            let info = pres_req.requested_predicates[predicate_referent].clone();
+        .filter_map(|(predicate_referent, _)| pres_req.predicates_for_referent(predicate_referent))
+        .flatten()
+        .inspect(|info| {
             if let Some(int) = &info.non_revoked {
                 match non_revoked_interval.as_mut() {
                     Some(ni) => {
@@ -227,8 +298,6 @@ pub fn get_predicates_for_credential(
                     None => non_revoked_interval = Some(int.clone()),
                 }
             };
-
-            info
         })
         .collect::<Vec<PredicateInfo>>();
 
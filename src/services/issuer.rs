@@ -1,10 +1,16 @@
-use crate::cl::{Issuer, RevocationRegistry as CryptoRevocationRegistry};
+use crate::cl::{
+    Accumulator, CredentialPublicKey, Issuer, RevocationKeyPrivate, RevocationKeyPublic,
+    RevocationRegistry as CryptoRevocationRegistry, RevocationTailsGenerator,
+};
 use crate::data_types::cred_def::CredentialDefinitionId;
 use crate::data_types::issuer_id::IssuerId;
 use crate::data_types::rev_reg_def::RevocationRegistryDefinitionId;
 use crate::data_types::schema::SchemaId;
 use crate::data_types::{
-    cred_def::{CredentialDefinition, CredentialDefinitionData},
+    cred_def::{
+        rotated_tag, CredentialDefinition, CredentialDefinitionData,
+        CredentialDefinitionSupersedes, RotationReason,
+    },
     nonce::Nonce,
     rev_reg_def::{RevocationRegistryDefinitionValue, RevocationRegistryDefinitionValuePublicKeys},
     schema::Schema,
@@ -12,12 +18,14 @@ use crate::data_types::{
 use crate::error::{Error, ErrorKind, Result};
 use crate::services::helpers::{
     build_credential_schema, build_credential_values, build_non_credential_schema,
+    check_credential_values,
 };
 use crate::types::{CredentialDefinitionConfig, CredentialRevocationConfig};
 use crate::utils::validation::Validatable;
 use bitvec::bitvec;
 use std::collections::BTreeSet;
 
+use super::progress::Progress;
 use super::tails::TailsWriter;
 use super::types::{
     AttributeNames, Credential, CredentialDefinitionPrivate, CredentialKeyCorrectnessProof,
@@ -170,6 +178,122 @@ pub fn create_credential_definition(
     Ok((cred_def, cred_def_private, cred_key_proof))
 }
 
+/// Rotate the keys of a credential definition, e.g. after its private part was compromised
+///
+/// A new credential definition is created for the same schema and issuer, with revocation support
+/// matching the superseded one. Its tag is derived with [`rotated_tag`], so `default-tag` becomes
+/// `default-tag-v2`. Next to the new credential definition a [`CredentialDefinitionSupersedes`]
+/// record is returned. This record can be published alongside the new credential definition so
+/// verifiers can accept or reject credentials issued under `cred_def_id` by policy, see
+/// [`crate::verifier::verify_superseded_credential_definitions`].
+///
+/// Credentials issued under the superseded credential definition are not affected. Holders have to
+/// request new credentials to migrate.
+///
+/// # Example
+///
+/// ```rust
+/// use anoncreds::issuer;
+/// use anoncreds::types::CredentialDefinitionConfig;
+/// use anoncreds::types::SignatureType;
+/// use anoncreds::data_types::issuer_id::IssuerId;
+/// use anoncreds::data_types::schema::SchemaId;
+/// use anoncreds::data_types::cred_def::{CredentialDefinitionId, RotationReason};
+///
+/// let attribute_names: &[&str] = &["name", "age"];
+/// let issuer_id = IssuerId::new("did:web:xyz").expect("Invalid issuer ID");
+/// let schema_id = SchemaId::new("did:web:xyz/resource/schema").expect("Invalid schema ID");
+/// let cred_def_id = CredentialDefinitionId::new("did:web:xyz/resource/cred-def").expect("Invalid credential definition ID");
+///
+/// let schema = issuer::create_schema("schema name",
+///                                    "1.0",
+///                                    issuer_id.clone(),
+///                                    attribute_names.into()
+///                                    ).expect("Unable to create schema");
+///
+/// let (cred_def, _cred_def_priv, _key_correctness_proof) =
+///     issuer::create_credential_definition(schema_id,
+///                                          &schema,
+///                                          issuer_id,
+///                                          "default-tag",
+///                                          SignatureType::CL,
+///                                          CredentialDefinitionConfig::default()
+///                                          ).expect("Unable to create Credential Definition");
+///
+/// let (new_cred_def, new_cred_def_priv, new_key_correctness_proof, supersedes) =
+///     issuer::rotate_credential_definition(cred_def_id,
+///                                          &cred_def,
+///                                          &schema,
+///                                          RotationReason::KeyCompromise,
+///                                          Some(10)
+///                                          ).expect("Unable to rotate Credential Definition");
+///
+/// assert_eq!(new_cred_def.tag, "default-tag-v2");
+/// ```
+pub fn rotate_credential_definition(
+    cred_def_id: CredentialDefinitionId,
+    cred_def: &CredentialDefinition,
+    schema: &Schema,
+    reason: RotationReason,
+    timestamp: Option<u64>,
+) -> Result<(
+    CredentialDefinition,
+    CredentialDefinitionPrivate,
+    CredentialKeyCorrectnessProof,
+    CredentialDefinitionSupersedes,
+)> {
+    trace!(
+        "rotate_credential_definition >>> cred_def_id: {:?}, cred_def: {:?}, schema: {:?}, reason: {:?}, timestamp: {:?}",
+        cred_def_id,
+        cred_def,
+        schema,
+        reason,
+        timestamp
+    );
+
+    cred_def_id.validate()?;
+    cred_def.validate()?;
+
+    let tag = rotated_tag(&cred_def.tag);
+    let config = CredentialDefinitionConfig::new(cred_def.value.revocation.is_some());
+
+    let (new_cred_def, new_cred_def_private, new_cred_key_proof) = create_credential_definition(
+        cred_def.schema_id.clone(),
+        schema,
+        cred_def.issuer_id.clone(),
+        &tag,
+        cred_def.signature_type,
+        config,
+    )?;
+
+    let supersedes = CredentialDefinitionSupersedes {
+        supersedes: cred_def_id,
+        schema_id: cred_def.schema_id.clone(),
+        issuer_id: cred_def.issuer_id.clone(),
+        previous_tag: cred_def.tag.clone(),
+        tag,
+        reason,
+        timestamp,
+    };
+
+    supersedes.validate()?;
+
+    trace!(
+        "rotate_credential_definition <<< cred_def: {:?}, cred_def_private: {:?}, key_correctness_proof: {:?}, supersedes: {:?}",
+        new_cred_def,
+        secret!(&new_cred_def_private),
+        new_cred_key_proof,
+        supersedes
+    );
+
+    Ok((
+        new_cred_def,
+        new_cred_def_private,
+        new_cred_key_proof,
+        supersedes,
+    ))
+}
+
 /// Create an Anoncreds revocation registry definition according to the [Anoncreds v1.0 -
 /// Revocation Registry
 /// Definition](https://hyperledger.github.io/anoncreds-spec/#issuer-create-and-publish-revocation-registry-objects).
@@ -233,6 +357,35 @@ pub fn create_revocation_registry_def<TW>(
     RevocationRegistryDefinition,
     RevocationRegistryDefinitionPrivate,
 )>
+where
+    TW: TailsWriter,
+{
+    create_revocation_registry_def_with_progress(
+        cred_def,
+        cred_def_id,
+        tag,
+        rev_reg_type,
+        max_cred_num,
+        tails_writer,
+        &Progress::default(),
+    )
+}
+
+/// Variant of [`create_revocation_registry_def`] reporting the tails generation to `progress`
+///
+/// Every generated tail is a step, `2 * max_cred_num + 1` in total.
+pub fn create_revocation_registry_def_with_progress<TW>(
+    cred_def: &CredentialDefinition,
+    cred_def_id: CredentialDefinitionId,
+    tag: &str,
+    rev_reg_type: RegistryType,
+    max_cred_num: u32,
+    tails_writer: &mut TW,
+    progress: &Progress<'_>,
+) -> Result<(
+    RevocationRegistryDefinition,
+    RevocationRegistryDefinitionPrivate,
+)>
 where
     TW: TailsWriter,
 {
@@ -244,20 +397,65 @@ where
         "Error fetching public key from credential definition"
     ))?;
 
-    // NOTE: registry is created with issuance_by_default: false  and it is not used.
-    // The accum value in the registry is derived from issuance by default: false in `create_revocation_status_list`
-    let (revoc_key_pub, revoc_key_priv, _, mut rev_tails_generator) =
-        Issuer::new_revocation_registry_def(&credential_pub_key, max_cred_num, false)?;
+    let (revoc_key_pub, revoc_key_priv, mut rev_tails_generator) =
+        new_revocation_registry_keys(&credential_pub_key, max_cred_num)?;
 
-    let rev_keys_pub = RevocationRegistryDefinitionValuePublicKeys {
-        accum_key: revoc_key_pub,
-    };
+    let tails = tails_writer.write_with_progress(&mut rev_tails_generator, progress)?;
 
-    let (tails_location, tails_hash) = tails_writer.write(&mut rev_tails_generator)?;
+    let (revoc_reg_def, revoc_def_priv) = build_revocation_registry_def(
+        cred_def,
+        cred_def_id,
+        tag,
+        rev_reg_type,
+        max_cred_num,
+        (revoc_key_pub, revoc_key_priv),
+        tails,
+    );
+
+    trace!(
+        "create_revocation_registry <<< revoc_reg_def: {:?}, private: {:?}",
+        revoc_reg_def,
+        secret!(&revoc_def_priv),
+    );
+
+    Ok((revoc_reg_def, revoc_def_priv))
+}
 
+/// Generate the keys of a revocation registry, along with the generator of its tails
+pub(crate) fn new_revocation_registry_keys(
+    credential_pub_key: &CredentialPublicKey,
+    max_cred_num: u32,
+) -> Result<(
+    RevocationKeyPublic,
+    RevocationKeyPrivate,
+    RevocationTailsGenerator,
+)> {
+    // NOTE: registry is created with issuance_by_default: false  and it is not used.
+    // The accum value in the registry is derived from issuance by default: false in `create_revocation_status_list`
+    let (revoc_key_pub, revoc_key_priv, _, rev_tails_generator) =
+        Issuer::new_revocation_registry_def(credential_pub_key, max_cred_num, false)?;
+    Ok((revoc_key_pub, revoc_key_priv, rev_tails_generator))
+}
+
+/// Assemble a revocation registry definition from its keys and the location and hash of its
+/// written tails
+pub(crate) fn build_revocation_registry_def(
+    cred_def: &CredentialDefinition,
+    cred_def_id: CredentialDefinitionId,
+    tag: &str,
+    rev_reg_type: RegistryType,
+    max_cred_num: u32,
+    (revoc_key_pub, revoc_key_priv): (RevocationKeyPublic, RevocationKeyPrivate),
+    (tails_location, tails_hash): (String, String),
+) -> (
+    RevocationRegistryDefinition,
+    RevocationRegistryDefinitionPrivate,
+) {
     let revoc_reg_def_value = RevocationRegistryDefinitionValue {
         max_cred_num,
-        public_keys: rev_keys_pub,
+        public_keys: RevocationRegistryDefinitionValuePublicKeys {
+            accum_key: revoc_key_pub,
+        },
         tails_location,
         tails_hash,
     };
@@ -274,13 +472,7 @@ where
         value: revoc_key_priv,
     };
 
-    trace!(
-        "create_revocation_registry <<< revoc_reg_def: {:?}, private: {:?}",
-        revoc_reg_def,
-        secret!(&revoc_def_priv),
-    );
-
-    Ok((revoc_reg_def, revoc_def_priv))
+    (revoc_reg_def, revoc_def_priv)
 }
 
 /// Create an Anoncreds Revocation Status List according to the [Anoncreds v1.0 - Revocation Status
@@ -350,15 +542,67 @@ pub fn create_revocation_status_list(
     rev_reg_priv: &RevocationRegistryDefinitionPrivate,
     issuance_by_default: bool,
     timestamp: Option<u64>,
+) -> Result<RevocationStatusList> {
+    create_revocation_status_list_with_progress(
+        cred_def,
+        rev_reg_def_id,
+        rev_reg_def,
+        rev_reg_priv,
+        issuance_by_default,
+        timestamp,
+        &Progress::default(),
+    )
+}
+
+/// Number of registry indexes added to the initial accumulator at once
+const ACCUMULATOR_BATCH_SIZE: u32 = 1024;
+
+/// Variant of [`create_revocation_status_list`] reporting to `progress`
+///
+/// Every registry index added to the initial accumulator is reported as a step. With
+/// `issuance_by_default` unset, the accumulator is empty and the operation completes at once.
+pub fn create_revocation_status_list_with_progress(
+    cred_def: &CredentialDefinition,
+    rev_reg_def_id: RevocationRegistryDefinitionId,
+    rev_reg_def: &RevocationRegistryDefinition,
+    rev_reg_priv: &RevocationRegistryDefinitionPrivate,
+    issuance_by_default: bool,
+    timestamp: Option<u64>,
+    progress: &Progress<'_>,
 ) -> Result<RevocationStatusList> {
     let max_cred_num = rev_reg_def.value.max_cred_num;
     let cred_pub_key = cred_def.get_public_key()?;
-    let rev_reg = CryptoRevocationRegistry::initial_state(
+    progress.start(u64::from(max_cred_num))?;
+    let mut rev_reg = CryptoRevocationRegistry::initial_state(
         &cred_pub_key,
         &rev_reg_priv.value,
         max_cred_num,
-        issuance_by_default,
+        false,
     )?;
+    if issuance_by_default {
+        let mut start = 1;
+        while start <= max_cred_num {
+            let end = start
+                .saturating_add(ACCUMULATOR_BATCH_SIZE - 1)
+                .min(max_cred_num);
+            let batch = CryptoRevocationRegistry::for_issued(
+                &cred_pub_key,
+                &rev_reg_priv.value,
+                max_cred_num,
+                &(start..=end).collect(),
+            )?;
+            let accum = rev_reg.accum.as_ref().add(batch.accum.as_ref())?;
+            rev_reg = Accumulator::from(accum).into();
+            for _ in start..=end {
+                progress.step()?;
+            }
+            match end.checked_add(1) {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+    }
+    progress.finish();
     let list = bitvec![if issuance_by_default { 0 } else { 1 }; max_cred_num as usize ];
 
     RevocationStatusList::new(
@@ -468,7 +712,6 @@ pub fn update_revocation_status_list_timestamp_only(
 /// let schema_id = SchemaId::new("did:web:xyz/resource/schema").expect("Invalid schema ID");
 /// let cred_def_id = CredentialDefinitionId::new("did:web:xyz/resource/cred-def",).expect("Invalid credential definition ID");
 /// let rev_reg_def_id = RevocationRegistryDefinitionId::new("did:web:xyz/resource/rev-reg-def").expect("Invalid revocation registry definition ID");
-
 ///
 /// let schema = issuer::create_schema("schema name",
 ///                                    "1.0",
@@ -634,6 +877,10 @@ pub fn create_credential_offer(
 ///
 /// This object can be send to a holder which means that the credential is issued to that entity.
 ///
+/// Before signing, `cred_values` are checked against the attribute names of `schema`. Missing or
+/// unexpected attributes and encoded values not matching the encoding of their raw value are all
+/// reported, by attribute name, in a single error.
+///
 /// # Example
 ///
 /// ```rust
@@ -690,7 +937,8 @@ pub fn create_credential_offer(
 /// credential_values.add_raw("age", "28").expect("Unable to add credential value");
 ///
 /// let credential =
-///     issuer::create_credential(&cred_def,
+///     issuer::create_credential(&schema,
+///                               &cred_def,
 ///                               &cred_def_priv,
 ///                               &credential_offer,
 ///                               &credential_request,
@@ -700,6 +948,7 @@ pub fn create_credential_offer(
 /// ```
 #[allow(clippy::too_many_arguments)]
 pub fn create_credential(
+    schema: &Schema,
     cred_def: &CredentialDefinition,
     cred_def_private: &CredentialDefinitionPrivate,
     cred_offer: &CredentialOffer,
@@ -707,9 +956,9 @@ pub fn create_credential(
     cred_values: CredentialValues,
     revocation_config: Option<CredentialRevocationConfig>,
 ) -> Result<Credential> {
-    trace!("create_credential >>> cred_def: {:?}, cred_def_private: {:?}, cred_offer.nonce: {:?}, cred_request: {:?},\
+    trace!("create_credential >>> schema: {:?}, cred_def: {:?}, cred_def_private: {:?}, cred_offer.nonce: {:?}, cred_request: {:?},\
             cred_values: {:?}, revocation_config: {:?}",
-            cred_def, secret!(&cred_def_private), &cred_offer.nonce, &cred_request, secret!(&cred_values), revocation_config,
+            schema, cred_def, secret!(&cred_def_private), &cred_offer.nonce, &cred_request, secret!(&cred_values), revocation_config,
             );
 
     let cred_public_key: anoncreds_clsignatures::CredentialPublicKey =
@@ -717,6 +966,7 @@ pub fn create_credential(
             Unexpected,
             "Error fetching public key from credential definition"
         ))?;
+    check_credential_values(&schema.attr_names.0, &cred_values.0)?;
     let credential_values = build_credential_values(&cred_values.0, None)?;
 
     let (credential_signature, signature_correctness_proof, rev_reg_id, rev_reg, witness) =
@@ -812,7 +1062,11 @@ pub fn create_credential(
 
 #[cfg(test)]
 mod tests {
-    use crate::{services::helpers::encode_credential_attribute, tails::TailsFileWriter};
+    use crate::{
+        data_types::link_secret::LinkSecret, prover,
+        services::helpers::encode_credential_attribute, tails::TailsFileWriter,
+        types::MakeCredentialValues,
+    };
 
     use super::*;
 
@@ -849,6 +1103,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_initial_accumulator_in_batches() -> Result<()> {
+        let issuer_id: IssuerId = "sample:uri".try_into()?;
+        let attr_names = AttributeNames::from(vec!["name".to_owned()]);
+        let schema = create_schema("schema:name", "1.0", issuer_id.clone(), attr_names)?;
+        let (cred_def, _, _) = create_credential_definition(
+            "schema:id".try_into()?,
+            &schema,
+            issuer_id,
+            "default",
+            SignatureType::CL,
+            CredentialDefinitionConfig {
+                support_revocation: true,
+            },
+        )?;
+        let max_cred_num = ACCUMULATOR_BATCH_SIZE + 1;
+        let (rev_reg_def, rev_reg_def_priv) = create_revocation_registry_def(
+            &cred_def,
+            "sample:uri".try_into()?,
+            "default",
+            RegistryType::CL_ACCUM,
+            max_cred_num,
+            &mut TailsFileWriter::new(None),
+        )?;
+
+        let list = create_revocation_status_list(
+            &cred_def,
+            "sample:uri".try_into()?,
+            &rev_reg_def,
+            &rev_reg_def_priv,
+            true,
+            None,
+        )?;
+        let rev_reg: Option<CryptoRevocationRegistry> = (&list).into();
+        let expected = CryptoRevocationRegistry::initial_state(
+            &cred_def.get_public_key()?,
+            &rev_reg_def_priv.value,
+            max_cred_num,
+            true,
+        )?;
+        assert_eq!(rev_reg.unwrap().accum, expected.accum);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotate_credential_definition() -> Result<()> {
+        let issuer_id: IssuerId = "sample:uri".try_into()?;
+        let schema_id: SchemaId = "schema:id".try_into()?;
+        let cred_def_id: CredentialDefinitionId = "sample:uri/cred-def".try_into()?;
+        let attr_names = AttributeNames::from(vec!["name".to_owned(), "age".to_owned()]);
+
+        let schema = create_schema("schema:name", "1.0", issuer_id.clone(), attr_names)?;
+        let (cred_def, _, _) = create_credential_definition(
+            schema_id.clone(),
+            &schema,
+            issuer_id.clone(),
+            "default",
+            SignatureType::CL,
+            CredentialDefinitionConfig {
+                support_revocation: true,
+            },
+        )?;
+
+        let (new_cred_def, _, _, supersedes) = rotate_credential_definition(
+            cred_def_id.clone(),
+            &cred_def,
+            &schema,
+            RotationReason::KeyCompromise,
+            Some(10),
+        )?;
+
+        assert_eq!(new_cred_def.tag, "default-v2");
+        assert_eq!(new_cred_def.schema_id, schema_id);
+        assert_eq!(new_cred_def.issuer_id, issuer_id);
+        assert!(new_cred_def.value.revocation.is_some());
+        assert_eq!(supersedes.supersedes, cred_def_id);
+        assert_eq!(supersedes.previous_tag, "default");
+        assert_eq!(supersedes.tag, "default-v2");
+
+        let (rotated_again, _, _, _) = rotate_credential_definition(
+            "sample:uri/cred-def-v2".try_into()?,
+            &new_cred_def,
+            &schema,
+            RotationReason::Scheduled,
+            None,
+        )?;
+        assert_eq!(rotated_again.tag, "default-v3");
+        Ok(())
+    }
+
     #[test]
     fn test_encode_attribute() {
         assert_eq!(
@@ -916,4 +1260,72 @@ mod tests {
             "99398763056634537812744552006896172984671876672520535998211840060697129507206"
         );
     }
+
+    #[test]
+    fn test_create_credential_rejects_mismatched_values() -> Result<()> {
+        let issuer_id: IssuerId = "sample:uri".try_into()?;
+        let schema_id: SchemaId = "schema:id".try_into()?;
+        let cred_def_id: CredentialDefinitionId = "sample:uri/cred-def".try_into()?;
+        let attr_names =
+            AttributeNames::from(vec!["Name".to_owned(), "age".to_owned(), "sex".to_owned()]);
+
+        let schema = create_schema("schema:name", "1.0", issuer_id.clone(), attr_names)?;
+        let (cred_def, cred_def_priv, key_correctness_proof) = create_credential_definition(
+            schema_id.clone(),
+            &schema,
+            issuer_id,
+            "default",
+            SignatureType::CL,
+            CredentialDefinitionConfig::default(),
+        )?;
+        let cred_offer = create_credential_offer(schema_id, cred_def_id, &key_correctness_proof)?;
+        let link_secret = LinkSecret::new()?;
+        let (cred_request, _) = prover::create_credential_request(
+            Some("entropy"),
+            None,
+            &cred_def,
+            &link_secret,
+            "default",
+            &cred_offer,
+        )?;
+
+        let mut values = MakeCredentialValues::default();
+        values.add_raw("name", "Alex")?;
+        values.add_encoded("age", "28", "29".to_owned());
+        values.add_raw("height", "175")?;
+        let err = create_credential(
+            &schema,
+            &cred_def,
+            &cred_def_priv,
+            &cred_offer,
+            &cred_request,
+            values.into(),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Input);
+        assert_eq!(
+            err.to_string(),
+            "Credential values do not match the schema: \
+             `age`: encoded value does not match raw value, \
+             `height`: not in schema, \
+             `sex`: missing value"
+        );
+
+        let mut values = MakeCredentialValues::default();
+        values.add_raw("name", "Alex")?;
+        values.add_raw("age", "28")?;
+        values.add_raw("sex", "male")?;
+        let res = create_credential(
+            &schema,
+            &cred_def,
+            &cred_def_priv,
+            &cred_offer,
+            &cred_request,
+            values.into(),
+            None,
+        );
+        assert!(res.is_ok());
+        Ok(())
+    }
 }
@@ -1,5 +1,10 @@
 pub(crate) mod helpers;
 pub mod issuer;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+pub mod nonce_registry;
+pub mod offer_tracker;
+pub mod progress;
 pub mod prover;
 pub mod tails;
 pub mod types;
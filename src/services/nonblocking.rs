@@ -0,0 +1,348 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use rand::random;
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio::task::spawn_blocking;
+
+use super::issuer::{build_revocation_registry_def, new_revocation_registry_keys};
+use super::progress::Progress;
+use super::prover::create_or_update_revocation_state_with_progress;
+use super::tails::{InMemoryTailsReader, TAILS_BLOB_VERSION};
+use super::types::{
+    CredentialRevocationState, RegistryType, RevocationRegistryDefinition,
+    RevocationRegistryDefinitionPrivate, RevocationStatusList,
+};
+use crate::cl::RevocationTailsGenerator;
+use crate::data_types::cred_def::{CredentialDefinition, CredentialDefinitionId};
+use crate::error::Result;
+use crate::utils::base58;
+
+/// Number of tails sent to an [`AsyncTailsWriter`] at once
+const TAILS_CHUNK_SIZE: usize = 1024;
+
+/// Tails blob produced on a blocking thread, received in chunks
+#[derive(Debug)]
+pub struct TailsStream {
+    chunks: mpsc::Receiver<Result<Vec<u8>>>,
+}
+
+impl TailsStream {
+    /// Receive the next chunk of the tails blob, `None` once the blob is complete
+    pub async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        self.chunks.recv().await.transpose()
+    }
+}
+
+/// Async counterpart of [`crate::tails::TailsWriter`]
+pub trait AsyncTailsWriter: Debug + Send {
+    /// Write the tails blob, returning its location and base58 encoded SHA-256 hash
+    fn write(&mut self, tails: TailsStream) -> TailsWriteFuture<'_>;
+}
+
+/// Future returned by [`AsyncTailsWriter::write`]
+pub type TailsWriteFuture<'a> = Pin<Box<dyn Future<Output = Result<(String, String)>> + Send + 'a>>;
+
+/// Writes tails files to a directory, named by their hash
+#[derive(Debug)]
+pub struct AsyncTailsFileWriter {
+    root_path: PathBuf,
+}
+
+impl AsyncTailsFileWriter {
+    pub fn new(root_path: Option<String>) -> Self {
+        Self {
+            root_path: root_path
+                .map(PathBuf::from)
+                .unwrap_or_else(std::env::temp_dir),
+        }
+    }
+
+    async fn write_file(
+        &self,
+        temp_path: &Path,
+        tails: &mut TailsStream,
+    ) -> Result<(String, String)> {
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(temp_path)
+            .await
+            .map_err(|e| err_msg!(IOError, "Error creating tails temp file {temp_path:?}: {e}"))?;
+        let mut buf = tokio::io::BufWriter::new(file);
+        let mut hasher = Sha256::default();
+        while let Some(chunk) = tails.next_chunk().await? {
+            buf.write_all(&chunk).await?;
+            hasher.update(&chunk);
+        }
+        buf.flush().await?;
+        drop(buf);
+
+        let hash = base58::encode(hasher.finalize());
+        let target_path = self.root_path.join(&hash);
+        tokio::fs::rename(temp_path, &target_path)
+            .await
+            .map_err(|e| err_msg!("Error moving tails temp file {temp_path:?}: {e}"))?;
+        let target_path = target_path.to_string_lossy().into_owned();
+        debug!("AsyncTailsFileWriter: wrote tails file {}", target_path);
+        Ok((target_path, hash))
+    }
+}
+
+impl AsyncTailsWriter for AsyncTailsFileWriter {
+    fn write(&mut self, mut tails: TailsStream) -> TailsWriteFuture<'_> {
+        Box::pin(async move {
+            let temp_path = self.root_path.join(format!("{:020}.tmp", random::<u64>()));
+            let result = self.write_file(&temp_path, &mut tails).await;
+            if result.is_err() {
+                if let Err(e) = tokio::fs::remove_file(&temp_path).await {
+                    error!("Error removing tails temp file {:?}: {e}", temp_path);
+                }
+            }
+            result
+        })
+    }
+}
+
+/// Reads tails files without blocking the runtime
+#[derive(Debug)]
+pub struct AsyncTailsFileReader {
+    path: PathBuf,
+}
+
+impl AsyncTailsFileReader {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Read the whole tails file into memory
+    pub async fn load(&self) -> Result<InMemoryTailsReader> {
+        let tails = tokio::fs::read(&self.path)
+            .await
+            .map_err(|e| err_msg!(IOError, "Error reading tails file {:?}: {e}", self.path))?;
+        InMemoryTailsReader::new(tails)
+    }
+}
+
+/// Run CPU heavy work on the blocking thread pool
+async fn run_blocking<T, F>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    spawn_blocking(f)
+        .await
+        .map_err(err_map!(Unexpected, "Error running blocking task"))?
+}
+
+fn generate_tails(
+    generator: &mut RevocationTailsGenerator,
+    chunks: &mpsc::Sender<Result<Vec<u8>>>,
+) -> Result<()> {
+    let chunk_size = TAILS_CHUNK_SIZE * crate::cl::Tail::BYTES_REPR_SIZE;
+    let mut chunk = TAILS_BLOB_VERSION.to_vec();
+    while let Some(tail) = generator.try_next()? {
+        chunk.extend_from_slice(&tail.to_bytes()?);
+        if chunk.len() >= chunk_size
+            && chunks
+                .blocking_send(Ok(std::mem::take(&mut chunk)))
+                .is_err()
+        {
+            // The writer stopped receiving
+            return Ok(());
+        }
+    }
+    if !chunk.is_empty() {
+        chunks.blocking_send(Ok(chunk)).ok();
+    }
+    Ok(())
+}
+
+/// Async variant of [`crate::issuer::create_revocation_registry_def`]
+///
+/// The keys and tails are generated on the blocking thread pool while the tails are written by
+/// the async `tails_writer`.
+pub async fn create_revocation_registry_def<TW>(
+    cred_def: &CredentialDefinition,
+    cred_def_id: CredentialDefinitionId,
+    tag: &str,
+    rev_reg_type: RegistryType,
+    max_cred_num: u32,
+    tails_writer: &mut TW,
+) -> Result<(
+    RevocationRegistryDefinition,
+    RevocationRegistryDefinitionPrivate,
+)>
+where
+    TW: AsyncTailsWriter,
+{
+    trace!("create_revocation_registry_def >>> cred_def: {:?}, tag: {:?}, max_cred_num: {:?}, rev_reg_type: {:?}",
+             cred_def, tag, max_cred_num, rev_reg_type);
+
+    let credential_pub_key = cred_def.get_public_key().map_err(err_map!(
+        Unexpected,
+        "Error fetching public key from credential definition"
+    ))?;
+
+    let (revoc_key_pub, revoc_key_priv, mut rev_tails_generator) =
+        run_blocking(move || new_revocation_registry_keys(&credential_pub_key, max_cred_num))
+            .await?;
+
+    let (sender, chunks) = mpsc::channel(4);
+    let generation = spawn_blocking(move || {
+        if let Err(err) = generate_tails(&mut rev_tails_generator, &sender) {
+            sender.blocking_send(Err(err)).ok();
+        }
+    });
+    let written = tails_writer.write(TailsStream { chunks }).await;
+    generation
+        .await
+        .map_err(err_map!(Unexpected, "Error running blocking task"))?;
+    let tails = written?;
+
+    let (revoc_reg_def, revoc_def_priv) = build_revocation_registry_def(
+        cred_def,
+        cred_def_id,
+        tag,
+        rev_reg_type,
+        max_cred_num,
+        (revoc_key_pub, revoc_key_priv),
+        tails,
+    );
+
+    trace!(
+        "create_revocation_registry_def <<< revoc_reg_def: {:?}, private: {:?}",
+        revoc_reg_def,
+        secret!(&revoc_def_priv),
+    );
+
+    Ok((revoc_reg_def, revoc_def_priv))
+}
+
+/// Async variant of [`crate::prover::create_or_update_revocation_state`]
+///
+/// The tails file is read and the witness computed on the blocking thread pool.
+pub async fn create_or_update_revocation_state(
+    tails_path: &str,
+    rev_reg_def: &RevocationRegistryDefinition,
+    rev_status_list: &RevocationStatusList,
+    rev_reg_idx: u32,
+    rev_state: Option<&CredentialRevocationState>,
+    old_rev_status_list: Option<&RevocationStatusList>,
+) -> Result<CredentialRevocationState> {
+    let tails_path = tails_path.to_owned();
+    let rev_reg_def = rev_reg_def.clone();
+    let rev_status_list = rev_status_list.clone();
+    let rev_state = rev_state.cloned();
+    let old_rev_status_list = old_rev_status_list.cloned();
+
+    run_blocking(move || {
+        create_or_update_revocation_state_with_progress(
+            &tails_path,
+            &rev_reg_def,
+            &rev_status_list,
+            rev_reg_idx,
+            rev_state.as_ref(),
+            old_rev_status_list.as_ref(),
+            &Progress::default(),
+        )
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issuer;
+    use crate::types::{CredentialDefinitionConfig, SignatureType};
+
+    #[test]
+    fn test_async_revocation_registry() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let schema = issuer::create_schema(
+            "name",
+            "1.0",
+            "mock:uri".try_into().unwrap(),
+            vec!["name".to_owned()].into(),
+        )
+        .unwrap();
+        let (cred_def, _, _) = issuer::create_credential_definition(
+            "mock:uri".try_into().unwrap(),
+            &schema,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            SignatureType::CL,
+            CredentialDefinitionConfig {
+                support_revocation: true,
+            },
+        )
+        .unwrap();
+
+        let mut tails_writer = AsyncTailsFileWriter::new(None);
+        let (rev_reg_def, rev_reg_def_priv) = runtime
+            .block_on(create_revocation_registry_def(
+                &cred_def,
+                "mock:uri".try_into().unwrap(),
+                "tag",
+                RegistryType::CL_ACCUM,
+                10,
+                &mut tails_writer,
+            ))
+            .unwrap();
+
+        let tails = std::fs::read(&rev_reg_def.value.tails_location).unwrap();
+        assert_eq!(tails[..2], TAILS_BLOB_VERSION);
+        assert_eq!(
+            tails.len(),
+            2 + (2 * 10 + 1) * crate::cl::Tail::BYTES_REPR_SIZE
+        );
+        assert_eq!(
+            base58::encode(Sha256::digest(&tails)),
+            rev_reg_def.value.tails_hash
+        );
+
+        let rev_status_list = issuer::create_revocation_status_list(
+            &cred_def,
+            "mock:uri".try_into().unwrap(),
+            &rev_reg_def,
+            &rev_reg_def_priv,
+            true,
+            Some(10),
+        )
+        .unwrap();
+        let rev_state = runtime
+            .block_on(create_or_update_revocation_state(
+                &rev_reg_def.value.tails_location,
+                &rev_reg_def,
+                &rev_status_list,
+                1,
+                None,
+                None,
+            ))
+            .unwrap();
+        assert_eq!(rev_state.timestamp, 10);
+
+        assert!(runtime
+            .block_on(create_or_update_revocation_state(
+                "missing-tails-file",
+                &rev_reg_def,
+                &rev_status_list,
+                1,
+                None,
+                None,
+            ))
+            .is_err());
+        assert!(runtime
+            .block_on(AsyncTailsFileReader::new("missing-tails-file").load())
+            .is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::data_types::nonce::Nonce;
+use crate::error::Result;
+
+use super::helpers::new_nonce;
+
+/// Verifier side registry of presentation request nonces
+///
+/// Nonces are issued through the registry when creating a presentation request and consumed when
+/// the matching presentation is verified, see [`crate::verifier::VerificationOptions`]. A nonce
+/// can only be consumed once and only within the time window of the registry. Timestamps are
+/// supplied by the caller.
+pub trait NonceRegistry: Debug {
+    /// Register a nonce issued at `now`. Must fail if the nonce is already known.
+    fn register(&mut self, nonce: &Nonce, now: u64) -> Result<()>;
+
+    /// Consume a nonce at `now`. Must fail if the nonce is unknown, expired or already consumed.
+    fn consume(&mut self, nonce: &Nonce, now: u64) -> Result<()>;
+
+    /// Remove all nonces which expired before `now`, returning the number of removed nonces
+    fn remove_expired(&mut self, now: u64) -> Result<usize>;
+
+    /// Generate a new nonce and register it as issued at `now`
+    fn issue_nonce(&mut self, now: u64) -> Result<Nonce> {
+        let nonce = new_nonce()?;
+        self.register(&nonce, now)?;
+        Ok(nonce)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct NonceState {
+    issued_at: u64,
+    consumed: bool,
+}
+
+/// Nonce registry kept in memory, lost when the process exits
+#[derive(Debug)]
+pub struct InMemoryNonceRegistry {
+    window: u64,
+    nonces: HashMap<String, NonceState>,
+}
+
+impl InMemoryNonceRegistry {
+    /// Create a registry accepting nonces for `window` after they were issued
+    #[must_use]
+    pub fn new(window: u64) -> Self {
+        Self {
+            window,
+            nonces: HashMap::new(),
+        }
+    }
+
+    fn is_expired(&self, state: &NonceState, now: u64) -> bool {
+        now.saturating_sub(state.issued_at) > self.window
+    }
+}
+
+impl NonceRegistry for InMemoryNonceRegistry {
+    fn register(&mut self, nonce: &Nonce, now: u64) -> Result<()> {
+        if self.nonces.contains_key(nonce.as_ref()) {
+            return Err(err_msg!("Nonce {} already registered", nonce));
+        }
+        self.nonces.insert(
+            nonce.to_string(),
+            NonceState {
+                issued_at: now,
+                consumed: false,
+            },
+        );
+        Ok(())
+    }
+
+    fn consume(&mut self, nonce: &Nonce, now: u64) -> Result<()> {
+        let state = *self
+            .nonces
+            .get(nonce.as_ref())
+            .ok_or_else(|| err_msg!(ProofRejected, "Unknown nonce {}", nonce))?;
+
+        if state.consumed {
+            return Err(err_msg!(
+                ProofRejected,
+                "Nonce {} was already consumed",
+                nonce
+            ));
+        }
+        if now < state.issued_at || self.is_expired(&state, now) {
+            return Err(err_msg!(
+                ProofRejected,
+                "Nonce {} is outside of the accepted time window",
+                nonce
+            ));
+        }
+
+        self.nonces.insert(
+            nonce.to_string(),
+            NonceState {
+                consumed: true,
+                ..state
+            },
+        );
+        Ok(())
+    }
+
+    fn remove_expired(&mut self, now: u64) -> Result<usize> {
+        let before = self.nonces.len();
+        let window = self.window;
+        self.nonces
+            .retain(|_, state| now.saturating_sub(state.issued_at) <= window);
+        Ok(before - self.nonces.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consume_nonce_once() {
+        let mut registry = InMemoryNonceRegistry::new(100);
+        let nonce = registry.issue_nonce(10).unwrap();
+
+        assert_kind!(Input, registry.register(&nonce, 20));
+        registry.consume(&nonce, 110).unwrap();
+        assert_kind!(ProofRejected, registry.consume(&nonce, 120));
+    }
+
+    #[test]
+    fn test_reject_unknown_and_expired_nonces() {
+        let mut registry = InMemoryNonceRegistry::new(100);
+        let nonce = new_nonce().unwrap();
+        assert_kind!(ProofRejected, registry.consume(&nonce, 10));
+
+        registry.register(&nonce, 10).unwrap();
+        assert_kind!(ProofRejected, registry.consume(&nonce, 5));
+        assert_kind!(ProofRejected, registry.consume(&nonce, 111));
+
+        assert_eq!(registry.remove_expired(111).unwrap(), 1);
+        assert_kind!(ProofRejected, registry.consume(&nonce, 111));
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::data_types::cred_def::CredentialDefinitionId;
+use crate::error::Result;
+
+use super::types::{CredentialOffer, CredentialRequest};
+
+/// State of a credential offer made by an issuer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfferRecord {
+    /// Nonce of the credential offer
+    pub offer_nonce: String,
+    pub cred_def_id: CredentialDefinitionId,
+    /// Timestamp after which the offer can no longer be redeemed
+    pub expires_at: u64,
+    /// Nonce of the credential request which redeemed the offer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_nonce: Option<String>,
+}
+
+impl OfferRecord {
+    #[must_use]
+    pub const fn is_consumed(&self) -> bool {
+        self.request_nonce.is_some()
+    }
+
+    #[must_use]
+    pub const fn is_expired(&self, now: u64) -> bool {
+        now > self.expires_at
+    }
+}
+
+/// Storage backend of an [`OfferTracker`]
+pub trait OfferStorage: Debug {
+    /// Store the record of a new offer. Must fail if the offer nonce is already known.
+    fn insert(&mut self, record: OfferRecord) -> Result<()>;
+
+    /// Load the record of an offer by its nonce
+    fn get(&self, offer_nonce: &str) -> Result<Option<OfferRecord>>;
+
+    /// Mark an offer for `cred_def_id` as redeemed by `request_nonce` at `now`
+    ///
+    /// Must fail if the offer nonce is unknown, was recorded for another credential definition,
+    /// was already consumed or has expired. The check and the update must be a single atomic
+    /// operation, so that concurrent requests cannot both redeem the same offer.
+    fn consume(
+        &mut self,
+        offer_nonce: &str,
+        request_nonce: &str,
+        cred_def_id: &CredentialDefinitionId,
+        now: u64,
+    ) -> Result<()>;
+
+    /// Remove all records which expired before `now`, returning the number of removed records
+    fn remove_expired(&mut self, now: u64) -> Result<usize>;
+}
+
+/// Offer storage kept in memory, lost when the process exits
+#[derive(Debug, Default)]
+pub struct InMemoryOfferStorage {
+    offers: HashMap<String, OfferRecord>,
+}
+
+impl OfferStorage for InMemoryOfferStorage {
+    fn insert(&mut self, record: OfferRecord) -> Result<()> {
+        if self.offers.contains_key(&record.offer_nonce) {
+            return Err(err_msg!(
+                "Credential offer with nonce {} already recorded",
+                record.offer_nonce
+            ));
+        }
+        self.offers.insert(record.offer_nonce.clone(), record);
+        Ok(())
+    }
+
+    fn get(&self, offer_nonce: &str) -> Result<Option<OfferRecord>> {
+        Ok(self.offers.get(offer_nonce).cloned())
+    }
+
+    fn consume(
+        &mut self,
+        offer_nonce: &str,
+        request_nonce: &str,
+        cred_def_id: &CredentialDefinitionId,
+        now: u64,
+    ) -> Result<()> {
+        let record = self
+            .offers
+            .get_mut(offer_nonce)
+            .ok_or_else(|| err_msg!("Unknown credential offer nonce {}", offer_nonce))?;
+
+        if &record.cred_def_id != cred_def_id {
+            return Err(err_msg!(
+                "Credential offer nonce {} was recorded for credential definition {}",
+                offer_nonce,
+                record.cred_def_id
+            ));
+        }
+        if let Some(consumed_by) = &record.request_nonce {
+            return Err(err_msg!(
+                "Credential offer nonce {} was already consumed by request nonce {}",
+                offer_nonce,
+                consumed_by
+            ));
+        }
+        if record.is_expired(now) {
+            return Err(err_msg!(
+                "Credential offer nonce {} expired at {}",
+                offer_nonce,
+                record.expires_at
+            ));
+        }
+
+        record.request_nonce = Some(request_nonce.to_owned());
+        Ok(())
+    }
+
+    fn remove_expired(&mut self, now: u64) -> Result<usize> {
+        let before = self.offers.len();
+        self.offers.retain(|_, record| !record.is_expired(now));
+        Ok(before - self.offers.len())
+    }
+}
+
+/// Issuer side replay protection for credential offers
+///
+/// Every [`CredentialOffer`] sent out is recorded with a time to live. A [`CredentialRequest`]
+/// is only accepted once per offer, and only before the offer expires. Timestamps are supplied by
+/// the caller, in the same unit as `ttl`.
+///
+/// # Example
+///
+/// ```rust
+/// use anoncreds::offer_tracker::OfferTracker;
+/// # use anoncreds::issuer;
+/// # use anoncreds::types::{CredentialDefinitionConfig, SignatureType};
+/// # use anoncreds::data_types::issuer_id::IssuerId;
+/// # use anoncreds::data_types::schema::SchemaId;
+/// # use anoncreds::data_types::cred_def::CredentialDefinitionId;
+/// # let issuer_id = IssuerId::new("did:web:xyz").expect("Invalid issuer ID");
+/// # let schema_id = SchemaId::new("did:web:xyz/resource/schema").expect("Invalid schema ID");
+/// # let cred_def_id = CredentialDefinitionId::new("did:web:xyz/resource/cred-def").expect("Invalid credential definition ID");
+/// # let attribute_names: &[&str] = &["name", "age"];
+/// # let schema = issuer::create_schema("schema name", "1.0", issuer_id.clone(), attribute_names.into())
+/// #     .expect("Unable to create schema");
+/// # let (_cred_def, _cred_def_priv, key_correctness_proof) =
+/// #     issuer::create_credential_definition(schema_id.clone(), &schema, issuer_id, "default-tag",
+/// #         SignatureType::CL, CredentialDefinitionConfig::default())
+/// #     .expect("Unable to create Credential Definition");
+///
+/// let mut tracker = OfferTracker::in_memory(3600);
+///
+/// let cred_offer =
+///     issuer::create_credential_offer(schema_id,
+///                                     cred_def_id,
+///                                     &key_correctness_proof,
+///                                     ).expect("Unable to create Credential Offer");
+///
+/// tracker.record_offer(&cred_offer, 1000).expect("Unable to record Credential Offer");
+///
+/// // Once a credential request arrives, and before issuing the credential:
+/// // tracker.consume_request(&cred_offer, &cred_request, 1200)?;
+/// ```
+#[derive(Debug)]
+pub struct OfferTracker<S: OfferStorage = InMemoryOfferStorage> {
+    storage: S,
+    ttl: u64,
+}
+
+impl OfferTracker<InMemoryOfferStorage> {
+    /// Create a tracker keeping the offers in memory
+    #[must_use]
+    pub fn in_memory(ttl: u64) -> Self {
+        Self::new(InMemoryOfferStorage::default(), ttl)
+    }
+}
+
+impl<S: OfferStorage> OfferTracker<S> {
+    pub const fn new(storage: S, ttl: u64) -> Self {
+        Self { storage, ttl }
+    }
+
+    #[must_use]
+    pub const fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Record a credential offer sent at `now`
+    pub fn record_offer(&mut self, cred_offer: &CredentialOffer, now: u64) -> Result<()> {
+        trace!(
+            "record_offer >>> cred_offer: {:?}, now: {:?}",
+            cred_offer,
+            now
+        );
+
+        let expires_at = now
+            .checked_add(self.ttl)
+            .ok_or_else(|| err_msg!("Credential offer expiry overflows"))?;
+        self.storage.insert(OfferRecord {
+            offer_nonce: cred_offer.nonce.to_string(),
+            cred_def_id: cred_offer.cred_def_id.clone(),
+            expires_at,
+            request_nonce: None,
+        })?;
+
+        trace!("record_offer <<< expires_at: {:?}", expires_at);
+
+        Ok(())
+    }
+
+    /// Mark the credential offer as redeemed by `cred_request` at `now`
+    ///
+    /// Fails if the offer was never recorded, has expired or was already redeemed, including by
+    /// the same request, or if the request is for another credential definition than the
+    /// recorded offer. The blinded link secret proof of the request is bound to the offer nonce
+    /// and checked when the credential is created from the same offer.
+    pub fn consume_request(
+        &mut self,
+        cred_offer: &CredentialOffer,
+        cred_request: &CredentialRequest,
+        now: u64,
+    ) -> Result<()> {
+        trace!(
+            "consume_request >>> cred_offer: {:?}, cred_request: {:?}, now: {:?}",
+            cred_offer,
+            cred_request,
+            now
+        );
+
+        let offer_nonce = cred_offer.nonce.as_ref();
+        let request_nonce = cred_request.nonce.as_ref();
+
+        if cred_request.cred_def_id() != &cred_offer.cred_def_id {
+            return Err(err_msg!(
+                "Credential request for credential definition {} does not match offer nonce {}",
+                cred_request.cred_def_id(),
+                offer_nonce
+            ));
+        }
+        if request_nonce == offer_nonce {
+            return Err(err_msg!(
+                "Credential request nonce must differ from the offer nonce"
+            ));
+        }
+
+        self.storage
+            .consume(offer_nonce, request_nonce, &cred_offer.cred_def_id, now)?;
+
+        trace!("consume_request <<<");
+
+        Ok(())
+    }
+
+    /// Drop the records of offers which expired before `now`
+    ///
+    /// Consumed offers are dropped as well once expired, after which their nonces are unknown
+    /// and requests for them are rejected as such.
+    pub fn purge_expired(&mut self, now: u64) -> Result<usize> {
+        self.storage.remove_expired(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::link_secret::LinkSecret;
+    use crate::data_types::schema::Schema;
+    use crate::issuer;
+    use crate::prover;
+    use crate::types::{CredentialDefinitionConfig, SignatureType};
+
+    const CRED_DEF_ID: &str = "did:web:xyz/resource/cred-def";
+
+    fn offer_and_request() -> (CredentialOffer, CredentialRequest) {
+        offer_and_request_for(CRED_DEF_ID)
+    }
+
+    fn offer_and_request_for(cred_def_id: &str) -> (CredentialOffer, CredentialRequest) {
+        let schema: Schema = issuer::create_schema(
+            "schema name",
+            "1.0",
+            "did:web:xyz".try_into().unwrap(),
+            vec!["name".to_owned()].into(),
+        )
+        .unwrap();
+        let (cred_def, _, key_correctness_proof) = issuer::create_credential_definition(
+            "did:web:xyz/resource/schema".try_into().unwrap(),
+            &schema,
+            "did:web:xyz".try_into().unwrap(),
+            "default-tag",
+            SignatureType::CL,
+            CredentialDefinitionConfig::default(),
+        )
+        .unwrap();
+        let cred_offer = issuer::create_credential_offer(
+            "did:web:xyz/resource/schema".try_into().unwrap(),
+            cred_def_id.try_into().unwrap(),
+            &key_correctness_proof,
+        )
+        .unwrap();
+        let link_secret = LinkSecret::new().unwrap();
+        let (cred_request, _) = prover::create_credential_request(
+            Some("entropy"),
+            None,
+            &cred_def,
+            &link_secret,
+            "link-secret",
+            &cred_offer,
+        )
+        .unwrap();
+        (cred_offer, cred_request)
+    }
+
+    #[test]
+    fn test_consume_request_once() {
+        let (cred_offer, cred_request) = offer_and_request();
+        let mut tracker = OfferTracker::in_memory(100);
+
+        tracker.record_offer(&cred_offer, 10).unwrap();
+        assert_kind!(Input, tracker.record_offer(&cred_offer, 20));
+
+        tracker
+            .consume_request(&cred_offer, &cred_request, 110)
+            .unwrap();
+        assert_kind!(
+            Input,
+            tracker.consume_request(&cred_offer, &cred_request, 120)
+        );
+    }
+
+    #[test]
+    fn test_reject_expired_and_unknown_offers() {
+        let (cred_offer, cred_request) = offer_and_request();
+        let mut tracker = OfferTracker::in_memory(100);
+
+        assert_kind!(
+            Input,
+            tracker.consume_request(&cred_offer, &cred_request, 10)
+        );
+
+        tracker.record_offer(&cred_offer, 10).unwrap();
+        assert_kind!(
+            Input,
+            tracker.consume_request(&cred_offer, &cred_request, 111)
+        );
+
+        assert_eq!(tracker.purge_expired(111).unwrap(), 1);
+        assert!(tracker
+            .storage()
+            .get(cred_offer.nonce.as_ref())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_reject_request_for_other_cred_def() {
+        let (cred_offer, cred_request) = offer_and_request();
+        let (_, other_request) = offer_and_request_for("did:web:xyz/resource/other-cred-def");
+        let mut tracker = OfferTracker::in_memory(100);
+
+        tracker.record_offer(&cred_offer, 10).unwrap();
+        assert_kind!(
+            Input,
+            tracker.consume_request(&cred_offer, &other_request, 20)
+        );
+
+        tracker
+            .consume_request(&cred_offer, &cred_request, 20)
+            .unwrap();
+    }
+}
@@ -0,0 +1,283 @@
+use std::cell::Cell;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::cl::{Error as ClError, ErrorKind as ClErrorKind, RevocationTailsAccessor, Tail};
+use crate::error::{Error, Result};
+
+/// Number of progress reports made over the whole operation, at most
+const PROGRESS_STEPS: u64 = 1000;
+
+/// Shared flag to abort a long running operation, possibly from another thread
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Progress reporting and cancellation of a long running operation
+///
+/// The callback receives the number of completed steps and the total number of steps. It is
+/// called when the operation starts, at most a thousand times while it runs and once it
+/// completes. Cancellation is checked between steps, a cancelled operation fails with
+/// [`crate::ErrorKind::Cancelled`].
+#[derive(Default)]
+pub struct Progress<'a> {
+    callback: Option<&'a dyn Fn(u64, u64)>,
+    cancellation: Option<&'a CancellationToken>,
+    total: Cell<u64>,
+    done: Cell<u64>,
+}
+
+impl<'a> Progress<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_callback(mut self, callback: &'a dyn Fn(u64, u64)) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: &'a CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .map_or(false, CancellationToken::is_cancelled)
+    }
+
+    pub(crate) fn check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(err_msg!(Cancelled, "Operation cancelled"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Start an operation of `total` steps
+    pub(crate) fn start(&self, total: u64) -> Result<()> {
+        self.check_cancelled()?;
+        self.total.set(total);
+        self.done.set(0);
+        self.report();
+        Ok(())
+    }
+
+    /// Complete a step, failing if the operation was cancelled
+    pub(crate) fn step(&self) -> Result<()> {
+        self.check_cancelled()?;
+        let total = self.total.get();
+        let done = self.done.get();
+        if done < total {
+            self.done.set(done + 1);
+            if is_report_step(done + 1, total) {
+                self.report();
+            }
+        }
+        Ok(())
+    }
+
+    /// Complete the operation, skipping any remaining steps
+    pub(crate) fn finish(&self) {
+        if self.done.get() < self.total.get() || self.total.get() == 0 {
+            self.done.set(self.total.get());
+            self.report();
+        }
+    }
+
+    /// Replace an error caused by the cancellation of the operation
+    pub(crate) fn map_cancelled(&self, err: impl Into<Error>) -> Error {
+        self.check_cancelled().err().unwrap_or_else(|| err.into())
+    }
+
+    fn report(&self) {
+        if let Some(callback) = self.callback {
+            callback(self.done.get(), self.total.get());
+        }
+    }
+}
+
+impl Debug for Progress<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Progress")
+            .field("total", &self.total.get())
+            .field("done", &self.done.get())
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Whether completing step `done` moves the progress to the next reported fraction
+fn is_report_step(done: u64, total: u64) -> bool {
+    done == total
+        || u128::from(done) * u128::from(PROGRESS_STEPS) / u128::from(total)
+            != u128::from(done - 1) * u128::from(PROGRESS_STEPS) / u128::from(total)
+}
+
+/// Tails accessor counting every tail read as a step of `progress`
+pub(crate) struct ProgressTailsAccessor<'a, TA> {
+    pub tails_reader: &'a TA,
+    pub progress: &'a Progress<'a>,
+}
+
+impl<TA: RevocationTailsAccessor> RevocationTailsAccessor for ProgressTailsAccessor<'_, TA> {
+    fn access_tail(
+        &self,
+        tail_id: u32,
+        accessor: &mut dyn FnMut(&Tail),
+    ) -> std::result::Result<(), ClError> {
+        self.progress
+            .step()
+            .map_err(|_| ClError::new(ClErrorKind::InvalidState, "Operation cancelled"))?;
+        self.tails_reader.access_tail(tail_id, accessor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::issuer;
+    use crate::prover;
+    use crate::tails::TailsFileWriter;
+    use crate::types::{CredentialDefinitionConfig, RegistryType, SignatureType};
+
+    #[test]
+    fn test_report_progress() {
+        let reports = RefCell::new(Vec::new());
+        let callback = |done, total| reports.borrow_mut().push((done, total));
+        let progress = Progress::new().with_callback(&callback);
+
+        progress.start(4000).unwrap();
+        for _ in 0..4000 {
+            progress.step().unwrap();
+        }
+        progress.finish();
+
+        let reports = reports.into_inner();
+        assert_eq!(reports.len(), 1001);
+        assert_eq!(reports[0], (0, 4000));
+        assert_eq!(reports[1], (4, 4000));
+        assert_eq!(reports[1000], (4000, 4000));
+    }
+
+    #[test]
+    fn test_cancel_progress() {
+        let token = CancellationToken::new();
+        let progress = Progress::new().with_cancellation(&token);
+
+        progress.start(10).unwrap();
+        progress.step().unwrap();
+        token.clone().cancel();
+        assert_kind!(Cancelled, progress.step());
+        assert_kind!(Cancelled, progress.start(10));
+    }
+
+    #[test]
+    fn test_revocation_progress() {
+        let schema = issuer::create_schema(
+            "name",
+            "1.0",
+            "mock:uri".try_into().unwrap(),
+            vec!["name".to_owned()].into(),
+        )
+        .unwrap();
+        let (cred_def, _, _) = issuer::create_credential_definition(
+            "mock:uri".try_into().unwrap(),
+            &schema,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            SignatureType::CL,
+            CredentialDefinitionConfig {
+                support_revocation: true,
+            },
+        )
+        .unwrap();
+
+        let reports = RefCell::new(Vec::new());
+        let callback = |done, total| reports.borrow_mut().push((done, total));
+        let token = CancellationToken::new();
+        let progress = Progress::new()
+            .with_callback(&callback)
+            .with_cancellation(&token);
+
+        let mut tails_writer = TailsFileWriter::new(None);
+        let (rev_reg_def, rev_reg_def_priv) = issuer::create_revocation_registry_def_with_progress(
+            &cred_def,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            RegistryType::CL_ACCUM,
+            10,
+            &mut tails_writer,
+            &progress,
+        )
+        .unwrap();
+        assert_eq!(reports.borrow().first(), Some(&(0, 21)));
+        assert_eq!(reports.borrow().last(), Some(&(21, 21)));
+
+        reports.borrow_mut().clear();
+        let rev_status_list = issuer::create_revocation_status_list_with_progress(
+            &cred_def,
+            "mock:uri".try_into().unwrap(),
+            &rev_reg_def,
+            &rev_reg_def_priv,
+            true,
+            Some(10),
+            &progress,
+        )
+        .unwrap();
+        assert_eq!(reports.borrow().first(), Some(&(0, 10)));
+        assert_eq!(reports.borrow().last(), Some(&(10, 10)));
+        assert_eq!(reports.borrow().len(), 11);
+
+        reports.borrow_mut().clear();
+        prover::create_or_update_revocation_state_with_progress(
+            &rev_reg_def.value.tails_location,
+            &rev_reg_def,
+            &rev_status_list,
+            1,
+            None,
+            None,
+            &progress,
+        )
+        .unwrap();
+        assert_eq!(reports.borrow().first(), Some(&(0, 9)));
+        assert_eq!(reports.borrow().last(), Some(&(9, 9)));
+
+        token.cancel();
+        assert_kind!(
+            Cancelled,
+            prover::create_or_update_revocation_state_with_progress(
+                &rev_reg_def.value.tails_location,
+                &rev_reg_def,
+                &rev_status_list,
+                1,
+                None,
+                None,
+                &progress,
+            )
+        );
+    }
+}
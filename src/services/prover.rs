@@ -1,16 +1,17 @@
-use super::tails::TailsFileReader;
+use super::progress::{Progress, ProgressTailsAccessor};
+use super::tails::{InMemoryTailsReader, TailsFileReader};
 use super::types::{
     Credential, CredentialOffer, CredentialRequest, CredentialRequestMetadata, LinkSecret,
     Presentation, PresentationRequest, RevocationRegistryDefinition,
 };
 use crate::cl::{
     CredentialPublicKey, Issuer, Prover, RevocationRegistry, RevocationRegistryDelta,
-    SubProofRequest, Verifier, Witness,
+    RevocationTailsAccessor, SubProofRequest, Verifier, Witness,
 };
 use crate::data_types::cred_def::{CredentialDefinition, CredentialDefinitionId};
 use crate::data_types::credential::AttributeValues;
 use crate::data_types::pres_request::{
-    PresentationRequestPayload, RequestedAttributeInfo, RequestedPredicateInfo,
+    DisclosurePolicy, PresentationRequestPayload, RequestedAttributeInfo, RequestedPredicateInfo,
 };
 use crate::data_types::presentation::AttributeValue;
 use crate::data_types::presentation::Identifier;
@@ -18,13 +19,14 @@ use crate::data_types::presentation::RequestedProof;
 use crate::data_types::presentation::RevealedAttributeGroupInfo;
 use crate::data_types::presentation::RevealedAttributeInfo;
 use crate::data_types::presentation::SubProofReferent;
+use crate::data_types::rev_reg_def::RevocationRegistryDefinitionId;
 use crate::data_types::rev_status_list::RevocationStatusList;
 use crate::data_types::schema::{Schema, SchemaId};
 use crate::error::{Error, Result};
 use crate::services::helpers::{
     attr_common_view, build_credential_schema, build_credential_values,
-    build_non_credential_schema, get_predicates_for_credential,
-    get_revealed_attributes_for_credential, new_nonce,
+    build_non_credential_schema, credential_values_mismatches, current_timestamp,
+    get_predicates_for_credential, get_revealed_attributes_for_credential, new_nonce,
 };
 use crate::types::{CredentialRevocationState, PresentCredentials};
 use crate::utils::validation::Validatable;
@@ -167,6 +169,9 @@ pub fn create_credential_request(
 
 /// Process an incoming credential as received from the issuer.
 ///
+/// The credential is checked as by [`validate_credential`] before its signature is processed,
+/// except for its attribute names, which require the schema.
+///
 /// # Example
 ///
 /// ```rust
@@ -223,7 +228,8 @@ pub fn create_credential_request(
 /// credential_values.add_raw("age", "28").expect("Unable to add credential value");
 ///
 /// let mut credential =
-///     issuer::create_credential(&cred_def,
+///     issuer::create_credential(&schema,
+///                               &cred_def,
 ///                               &cred_def_priv,
 ///                               &credential_offer,
 ///                               &credential_request,
@@ -248,11 +254,13 @@ pub fn process_credential(
     trace!("process_credential >>> credential: {:?}, cred_request_metadata: {:?}, link_secret: {:?}, cred_def: {:?}, rev_reg_def: {:?}",
             credential, cred_request_metadata, secret!(&link_secret), cred_def, rev_reg_def);
 
+    check_credential(credential, None, cred_def, None, rev_reg_def)?;
+
     let credential_pub_key = CredentialPublicKey::build_from_parts(
         &cred_def.value.primary,
         cred_def.value.revocation.as_ref(),
     )?;
-    let credential_values = build_credential_values(&credential.values.0, Some(&link_secret))?;
+    let credential_values = build_credential_values(&credential.values.0, Some(link_secret))?;
     let rev_pub_key = rev_reg_def.map(|d| &d.value.public_keys.accum_key);
 
     Prover::process_credential_signature(
@@ -272,6 +280,121 @@ pub fn process_credential(
     Ok(())
 }
 
+/// Check a received credential against the objects it references, before storing it
+///
+/// Unlike [`process_credential`], which does not know the schema, this checks that:
+///
+/// - the credential values match the attribute names of `schema`
+/// - raw and encoded values match
+/// - the credential references the same schema as `cred_def`
+/// - `rev_reg_id` matches `rev_reg_def_id`, and `rev_reg_def` belongs to the credential
+///   definition
+///
+/// Every mismatch is reported in a single error, so a holder can refuse a credential which would
+/// later fail verification.
+pub fn validate_credential(
+    credential: &Credential,
+    schema: &Schema,
+    cred_def: &CredentialDefinition,
+    rev_reg_def_id: Option<&RevocationRegistryDefinitionId>,
+    rev_reg_def: Option<&RevocationRegistryDefinition>,
+) -> Result<()> {
+    trace!("validate_credential >>> credential: {:?}, schema: {:?}, cred_def: {:?}, rev_reg_def_id: {:?}, rev_reg_def: {:?}",
+            secret!(&credential), schema, cred_def, rev_reg_def_id, rev_reg_def);
+
+    check_credential(
+        credential,
+        Some(&schema.attr_names.0),
+        cred_def,
+        rev_reg_def_id,
+        rev_reg_def,
+    )?;
+
+    trace!("validate_credential <<<");
+
+    Ok(())
+}
+
+fn check_credential(
+    credential: &Credential,
+    attr_names: Option<&[String]>,
+    cred_def: &CredentialDefinition,
+    rev_reg_def_id: Option<&RevocationRegistryDefinitionId>,
+    rev_reg_def: Option<&RevocationRegistryDefinition>,
+) -> Result<()> {
+    // Without the schema, the credential attribute names are only checked for duplicates
+    let mut mismatches = match attr_names {
+        Some(attr_names) => credential_values_mismatches(attr_names, &credential.values.0)?,
+        None => {
+            let names: Vec<&String> = credential.values.0.keys().collect();
+            credential_values_mismatches(&names, &credential.values.0)?
+        }
+    };
+
+    if credential.schema_id != cred_def.schema_id {
+        mismatches.push(format!(
+            "schema_id `{}` differs from the credential definition schema_id `{}`",
+            credential.schema_id, cred_def.schema_id
+        ));
+    }
+
+    match (&credential.rev_reg_id, rev_reg_def) {
+        (Some(rev_reg_id), Some(rev_reg_def)) => {
+            if let Some(rev_reg_def_id) = rev_reg_def_id {
+                if rev_reg_id != rev_reg_def_id {
+                    mismatches.push(format!(
+                        "rev_reg_id `{rev_reg_id}` differs from the revocation registry definition id `{rev_reg_def_id}`"
+                    ));
+                }
+            }
+            if rev_reg_def.cred_def_id != credential.cred_def_id {
+                mismatches.push(format!(
+                    "revocation registry definition belongs to the credential definition `{}` instead of `{}`",
+                    rev_reg_def.cred_def_id, credential.cred_def_id
+                ));
+            }
+        }
+        (Some(rev_reg_id), None) => mismatches.push(format!(
+            "rev_reg_id `{rev_reg_id}` given, but no revocation registry definition"
+        )),
+        (None, Some(_)) => {
+            mismatches.push("revocation registry definition given, but no rev_reg_id".to_owned())
+        }
+        (None, None) => (),
+    }
+
+    if credential.rev_reg_id.is_some() && cred_def.value.revocation.is_none() {
+        mismatches.push(
+            "rev_reg_id given, but the credential definition does not support revocation"
+                .to_owned(),
+        );
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(err_msg!(
+            "Received credential is invalid: {}",
+            mismatches.join(", ")
+        ))
+    }
+}
+
+/// Options of [`create_presentation_with_options`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PresentationOptions {
+    /// Time of the presentation as seconds since the Unix epoch, the system clock when `None`
+    ///
+    /// Presentation requests with an `expires_at` before this time are refused.
+    pub now: Option<u64>,
+}
+
+impl PresentationOptions {
+    fn timestamp(&self) -> Result<u64> {
+        self.now.map_or_else(current_timestamp, Ok)
+    }
+}
+
 /// Process an incoming credential as received from the issuer.
 ///
 /// # Example
@@ -333,7 +456,8 @@ pub fn process_credential(
 /// credential_values.add_raw("age", "28").expect("Unable to add credential value");
 ///
 /// let mut credential =
-///     issuer::create_credential(&cred_def,
+///     issuer::create_credential(&schema,
+///                               &cred_def,
 ///                               &cred_def_priv,
 ///                               &credential_offer,
 ///                               &credential_request,
@@ -401,8 +525,29 @@ pub fn create_presentation(
     schemas: &HashMap<SchemaId, Schema>,
     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinition>,
 ) -> Result<Presentation> {
-    trace!("create_proof >>> credentials: {:?}, pres_req: {:?}, credentials: {:?}, self_attested: {:?}, link_secret: {:?}, schemas: {:?}, cred_defs: {:?}",
-            credentials, pres_req, credentials, &self_attested, secret!(&link_secret), schemas, cred_defs);
+    create_presentation_with_options(
+        pres_req,
+        credentials,
+        self_attested,
+        link_secret,
+        schemas,
+        cred_defs,
+        &PresentationOptions::default(),
+    )
+}
+
+/// Create a presentation with the given options
+pub fn create_presentation_with_options(
+    pres_req: &PresentationRequest,
+    credentials: PresentCredentials,
+    self_attested: Option<HashMap<String, String>>,
+    link_secret: &LinkSecret,
+    schemas: &HashMap<SchemaId, Schema>,
+    cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinition>,
+    options: &PresentationOptions,
+) -> Result<Presentation> {
+    trace!("create_proof >>> credentials: {:?}, pres_req: {:?}, credentials: {:?}, self_attested: {:?}, link_secret: {:?}, schemas: {:?}, cred_defs: {:?}, options: {:?}",
+            credentials, pres_req, credentials, &self_attested, secret!(&link_secret), schemas, cred_defs, options);
 
     if credentials.is_empty() && self_attested.as_ref().map_or(true, HashMap::is_empty) {
         return Err(err_msg!(
@@ -413,13 +558,41 @@ pub fn create_presentation(
     credentials.validate()?;
 
     let pres_req_val = pres_req.value();
+    if pres_req_val.expires_at.is_some() && pres_req_val.is_expired(options.timestamp()?) {
+        return Err(err_msg!("Presentation request has expired"));
+    }
+    let disclosure_policy = pres_req_val.disclosure_policy();
+
     let mut proof_builder = Prover::new_proof_builder()?;
     proof_builder.add_common_attribute("master_secret")?;
+    let common_attributes = pres_req_val
+        .common_attributes
+        .iter()
+        .map(|attr| attr_common_view(attr))
+        .collect::<Vec<String>>();
+    for attr in &common_attributes {
+        proof_builder.add_common_attribute(attr)?;
+    }
 
     let mut requested_proof = RequestedProof {
         self_attested_attrs: self_attested.unwrap_or_default(),
         ..Default::default()
     };
+    for (referent, value) in &requested_proof.self_attested_attrs {
+        let policy = pres_req_val
+            .requested_attributes
+            .get(referent)
+            .and_then(|info| info.self_attested.as_ref());
+        if let Some(policy) = policy {
+            policy.check(value).map_err(|err| {
+                err_msg!(
+                    "Self-attested value for referent \"{}\" is not accepted",
+                    referent
+                )
+                .with_cause(err)
+            })?;
+        }
+    }
 
     let mut sub_proof_index = 0;
     let non_credential_schema = build_non_credential_schema()?;
@@ -449,12 +622,24 @@ pub fn create_presentation(
         )?;
 
         let credential_schema = build_credential_schema(&schema.attr_names.0)?;
-        let credential_values = build_credential_values(&credential.values.0, Some(&link_secret))?;
-        let (req_attrs, req_predicates) = prepare_credential_for_proving(
+        let credential_values = build_credential_values(&credential.values.0, Some(link_secret))?;
+        let (mut req_attrs, req_predicates) = prepare_credential_for_proving(
             present.requested_attributes,
             present.requested_predicates,
             pres_req_val,
         )?;
+        for attr in &mut req_attrs {
+            if disclosure_policy == DisclosurePolicy::RevealAll && !attr.revealed {
+                return Err(err_msg!(
+                    "Attribute with referent \"{}\" must be revealed",
+                    attr.attr_referent
+                ));
+            }
+            attr.attr_info = attr.attr_info.with_optional_names(|name| {
+                get_credential_values_for_attribute(&credential.values.0, name).is_some()
+            });
+        }
+        check_common_attributes(&common_attributes, credential, &req_attrs)?;
         let sub_proof_request = build_sub_proof_request(&req_attrs, &req_predicates)?;
 
         update_requested_proof(
@@ -529,6 +714,16 @@ pub fn create_presentation(
         sub_proof_index += 1;
     }
 
+    if let Some(min_credentials) = pres_req_val.min_credentials {
+        if identifiers.len() < min_credentials as usize {
+            return Err(err_msg!(
+                "Presentation request requires at least {} credentials, {} presented",
+                min_credentials,
+                identifiers.len()
+            ));
+        }
+    }
+
     let proof = proof_builder.finalize(pres_req_val.nonce.as_native())?;
 
     let full_proof = Presentation {
@@ -625,7 +820,7 @@ pub fn create_revocation_state_with_witness(
 ///     prover::create_or_update_revocation_state(&rev_reg_def.value.tails_location,
 ///                                               &rev_reg_def,
 ///                                               &rev_status_list,
-///                                               0,
+///                                               1,
 ///                                               None,
 ///                                               None
 ///                                               ).expect("Unable to create or update the revocation state");
@@ -638,6 +833,79 @@ pub fn create_or_update_revocation_state(
     rev_state: Option<&CredentialRevocationState>, // for witness update
     old_rev_status_list: Option<&RevocationStatusList>, // for witness update
 ) -> Result<CredentialRevocationState> {
+    create_or_update_revocation_state_with_progress(
+        tails_path,
+        rev_reg_def,
+        rev_status_list,
+        rev_reg_idx,
+        rev_state,
+        old_rev_status_list,
+        &Progress::default(),
+    )
+}
+
+/// Variant of [`create_or_update_revocation_state`] reporting the witness computation to
+/// `progress`
+///
+/// Every tail read from the tails file is a step, one for each credential issued, or changed
+/// since `old_rev_status_list` when updating.
+pub fn create_or_update_revocation_state_with_progress(
+    tails_path: &str,
+    rev_reg_def: &RevocationRegistryDefinition,
+    rev_status_list: &RevocationStatusList,
+    rev_reg_idx: u32,
+    rev_state: Option<&CredentialRevocationState>,
+    old_rev_status_list: Option<&RevocationStatusList>,
+    progress: &Progress<'_>,
+) -> Result<CredentialRevocationState> {
+    let tails_reader = TailsFileReader::new(tails_path)?;
+    create_or_update_revocation_state_with_accessor(
+        &tails_reader,
+        rev_reg_def,
+        rev_status_list,
+        rev_reg_idx,
+        rev_state,
+        old_rev_status_list,
+        progress,
+    )
+}
+
+/// Variant of [`create_or_update_revocation_state`] reading the tails from memory
+///
+/// This lets a holder use tails downloaded from the `tails_location` of `rev_reg_def`, or
+/// written with an [`crate::tails::InMemoryTailsWriter`], without storing them in a file.
+pub fn create_or_update_revocation_state_with_tails(
+    tails: &InMemoryTailsReader,
+    rev_reg_def: &RevocationRegistryDefinition,
+    rev_status_list: &RevocationStatusList,
+    rev_reg_idx: u32,
+    rev_state: Option<&CredentialRevocationState>,
+    old_rev_status_list: Option<&RevocationStatusList>,
+) -> Result<CredentialRevocationState> {
+    create_or_update_revocation_state_with_accessor(
+        tails,
+        rev_reg_def,
+        rev_status_list,
+        rev_reg_idx,
+        rev_state,
+        old_rev_status_list,
+        &Progress::default(),
+    )
+}
+
+/// Create or update the revocation state, reading the tails from any accessor
+fn create_or_update_revocation_state_with_accessor<TA>(
+    tails_reader: &TA,
+    rev_reg_def: &RevocationRegistryDefinition,
+    rev_status_list: &RevocationStatusList,
+    rev_reg_idx: u32,
+    rev_state: Option<&CredentialRevocationState>,
+    old_rev_status_list: Option<&RevocationStatusList>,
+    progress: &Progress<'_>,
+) -> Result<CredentialRevocationState>
+where
+    TA: RevocationTailsAccessor,
+{
     trace!(
         "create_or_update_revocation_state >>> revoc_reg_def: {:?}, \
     rev_status_list: {:?}, rev_reg_idx: {},  rev_state: {:?}, old_rev_status_list {:?}",
@@ -657,9 +925,12 @@ pub fn create_or_update_revocation_state(
         err_msg!("Timestamp is required to create or update the revocation state")
     })?;
 
+    let tails_reader = ProgressTailsAccessor {
+        tails_reader,
+        progress,
+    };
     let mut issued = HashSet::<u32>::new();
     let mut revoked = HashSet::<u32>::new();
-    let tails_reader = TailsFileReader::new(tails_path)?;
     let witness = if let (Some(source_rev_state), Some(source_rev_list)) =
         (rev_state, old_rev_status_list)
     {
@@ -681,13 +952,16 @@ pub fn create_or_update_revocation_state(
             &revoked,
         );
 
+        progress.start((issued.len() + revoked.len()) as u64)?;
         let mut witness = source_rev_state.witness.clone();
-        witness.update(
-            rev_reg_idx,
-            rev_reg_def.value.max_cred_num,
-            &rev_reg_delta,
-            &tails_reader,
-        )?;
+        witness
+            .update(
+                rev_reg_idx,
+                rev_reg_def.value.max_cred_num,
+                &rev_reg_delta,
+                &tails_reader,
+            )
+            .map_err(|err| progress.map_cancelled(err))?;
         witness
     } else {
         let list_size = usize::try_from(rev_reg_def.value.max_cred_num)
@@ -702,6 +976,7 @@ pub fn create_or_update_revocation_state(
             &mut revoked,
         );
         let rev_reg_delta = RevocationRegistryDelta::from_parts(None, &rev_reg, &issued, &revoked);
+        progress.start((list_size - revoked.len()).saturating_sub(1) as u64)?;
         Witness::new(
             rev_reg_idx,
             rev_reg_def.value.max_cred_num,
@@ -709,8 +984,10 @@ pub fn create_or_update_revocation_state(
             true,
             &rev_reg_delta,
             &tails_reader,
-        )?
+        )
+        .map_err(|err| progress.map_cancelled(err))?
     };
+    progress.finish();
 
     Ok(CredentialRevocationState {
         witness,
@@ -770,9 +1047,9 @@ fn prepare_credential_for_proving(
     }
 
     for predicate_referent in requested_predicates {
-        let predicate_info = pres_req
-            .requested_predicates
-            .get(predicate_referent.as_str())
+        // A range is proven by its two bound predicates under the same referent
+        let predicate_infos = pres_req
+            .predicates_for_referent(predicate_referent.as_str())
             .ok_or_else(|| {
                 err_msg!(
                     "PredicateInfo not found in PresentationRequest for referent \"{}\"",
@@ -780,10 +1057,12 @@ fn prepare_credential_for_proving(
                 )
             })?;
 
-        preds.push(RequestedPredicateInfo {
-            predicate_referent,
-            predicate_info: predicate_info.clone(),
-        });
+        for predicate_info in predicate_infos {
+            preds.push(RequestedPredicateInfo {
+                predicate_referent: predicate_referent.clone(),
+                predicate_info,
+            });
+        }
     }
 
     trace!(
@@ -832,7 +1111,8 @@ fn update_requested_proof(
 
     for attr_info in req_attrs_for_credential {
         if attr_info.revealed {
-            let attribute = &proof_req.requested_attributes[&attr_info.attr_referent];
+            // Optional group members are already resolved against the credential
+            let attribute = &attr_info.attr_info;
 
             if let Some(name) = &attribute.name {
                 let attribute_values =
@@ -881,6 +1161,15 @@ fn update_requested_proof(
     }
 
     for predicate_info in req_predicates_for_credential {
+        let name = &predicate_info.predicate_info.name;
+        let attr_value = get_credential_values_for_attribute(&credential.values.0, name)
+            .ok_or_else(|| err_msg!("Credential value not found for attribute {:?}", name))?;
+        if attr_value.encoded.parse::<i32>().is_err() {
+            return Err(err_msg!(
+                "Attribute {:?} is not encoded as a 32-bit integer and cannot be used in a predicate",
+                name
+            ));
+        }
         requested_proof.predicates.insert(
             predicate_info.predicate_referent,
             SubProofReferent { sub_proof_index },
@@ -892,6 +1181,48 @@ fn update_requested_proof(
     Ok(())
 }
 
+/// Ensure every common attribute of the request is held by the credential and kept hidden,
+/// as its blinded value has to be present in each sub proof.
+fn check_common_attributes(
+    common_attributes: &[String],
+    credential: &Credential,
+    req_attrs_for_credential: &[RequestedAttributeInfo],
+) -> Result<()> {
+    for common_attr in common_attributes {
+        if !credential
+            .values
+            .0
+            .keys()
+            .any(|attr| attr_common_view(attr) == *common_attr)
+        {
+            return Err(err_msg!(
+                "Common attribute `{}` is not present in credential: {}",
+                common_attr,
+                credential.cred_def_id
+            ));
+        }
+
+        let revealed = req_attrs_for_credential
+            .iter()
+            .filter(|attr| attr.revealed)
+            .flat_map(|attr| {
+                attr.attr_info
+                    .name
+                    .iter()
+                    .chain(attr.attr_info.names.iter().flatten())
+            })
+            .any(|name| attr_common_view(name) == *common_attr);
+        if revealed {
+            return Err(err_msg!(
+                "Common attribute `{}` cannot be revealed",
+                common_attr
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn build_sub_proof_request(
     req_attrs_for_credential: &[RequestedAttributeInfo],
     req_predicates_for_credential: &[RequestedPredicateInfo],
@@ -919,7 +1250,7 @@ fn build_sub_proof_request(
         sub_proof_request_builder.add_predicate(
             &attr_common_view(&predicate.predicate_info.name),
             &p_type,
-            predicate.predicate_info.p_value,
+            predicate.predicate_info.range_value()?,
         )?;
     }
 
@@ -964,7 +1295,9 @@ mod tests {
     }
 
     mod prepare_credentials_for_proving {
-        use crate::data_types::pres_request::{AttributeInfo, PredicateInfo};
+        use crate::data_types::pres_request::{
+            AttributeInfo, PredicateInfo, RangeBound, RangePredicateInfo,
+        };
 
         use super::*;
 
@@ -975,8 +1308,11 @@ mod tests {
             AttributeInfo {
                 name: Some("name".to_string()),
                 names: None,
+                optional_names: None,
                 restrictions: None,
                 non_revoked: None,
+                optional: false,
+                self_attested: None,
             }
         }
 
@@ -987,6 +1323,7 @@ mod tests {
                 p_value: 8,
                 restrictions: None,
                 non_revoked: None,
+                optional: false,
             }
         }
 
@@ -1001,7 +1338,13 @@ mod tests {
                 requested_predicates: hashmap!(
                     PREDICATE_REFERENT.to_string() => _predicate_info()
                 ),
+                requested_ranges: HashMap::new(),
+                common_attributes: Vec::new(),
                 non_revoked: None,
+                expires_at: None,
+                verifier: None,
+                min_credentials: None,
+                disclosure_policy: None,
             }
         }
 
@@ -1036,8 +1379,11 @@ mod tests {
                 AttributeInfo {
                     name: Some("last_name".to_string()),
                     names: None,
+                    optional_names: None,
                     restrictions: None,
                     non_revoked: None,
+                    optional: false,
+                    self_attested: None,
                 },
             );
 
@@ -1069,6 +1415,34 @@ mod tests {
             let res = prepare_credential_for_proving(req_attrs, req_preds, &proof_req);
             assert_kind!(Input, res);
         }
+
+        #[test]
+        fn prepare_credential_for_proving_works_for_range() {
+            let (req_attrs, mut req_preds) = _req_cred();
+            let mut proof_req = _proof_req();
+
+            req_preds.insert("range_referent".to_string());
+            proof_req.requested_ranges.insert(
+                "range_referent".to_string(),
+                RangePredicateInfo::new(
+                    "age",
+                    RangeBound::new(PredicateTypes::GE, 18),
+                    RangeBound::new(PredicateTypes::LT, 65),
+                ),
+            );
+
+            let (_, req_pred_info) =
+                prepare_credential_for_proving(req_attrs, req_preds, &proof_req).unwrap();
+
+            assert_eq!(3, req_pred_info.len());
+            assert_eq!(
+                2,
+                req_pred_info
+                    .iter()
+                    .filter(|info| info.predicate_referent == "range_referent")
+                    .count()
+            );
+        }
     }
 
     mod get_credential_values_for_attribute {
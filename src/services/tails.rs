@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use rand::random;
 use sha2::{Digest, Sha256};
 
+use super::progress::Progress;
 use crate::cl::{
     Error as ClError, ErrorKind as ClErrorKind, RevocationTailsAccessor, RevocationTailsGenerator,
     Tail,
@@ -16,6 +17,7 @@ use crate::utils::base58;
 use crate::ErrorKind;
 
 const TAILS_BLOB_TAG_SZ: u8 = 2;
+pub(crate) const TAILS_BLOB_VERSION: [u8; 2] = [0, 2];
 const TAIL_SIZE: usize = Tail::BYTES_REPR_SIZE;
 
 #[derive(Debug)]
@@ -74,11 +76,63 @@ impl RevocationTailsAccessor for TailsFileReader {
     }
 }
 
+/// Tails accessor over a tails blob held in memory
+#[derive(Debug, Clone)]
+pub struct InMemoryTailsReader {
+    tails: Vec<u8>,
+}
+
+impl InMemoryTailsReader {
+    /// Create a reader from the contents of a tails file, including the version tag
+    pub fn new(tails: Vec<u8>) -> Result<Self, Error> {
+        if tails.len() < TAILS_BLOB_TAG_SZ as usize
+            || (tails.len() - TAILS_BLOB_TAG_SZ as usize) % TAIL_SIZE != 0
+        {
+            return Err(err_msg!("Invalid tails file size {}", tails.len()));
+        }
+        Ok(Self { tails })
+    }
+}
+
+impl RevocationTailsAccessor for InMemoryTailsReader {
+    fn access_tail(
+        &self,
+        tail_id: u32,
+        accessor: &mut dyn FnMut(&Tail),
+    ) -> std::result::Result<(), ClError> {
+        let start = TAIL_SIZE * tail_id as usize + TAILS_BLOB_TAG_SZ as usize;
+        let tail_bytes = self.tails.get(start..start + TAIL_SIZE).ok_or_else(|| {
+            ClError::new(
+                ClErrorKind::InvalidState,
+                "Tail index is outside of the tails file",
+            )
+        })?;
+
+        let tail = Tail::from_bytes(tail_bytes)?;
+        accessor(&tail);
+        Ok(())
+    }
+}
+
 pub trait TailsWriter: std::fmt::Debug {
     fn write(
         &mut self,
         generator: &mut RevocationTailsGenerator,
     ) -> Result<(String, String), Error>;
+
+    /// Write the tails, reporting every generated tail as a step of `progress`
+    ///
+    /// Writers which do not override this only report the start and completion of the write.
+    fn write_with_progress(
+        &mut self,
+        generator: &mut RevocationTailsGenerator,
+        progress: &Progress<'_>,
+    ) -> Result<(String, String), Error> {
+        progress.start(u64::from(generator.count()))?;
+        let result = self.write(generator)?;
+        progress.finish();
+        Ok(result)
+    }
 }
 
 #[derive(Debug)]
@@ -100,6 +154,14 @@ impl TailsWriter for TailsFileWriter {
     fn write(
         &mut self,
         generator: &mut RevocationTailsGenerator,
+    ) -> Result<(String, String), Error> {
+        self.write_with_progress(generator, &Progress::default())
+    }
+
+    fn write_with_progress(
+        &mut self,
+        generator: &mut RevocationTailsGenerator,
+        progress: &Progress<'_>,
     ) -> Result<(String, String), Error> {
         struct TempFile<'a>(&'a Path);
         impl TempFile<'_> {
@@ -117,6 +179,7 @@ impl TailsWriter for TailsFileWriter {
             }
         }
 
+        progress.start(u64::from(generator.count()))?;
         let temp_name = format!("{:020}.tmp", random::<u64>());
         let temp_path = self.root_path.join(temp_name);
         let file = File::options()
@@ -127,20 +190,11 @@ impl TailsWriter for TailsFileWriter {
             .map_err(|e| err_msg!(IOError, "Error creating tails temp file {temp_path:?}: {e}"))?;
         let temp_handle = TempFile(&temp_path);
         let mut buf = BufWriter::new(file);
-        let mut hasher = Sha256::default();
-        let version = &[0u8, 2u8];
-        buf.write_all(version)?;
-        hasher.update(version);
-        while let Some(tail) = generator.try_next()? {
-            let tail_bytes = tail.to_bytes()?;
-            buf.write_all(&tail_bytes)?;
-            hasher.update(&tail_bytes);
-        }
+        let hash = write_tails(generator, &mut buf, progress)?;
         let mut file = buf
             .into_inner()
             .map_err(|e| err_msg!("Error flushing output file: {e}"))?;
         let tails_size = file.stream_position()?;
-        let hash = base58::encode(hasher.finalize());
         let target_path = self.root_path.join(&hash);
         drop(file);
         temp_handle.rename(&target_path)?;
@@ -149,6 +203,239 @@ impl TailsWriter for TailsFileWriter {
             "TailsFileWriter: wrote tails file [size {}]: {}",
             tails_size, target_path
         );
+        progress.finish();
         Ok((target_path, hash))
     }
 }
+
+/// Write the tails blob to `sink`, returning its base58 encoded SHA-256 hash
+fn write_tails<W: Write>(
+    generator: &mut RevocationTailsGenerator,
+    sink: &mut W,
+    progress: &Progress<'_>,
+) -> Result<String, Error> {
+    let mut hasher = Sha256::default();
+    sink.write_all(&TAILS_BLOB_VERSION)?;
+    hasher.update(TAILS_BLOB_VERSION);
+    while let Some(tail) = generator.try_next()? {
+        let tail_bytes = tail.to_bytes()?;
+        sink.write_all(&tail_bytes)?;
+        hasher.update(&tail_bytes);
+        progress.step()?;
+    }
+    sink.flush()?;
+    Ok(base58::encode(hasher.finalize()))
+}
+
+/// Streams tails to any [`Write`] sink, such as an upload to object storage
+///
+/// The returned location is the one given to [`TailsStreamWriter::with_location`], or the tails
+/// hash otherwise. Writes are not buffered.
+#[derive(Debug)]
+pub struct TailsStreamWriter<W> {
+    sink: W,
+    location: Option<String>,
+}
+
+impl<W: Write + Debug> TailsStreamWriter<W> {
+    pub const fn new(sink: W) -> Self {
+        Self {
+            sink,
+            location: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+
+impl<W: Write + Debug> TailsWriter for TailsStreamWriter<W> {
+    fn write(
+        &mut self,
+        generator: &mut RevocationTailsGenerator,
+    ) -> Result<(String, String), Error> {
+        self.write_with_progress(generator, &Progress::default())
+    }
+
+    fn write_with_progress(
+        &mut self,
+        generator: &mut RevocationTailsGenerator,
+        progress: &Progress<'_>,
+    ) -> Result<(String, String), Error> {
+        progress.start(u64::from(generator.count()))?;
+        let hash = write_tails(generator, &mut self.sink, progress)?;
+        progress.finish();
+        let location = self.location.clone().unwrap_or_else(|| hash.clone());
+        Ok((location, hash))
+    }
+}
+
+/// Keeps the tails in memory, to be stored or published by the caller
+#[derive(Debug)]
+pub struct InMemoryTailsWriter {
+    writer: TailsStreamWriter<Vec<u8>>,
+}
+
+impl Default for InMemoryTailsWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryTailsWriter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            writer: TailsStreamWriter::new(Vec::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn with_location(self, location: impl Into<String>) -> Self {
+        Self {
+            writer: self.writer.with_location(location),
+        }
+    }
+
+    /// The tails blob, including the version tag
+    #[must_use]
+    pub fn tails(&self) -> &[u8] {
+        &self.writer.sink
+    }
+
+    #[must_use]
+    pub fn into_tails(self) -> Vec<u8> {
+        self.writer.into_inner()
+    }
+
+    /// Read the written tails without going through a file
+    pub fn into_reader(self) -> Result<InMemoryTailsReader, Error> {
+        InMemoryTailsReader::new(self.into_tails())
+    }
+}
+
+impl TailsWriter for InMemoryTailsWriter {
+    fn write(
+        &mut self,
+        generator: &mut RevocationTailsGenerator,
+    ) -> Result<(String, String), Error> {
+        self.writer.write(generator)
+    }
+
+    fn write_with_progress(
+        &mut self,
+        generator: &mut RevocationTailsGenerator,
+        progress: &Progress<'_>,
+    ) -> Result<(String, String), Error> {
+        self.writer.write_with_progress(generator, progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::cred_def::CredentialDefinition;
+    use crate::issuer;
+    use crate::prover::create_or_update_revocation_state_with_tails;
+    use crate::types::{CredentialDefinitionConfig, RegistryType, SignatureType};
+
+    fn cred_def() -> CredentialDefinition {
+        let schema = issuer::create_schema(
+            "name",
+            "1.0",
+            "mock:uri".try_into().unwrap(),
+            vec!["name".to_owned()].into(),
+        )
+        .unwrap();
+        let (cred_def, _, _) = issuer::create_credential_definition(
+            "mock:uri".try_into().unwrap(),
+            &schema,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            SignatureType::CL,
+            CredentialDefinitionConfig {
+                support_revocation: true,
+            },
+        )
+        .unwrap();
+        cred_def
+    }
+
+    #[test]
+    fn test_in_memory_tails_writer() {
+        let cred_def = cred_def();
+        let mut tails_writer = InMemoryTailsWriter::new();
+        let (rev_reg_def, rev_reg_def_priv) = issuer::create_revocation_registry_def(
+            &cred_def,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            RegistryType::CL_ACCUM,
+            10,
+            &mut tails_writer,
+        )
+        .unwrap();
+
+        let tails = tails_writer.tails();
+        assert_eq!(tails.len(), 2 + 21 * TAIL_SIZE);
+        assert_eq!(
+            base58::encode(Sha256::digest(tails)),
+            rev_reg_def.value.tails_hash
+        );
+        assert_eq!(
+            rev_reg_def.value.tails_location,
+            rev_reg_def.value.tails_hash
+        );
+
+        let rev_status_list = issuer::create_revocation_status_list(
+            &cred_def,
+            "mock:uri".try_into().unwrap(),
+            &rev_reg_def,
+            &rev_reg_def_priv,
+            true,
+            Some(10),
+        )
+        .unwrap();
+        create_or_update_revocation_state_with_tails(
+            &tails_writer.into_reader().unwrap(),
+            &rev_reg_def,
+            &rev_status_list,
+            1,
+            None,
+            None,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_tails_stream_writer() {
+        let cred_def = cred_def();
+        let mut tails_writer =
+            TailsStreamWriter::new(Vec::new()).with_location("https://tails.example/upload");
+        let (rev_reg_def, _) = issuer::create_revocation_registry_def(
+            &cred_def,
+            "mock:uri".try_into().unwrap(),
+            "tag",
+            RegistryType::CL_ACCUM,
+            10,
+            &mut tails_writer,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rev_reg_def.value.tails_location,
+            "https://tails.example/upload"
+        );
+        let tails = tails_writer.into_inner();
+        assert_eq!(
+            base58::encode(Sha256::digest(&tails)),
+            rev_reg_def.value.tails_hash
+        );
+    }
+}
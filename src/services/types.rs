@@ -168,6 +168,7 @@ impl<'a, 'p> AddCredential<'a, 'p> {
             .insert((referent.into(), revealed));
     }
 
+    /// Add a requested predicate or range referent
     pub fn add_requested_predicate(&mut self, referent: impl Into<String>) {
         self.present.requested_predicates.insert(referent.into());
     }
@@ -190,6 +191,7 @@ pub(crate) struct RequestedPredicate<'a> {
     pub rev_state: Option<&'a CredentialRevocationState>,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct ProvingCredentialKey {
     pub cred_id: String,
@@ -1,5 +1,6 @@
 use super::helpers::attr_common_view;
 use super::helpers::new_nonce;
+use super::nonce_registry::NonceRegistry;
 use super::types::Presentation;
 use super::types::PresentationRequest;
 use super::types::RevocationRegistryDefinition;
@@ -7,9 +8,11 @@ use super::types::RevocationStatusList;
 use crate::cl::{CredentialPublicKey, RevocationRegistry, Verifier};
 use crate::data_types::cred_def::CredentialDefinition;
 use crate::data_types::cred_def::CredentialDefinitionId;
+use crate::data_types::cred_def::{CredentialDefinitionSupersedes, SupersededPolicy};
 use crate::data_types::issuer_id::IssuerId;
 use crate::data_types::nonce::Nonce;
 use crate::data_types::pres_request::AttributeInfo;
+use crate::data_types::pres_request::DisclosurePolicy;
 use crate::data_types::pres_request::NonRevokedInterval;
 use crate::data_types::pres_request::PresentationRequestPayload;
 use crate::data_types::presentation::{Identifier, RequestedProof, RevealedAttributeInfo};
@@ -20,8 +23,10 @@ use crate::error::Result;
 use crate::services::helpers::build_credential_schema;
 use crate::services::helpers::build_non_credential_schema;
 use crate::services::helpers::build_sub_proof_request;
+use crate::services::helpers::current_timestamp;
 use crate::services::helpers::get_predicates_for_credential;
 use crate::services::helpers::get_revealed_attributes_for_credential;
+use crate::utils::did_indy::{self, DID_INDY_NAMESPACE};
 use crate::utils::query::Query;
 use crate::utils::validation::LEGACY_DID_IDENTIFIER;
 
@@ -42,6 +47,76 @@ pub struct Filter {
 static INTERNAL_TAG_MATCHER: Lazy<Regex> =
     Lazy::new(|| Regex::new("^attr::([^:]+)::(value|marker)$").unwrap());
 
+/// Options of [`verify_presentation_with_options`]
+#[derive(Debug, Default)]
+pub struct VerificationOptions<'a> {
+    /// The `did:indy` namespace which legacy identifiers belong to
+    ///
+    /// When set, restrictions on `schema_id`, `cred_def_id` and the issuer DIDs match identifiers
+    /// in either the legacy or the `did:indy` form of that namespace, e.g. a restriction on
+    /// `DXoTtQJNtXtiwWaZAK3rB1:2:gvt:1.0` matches a credential for schema
+    /// `did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/gvt/1.0` and vice versa when
+    /// the namespace is `sovrin`. When `None`, only identical identifiers match.
+    pub legacy_identifier_namespace: Option<String>,
+    /// Time of the verification as seconds since the Unix epoch, the system clock when `None`
+    ///
+    /// Presentation requests with an `expires_at` before this time are rejected.
+    pub now: Option<u64>,
+    /// Registry the nonce of the presentation request was issued through
+    ///
+    /// When set, only a valid presentation consumes the request nonce at `now`, and verification
+    /// fails with [`crate::ErrorKind::ProofRejected`] if the nonce was not issued through the
+    /// registry, is outside of its time window, or was already consumed by an earlier
+    /// presentation.
+    pub nonce_registry: Option<&'a mut dyn NonceRegistry>,
+    /// Report filled in with the outcome of the verification, see [`VerificationReport`]
+    pub report: Option<&'a mut VerificationReport>,
+}
+
+impl VerificationOptions<'_> {
+    fn timestamp(&self) -> Result<u64> {
+        self.now.map_or_else(current_timestamp, Ok)
+    }
+}
+
+/// Outcome of a verification, reported through [`VerificationOptions::report`]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationReport {
+    pub valid: bool,
+    /// Referents of optional attributes, predicates and ranges left out by the prover
+    pub omitted_referents: Vec<String>,
+}
+
+/// The referents of the presentation request which are not answered by the presentation
+///
+/// A presentation omitting any referent which is not optional fails verification.
+pub fn omitted_referents(
+    presentation: &Presentation,
+    pres_req: &PresentationRequest,
+) -> Vec<String> {
+    let pres_req = pres_req.value();
+    let requested_proof = &presentation.requested_proof;
+
+    let mut omitted: Vec<String> = pres_req
+        .requested_attributes
+        .keys()
+        .filter(|referent| {
+            !requested_proof.revealed_attrs.contains_key(*referent)
+                && !requested_proof.revealed_attr_groups.contains_key(*referent)
+                && !requested_proof.unrevealed_attrs.contains_key(*referent)
+                && !requested_proof.self_attested_attrs.contains_key(*referent)
+        })
+        .chain(
+            pres_req
+                .predicate_referents()
+                .filter(|referent| !requested_proof.predicates.contains_key(*referent)),
+        )
+        .cloned()
+        .collect();
+    omitted.sort();
+    omitted
+}
+
 /// Verify an incoming proof presentation
 pub fn verify_presentation(
     presentation: &Presentation,
@@ -54,8 +129,46 @@ pub fn verify_presentation(
         &HashMap<RevocationRegistryDefinitionId, HashMap<u64, u64>>,
     >,
 ) -> Result<bool> {
-    trace!("verify >>> presentation: {:?}, pres_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_status_lists: {:?}",
-    presentation, pres_req, schemas, cred_defs, rev_reg_defs, rev_status_lists);
+    verify_presentation_with_options(
+        presentation,
+        pres_req,
+        schemas,
+        cred_defs,
+        rev_reg_defs,
+        rev_status_lists,
+        nonrevoke_interval_override,
+        VerificationOptions::default(),
+    )
+}
+
+/// Verify an incoming proof presentation with the given options
+#[allow(clippy::too_many_arguments)]
+pub fn verify_presentation_with_options(
+    presentation: &Presentation,
+    pres_req: &PresentationRequest,
+    schemas: &HashMap<SchemaId, Schema>,
+    cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinition>,
+    rev_reg_defs: Option<&HashMap<RevocationRegistryDefinitionId, RevocationRegistryDefinition>>,
+    rev_status_lists: Option<Vec<RevocationStatusList>>,
+    nonrevoke_interval_override: Option<
+        &HashMap<RevocationRegistryDefinitionId, HashMap<u64, u64>>,
+    >,
+    options: VerificationOptions<'_>,
+) -> Result<bool> {
+    trace!("verify >>> presentation: {:?}, pres_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_status_lists: {:?}, options: {:?}",
+    presentation, pres_req, schemas, cred_defs, rev_reg_defs, rev_status_lists, options);
+
+    let legacy_namespace = options.legacy_identifier_namespace.as_deref();
+    if let Some(namespace) = legacy_namespace {
+        if DID_INDY_NAMESPACE.captures(namespace).is_none() {
+            return Err(err_msg!("Invalid did:indy namespace {}", namespace));
+        }
+    }
+
+    let now = options.timestamp()?;
+    if pres_req.value().is_expired(now) {
+        return Err(err_msg!(ProofRejected, "Presentation request has expired"));
+    }
 
     // These values are from the prover and cannot be trusted
     let received_revealed_attrs: HashMap<String, Identifier> =
@@ -65,6 +178,10 @@ pub fn verify_presentation(
     let received_predicates: HashMap<String, Identifier> = received_predicates(presentation)?;
     let received_self_attested_attrs: HashSet<String> = received_self_attested_attrs(presentation);
 
+    let omitted = options
+        .report
+        .is_some()
+        .then(|| omitted_referents(presentation, pres_req));
     let pres_req = pres_req.value();
 
     // Ensures that all attributes in the request is also in the presentation
@@ -76,6 +193,9 @@ pub fn verify_presentation(
         &received_predicates,
     )?;
 
+    // Ensures the presentation follows the request's disclosure requirements
+    verify_disclosure_requirements(pres_req, presentation)?;
+
     // Ensures the encoded values are same as request
     verify_revealed_attribute_values(pres_req, presentation)?;
 
@@ -89,9 +209,13 @@ pub fn verify_presentation(
         &received_unrevealed_attrs,
         &received_predicates,
         &received_self_attested_attrs,
+        legacy_namespace,
     )?;
 
     let mut proof_verifier = Verifier::new_proof_verifier()?;
+    for attr in &pres_req.common_attributes {
+        proof_verifier.add_common_attribute(&attr_common_view(attr))?;
+    }
     let non_credential_schema = build_non_credential_schema()?;
 
     for sub_proof_index in 0..presentation.identifiers.len() {
@@ -127,9 +251,7 @@ pub fn verify_presentation(
                     err_msg!(Unexpected, "Revocation status list missing accumulator")
                 })?;
 
-                map.entry(id)
-                    .or_insert_with(HashMap::new)
-                    .insert(timestamp, rev_reg);
+                map.entry(id).or_default().insert(timestamp, rev_reg);
             }
             Some(map)
         } else {
@@ -270,6 +392,18 @@ pub fn verify_presentation(
 
     let valid = proof_verifier.verify(&presentation.proof, pres_req.nonce.as_native())?;
 
+    if valid {
+        if let Some(nonce_registry) = options.nonce_registry {
+            nonce_registry.consume(&pres_req.nonce, now)?;
+        }
+    }
+    if let (Some(report), Some(omitted_referents)) = (options.report, omitted) {
+        *report = VerificationReport {
+            valid,
+            omitted_referents,
+        };
+    }
+
     trace!("verify <<< valid: {:?}", valid);
 
     Ok(valid)
@@ -280,6 +414,47 @@ pub fn generate_nonce() -> Result<Nonce> {
     new_nonce()
 }
 
+/// Check the credential definitions used in a presentation against published
+/// [`CredentialDefinitionSupersedes`] records
+///
+/// Returns `false` if the presentation uses a superseded credential definition which is not
+/// accepted by `policy`. This does not verify the presentation itself, which is done by
+/// [`verify_presentation`].
+pub fn verify_superseded_credential_definitions(
+    presentation: &Presentation,
+    supersedes: &[CredentialDefinitionSupersedes],
+    policy: SupersededPolicy,
+) -> bool {
+    trace!(
+        "verify_superseded_credential_definitions >>> presentation: {:?}, supersedes: {:?}, policy: {:?}",
+        presentation,
+        supersedes,
+        policy
+    );
+
+    let rejected = presentation.identifiers.iter().find_map(|identifier| {
+        supersedes.iter().find(|record| {
+            record.supersedes == identifier.cred_def_id && !record.is_accepted(policy)
+        })
+    });
+
+    if let Some(record) = rejected {
+        info!(
+            "Credential definition {} is superseded ({:?}) and rejected by policy {:?}",
+            record.supersedes, record.reason, policy
+        );
+    }
+
+    let valid = rejected.is_none();
+
+    trace!(
+        "verify_superseded_credential_definitions <<< valid: {:?}",
+        valid
+    );
+
+    valid
+}
+
 fn compare_attr_from_proof_and_request(
     pres_req: &PresentationRequestPayload,
     received_revealed_attrs: &HashMap<String, Identifier>,
@@ -298,7 +473,12 @@ fn compare_attr_from_proof_and_request(
         .cloned()
         .collect();
 
-    if requested_attrs != received_attrs {
+    // Only optional referents may be left out
+    let missing_attrs = requested_attrs
+        .iter()
+        .any(|referent| !received_attrs.contains(referent) && !pres_req.is_optional(referent));
+
+    if missing_attrs || !received_attrs.is_subset(&requested_attrs) {
         return Err(err_msg!(
             "Requested attributes {:?} do not correspond to received {:?}",
             requested_attrs,
@@ -306,11 +486,15 @@ fn compare_attr_from_proof_and_request(
         ));
     }
 
-    let requested_predicates: HashSet<&String> = pres_req.requested_predicates.keys().collect();
+    let requested_predicates: HashSet<&String> = pres_req.predicate_referents().collect();
 
     let received_predicates_: HashSet<&String> = received_predicates.keys().collect();
 
-    if requested_predicates != received_predicates_ {
+    let missing_predicates = requested_predicates.iter().any(|referent| {
+        !received_predicates_.contains(referent) && !pres_req.is_optional(referent)
+    });
+
+    if missing_predicates || !received_predicates_.is_subset(&requested_predicates) {
         return Err(err_msg!(
             "Requested predicates {:?} do not correspond to received {:?}",
             requested_predicates,
@@ -377,6 +561,34 @@ fn get_proof_identifier(proof: &Presentation, index: u32) -> Result<Identifier>
         .ok_or_else(|| err_msg!("Identifier not found for index: {}", index))
 }
 
+fn verify_disclosure_requirements(
+    pres_req: &PresentationRequestPayload,
+    proof: &Presentation,
+) -> Result<()> {
+    if pres_req.disclosure_policy() == DisclosurePolicy::RevealAll {
+        if let Some(attr_referent) = proof.requested_proof.unrevealed_attrs.keys().next() {
+            return Err(err_msg!(
+                ProofRejected,
+                "Attribute with referent \"{}\" must be revealed",
+                attr_referent
+            ));
+        }
+    }
+
+    if let Some(min_credentials) = pres_req.min_credentials {
+        if proof.identifiers.len() < min_credentials as usize {
+            return Err(err_msg!(
+                ProofRejected,
+                "Presentation request requires at least {} credentials, {} presented",
+                min_credentials,
+                proof.identifiers.len()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn verify_revealed_attribute_values(
     pres_req: &PresentationRequestPayload,
     proof: &Presentation,
@@ -417,8 +629,8 @@ fn verify_revealed_attribute_values(
                     attr_referent,
                 )
             })?
+            .with_optional_names(|name| attr_infos.values.contains_key(name))
             .names
-            .as_ref()
             .ok_or_else(|| {
                 err_msg!(
                     ProofRejected,
@@ -432,7 +644,7 @@ fn verify_revealed_attribute_values(
                 "Proof Revealed Attr Group does not match Proof Request Attribute Group",
             ));
         }
-        for attr_name in attr_names {
+        for attr_name in &attr_names {
             let attr_info = &attr_infos.values.get(attr_name).ok_or_else(|| {
                 err_msg!("Proof Revealed Attr Group does not match Proof Request Attribute Group",)
             })?;
@@ -506,6 +718,7 @@ fn verify_requested_restrictions(
     received_unrevealed_attrs: &HashMap<String, Identifier>,
     received_predicates: &HashMap<String, Identifier>,
     self_attested_attrs: &HashSet<String>,
+    legacy_namespace: Option<&str>,
 ) -> Result<()> {
     let proof_attr_identifiers: HashMap<String, Identifier> = received_revealed_attrs
         .iter()
@@ -513,25 +726,35 @@ fn verify_requested_restrictions(
         .map(|(r, id)| (r.to_string(), id.clone()))
         .collect();
 
+    // Omitted optional referents have nothing to check
     let requested_attrs: HashMap<String, AttributeInfo> = pres_req
         .requested_attributes
         .iter()
+        .filter(|&(referent, _)| {
+            proof_attr_identifiers.contains_key(referent) || self_attested_attrs.contains(referent)
+        })
         .filter(|&(referent, info)| !is_self_attested(referent, info, self_attested_attrs))
         .map(|(referent, info)| (referent.to_string(), info.clone()))
         .collect();
 
     let requested_attributes_queries = pres_req
         .requested_attributes
-        .iter()
-        .filter_map(|(_, info)| info.restrictions.clone());
+        .values()
+        .filter_map(|info| info.restrictions.clone());
 
     let requested_predicates_queries = pres_req
         .requested_predicates
-        .iter()
-        .filter_map(|(_, info)| info.restrictions.clone());
+        .values()
+        .filter_map(|info| info.restrictions.clone());
+
+    let requested_ranges_queries = pres_req
+        .requested_ranges
+        .values()
+        .filter_map(|info| info.restrictions.clone());
 
     let filter_tags: Vec<String> = requested_attributes_queries
         .chain(requested_predicates_queries)
+        .chain(requested_ranges_queries)
         .flat_map(|r| {
             r.get_name()
                 .iter()
@@ -558,6 +781,24 @@ fn verify_requested_restrictions(
         return Err(err_msg!("Presentation request contains both restrictions for `schema_issuer_id` (new) and `schema_issuer_did` (legacy)"));
     }
 
+    // Self-attested answers must follow the policy of their referent
+    for (referent, value) in &requested_proof.self_attested_attrs {
+        let policy = pres_req
+            .requested_attributes
+            .get(referent)
+            .and_then(|info| info.self_attested.as_ref());
+        if let Some(policy) = policy {
+            policy.check(value).map_err(|err| {
+                err_msg!(
+                    "Self-attested value validation failed for \"{}\" attribute",
+                    referent
+                )
+                .with_cause(err)
+                .with_context("referent", referent)
+            })?;
+        }
+    }
+
     for (referent, info) in &requested_attrs {
         if let Some(ref query) = info.restrictions {
             let filter = gather_filter_info(referent, &proof_attr_identifiers, schemas, cred_defs)?;
@@ -574,12 +815,18 @@ fn verify_requested_restrictions(
                         .map(|attr| attr.raw.as_str()),
                 );
                 map
-            } else if let Some(names) = info.names.as_ref() {
+            } else if info.names.is_some() {
                 let mut map = HashMap::new();
                 let attrs = requested_proof
                     .revealed_attr_groups
                     .get(referent)
                     .ok_or_else(|| err_msg!("Proof does not have referent from proof request"))?;
+                // Optional members left out of the presentation are treated as unrevealed
+                let names = info
+                    .names
+                    .iter()
+                    .chain(info.optional_names.iter())
+                    .flatten();
                 for name in names {
                     let val = attrs.values.get(name).map(|attr| attr.raw.as_str());
                     map.insert(name.clone(), val);
@@ -595,20 +842,36 @@ fn verify_requested_restrictions(
                 ));
             };
 
-            process_operator(&attr_value_map, query, &filter).map_err(err_map!(
-                "Requested restriction validation failed for \"{:?}\" attributes",
-                &attr_value_map
-            ))?;
+            process_operator(&attr_value_map, query, &filter, legacy_namespace).map_err(|err| {
+                err_msg!(
+                    "Requested restriction validation failed for \"{:?}\" attributes",
+                    &attr_value_map
+                )
+                .with_cause(err)
+                .with_context("referent", referent)
+            })?;
         }
     }
 
-    for (referent, info) in &pres_req.requested_predicates {
-        if let Some(ref query) = info.restrictions {
+    let requested_predicates = pres_req
+        .requested_predicates
+        .iter()
+        .map(|(referent, info)| (referent, &info.name, &info.restrictions))
+        .chain(
+            pres_req
+                .requested_ranges
+                .iter()
+                .map(|(referent, info)| (referent, &info.name, &info.restrictions)),
+        )
+        .filter(|(referent, _, _)| received_predicates.contains_key(*referent));
+
+    for (referent, name, restrictions) in requested_predicates {
+        if let Some(ref query) = restrictions {
             let filter = gather_filter_info(referent, received_predicates, schemas, cred_defs)?;
 
             // start with the predicate requested attribute, which is un-revealed
             let mut attr_value_map = HashMap::new();
-            attr_value_map.insert(info.name.to_string(), None);
+            attr_value_map.insert(name.to_string(), None);
 
             // include any revealed attributes for the same credential (based on sub_proof_index)
             let pred_sub_proof_index = requested_proof
@@ -640,10 +903,14 @@ fn verify_requested_restrictions(
                 }
             }
 
-            process_operator(&attr_value_map, query, &filter).map_err(err_map!(
-                "Requested restriction validation failed for \"{}\" predicate",
-                &info.name
-            ))?;
+            process_operator(&attr_value_map, query, &filter, legacy_namespace).map_err(|err| {
+                err_msg!(
+                    "Requested restriction validation failed for \"{}\" predicate",
+                    name
+                )
+                .with_cause(err)
+                .with_context("referent", referent)
+            })?;
         }
     }
 
@@ -676,18 +943,23 @@ fn gather_filter_info(
             "Identifier not found for referent: {}",
             referent
         )
+        .with_context("referent", referent)
     })?;
 
     let schema_id = &identifier.schema_id;
     let cred_def_id = &identifier.cred_def_id;
 
-    let schema = schemas
-        .get(schema_id)
-        .ok_or_else(|| err_msg!("schema_id {schema_id} could not be found in the schemas"))?;
+    let schema = schemas.get(schema_id).ok_or_else(|| {
+        err_msg!("schema_id {schema_id} could not be found in the schemas")
+            .with_context("referent", referent)
+            .with_context("schema_id", schema_id)
+    })?;
 
-    let cred_def = cred_defs
-        .get(cred_def_id)
-        .ok_or_else(|| err_msg!("cred_def_id {cred_def_id} could not be found in the cred_defs"))?;
+    let cred_def = cred_defs.get(cred_def_id).ok_or_else(|| {
+        err_msg!("cred_def_id {cred_def_id} could not be found in the cred_defs")
+            .with_context("referent", referent)
+            .with_context("cred_def_id", cred_def_id)
+    })?;
 
     Ok(Filter {
         schema_id: schema_id.clone(),
@@ -703,17 +975,31 @@ fn process_operator(
     attr_value_map: &HashMap<String, Option<&str>>,
     restriction_op: &Query,
     filter: &Filter,
+    legacy_namespace: Option<&str>,
 ) -> Result<()> {
     match restriction_op {
-        Query::Eq(ref tag_name, ref tag_value) => {
-            process_filter(attr_value_map, tag_name, tag_value, filter).map_err(err_map!(
-                "$eq operator validation failed for tag: \"{}\", value: \"{}\"",
-                tag_name,
-                tag_value
-            ))
-        }
+        Query::Eq(ref tag_name, ref tag_value) => process_filter(
+            attr_value_map,
+            tag_name,
+            tag_value,
+            filter,
+            legacy_namespace,
+        )
+        .map_err(err_map!(
+            "$eq operator validation failed for tag: \"{}\", value: \"{}\"",
+            tag_name,
+            tag_value
+        )),
         Query::Neq(ref tag_name, ref tag_value) => {
-            if process_filter(attr_value_map, tag_name, tag_value, filter).is_err() {
+            if process_filter(
+                attr_value_map,
+                tag_name,
+                tag_value,
+                filter,
+                legacy_namespace,
+            )
+            .is_err()
+            {
                 Ok(())
             } else {
                 Err(err_msg!(ProofRejected,
@@ -721,9 +1007,9 @@ fn process_operator(
             }
         }
         Query::In(ref tag_name, ref tag_values) => {
-            let res = tag_values
-                .iter()
-                .any(|val| process_filter(attr_value_map, tag_name, val, filter).is_ok());
+            let res = tag_values.iter().any(|val| {
+                process_filter(attr_value_map, tag_name, val, filter, legacy_namespace).is_ok()
+            });
             if res {
                 Ok(())
             } else {
@@ -737,14 +1023,14 @@ fn process_operator(
         }
         Query::And(ref operators) => operators
             .iter()
-            .map(|op| process_operator(attr_value_map, op, filter))
+            .map(|op| process_operator(attr_value_map, op, filter, legacy_namespace))
             .collect::<Result<Vec<()>>>()
             .map(|_| ())
             .map_err(err_map!("$and operator validation failed.")),
         Query::Or(ref operators) => {
             let res = operators
                 .iter()
-                .any(|op| process_operator(attr_value_map, op, filter).is_ok());
+                .any(|op| process_operator(attr_value_map, op, filter, legacy_namespace).is_ok());
             if res {
                 Ok(())
             } else {
@@ -755,7 +1041,7 @@ fn process_operator(
             }
         }
         Query::Not(ref operator) => {
-            if process_operator(attr_value_map, operator, filter).is_err() {
+            if process_operator(attr_value_map, operator, filter, legacy_namespace).is_err() {
                 Ok(())
             } else {
                 Err(err_msg!(
@@ -773,6 +1059,7 @@ fn process_filter(
     tag: &str,
     tag_value: &str,
     filter: &Filter,
+    legacy_namespace: Option<&str>,
 ) -> Result<()> {
     trace!(
         "_process_filter: attr_value_map: {:?}, tag: {}, tag_value: {}, filter: {:?}",
@@ -782,15 +1069,32 @@ fn process_filter(
         filter
     );
     match tag {
-        tag_ @ "schema_id" => precess_filed(tag_, filter.schema_id.to_string(), tag_value),
-        tag_ @ ("schema_issuer_did" | "schema_issuer_id") => {
-            precess_filed(tag_, filter.schema_issuer_id.clone(), tag_value)
+        tag_ @ "schema_id" => precess_filed(
+            tag_,
+            filter.schema_id.to_string(),
+            tag_value,
+            legacy_namespace,
+        ),
+        tag_ @ ("schema_issuer_did" | "schema_issuer_id") => precess_filed(
+            tag_,
+            filter.schema_issuer_id.clone(),
+            tag_value,
+            legacy_namespace,
+        ),
+        tag_ @ "schema_name" => {
+            precess_filed(tag_, &filter.schema_name, tag_value, legacy_namespace)
         }
-        tag_ @ "schema_name" => precess_filed(tag_, &filter.schema_name, tag_value),
-        tag_ @ "schema_version" => precess_filed(tag_, &filter.schema_version, tag_value),
-        tag_ @ "cred_def_id" => precess_filed(tag_, filter.cred_def_id.to_string(), tag_value),
+        tag_ @ "schema_version" => {
+            precess_filed(tag_, &filter.schema_version, tag_value, legacy_namespace)
+        }
+        tag_ @ "cred_def_id" => precess_filed(
+            tag_,
+            filter.cred_def_id.to_string(),
+            tag_value,
+            legacy_namespace,
+        ),
         tag_ @ ("issuer_did" | "issuer_id") => {
-            precess_filed(tag_, filter.issuer_id.clone(), tag_value)
+            precess_filed(tag_, filter.issuer_id.clone(), tag_value, legacy_namespace)
         }
         key if is_attr_internal_tag(key, attr_value_map) => {
             check_internal_tag_revealed_value(key, tag_value, attr_value_map)
@@ -800,8 +1104,14 @@ fn process_filter(
     }
 }
 
-fn precess_filed(filed: &str, filter_value: impl Into<String>, tag_value: &str) -> Result<()> {
-    let filter_value = filter_value.into();
+fn precess_filed(
+    filed: &str,
+    filter_value: impl Into<String>,
+    tag_value: &str,
+    legacy_namespace: Option<&str>,
+) -> Result<()> {
+    let filter_value = to_legacy_identifier(filed, filter_value.into(), legacy_namespace);
+    let tag_value = to_legacy_identifier(filed, tag_value.to_owned(), legacy_namespace);
     // We explicitly check here with it is one of the two legacy identifier restrictions. This
     // means that we only allow legacy identifiers which can be detected with a simple regex. If
     // they are not in the legacy format, we do not support this.
@@ -827,6 +1137,33 @@ fn precess_filed(filed: &str, filter_value: impl Into<String>, tag_value: &str)
     }
 }
 
+/// Convert a `did:indy` identifier in the legacy identifier namespace to its legacy form, so that
+/// both forms of an identifier compare equal. Other values are returned as is.
+fn to_legacy_identifier(filed: &str, value: String, legacy_namespace: Option<&str>) -> String {
+    let namespace = match legacy_namespace {
+        Some(namespace) => namespace,
+        None => return value,
+    };
+    if did_indy::parse(&value).map_or(true, |parsed| parsed.namespace != namespace) {
+        return value;
+    }
+    let legacy = match filed {
+        "schema_id" => SchemaId::new_unchecked(value.as_str())
+            .to_legacy()
+            .map(|id| id.0),
+        "cred_def_id" => CredentialDefinitionId::new_unchecked(value.as_str())
+            .to_legacy()
+            .map(|id| id.0),
+        "schema_issuer_did" | "schema_issuer_id" | "issuer_did" | "issuer_id" => {
+            IssuerId::new_unchecked(value.as_str())
+                .to_legacy()
+                .map(|id| id.0)
+        }
+        _ => return value,
+    };
+    legacy.unwrap_or(value)
+}
+
 fn is_attr_internal_tag(key: &str, attr_value_map: &HashMap<String, Option<&str>>) -> bool {
     INTERNAL_TAG_MATCHER.captures(key).map_or(false, |caps| {
         caps.get(1).map_or(false, |s| {
@@ -930,7 +1267,7 @@ mod tests {
     ) -> Result<()> {
         let mut attr_value_map = HashMap::new();
         attr_value_map.insert(attr.to_string(), revealed_value);
-        process_operator(&attr_value_map, restriction_op, filter)
+        process_operator(&attr_value_map, restriction_op, filter, None)
     }
 
     #[test]
@@ -956,6 +1293,41 @@ mod tests {
         assert!(_process_operator("zip", &op, &filter, None).is_err());
     }
 
+    #[test]
+    fn test_process_op_eq_did_indy_namespace() {
+        let filter = Filter {
+            schema_id: SchemaId::new_unchecked(
+                "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/gvt/1.0",
+            ),
+            schema_issuer_id: IssuerId::new_unchecked("did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1"),
+            cred_def_id: CredentialDefinitionId::new_unchecked(
+                "DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default",
+            ),
+            issuer_id: IssuerId::new_unchecked("DXoTtQJNtXtiwWaZAK3rB1"),
+            ..filter()
+        };
+        let op = Query::And(vec![
+            Query::Eq(
+                schema_id_tag(),
+                "DXoTtQJNtXtiwWaZAK3rB1:2:gvt:1.0".to_string(),
+            ),
+            Query::Eq(
+                schema_issuer_did_tag(),
+                "DXoTtQJNtXtiwWaZAK3rB1".to_string(),
+            ),
+            Query::Eq(
+                cred_def_id_tag(),
+                "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/CLAIM_DEF/98153/default"
+                    .to_string(),
+            ),
+        ]);
+
+        let attr_value_map = HashMap::from([("zip".to_owned(), None)]);
+        process_operator(&attr_value_map, &op, &filter, Some("sovrin")).unwrap();
+        assert!(process_operator(&attr_value_map, &op, &filter, Some("indicio")).is_err());
+        assert!(process_operator(&attr_value_map, &op, &filter, None).is_err());
+    }
+
     #[test]
     fn test_process_op_ne() {
         let filter = filter();
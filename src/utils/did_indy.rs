@@ -0,0 +1,143 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::error::ValidationError;
+use crate::invalid;
+
+/// `did:indy:<namespace>:<did><path>` as defined in the [Indy DID Method
+/// specification](https://hyperledger.github.io/indy-did-method/). A namespace consists of one or
+/// two colon separated parts, e.g. `sovrin` or `sovrin:staging`.
+pub static DID_INDY_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("^did:indy:(?P<namespace>[a-z0-9_-]+(:[a-z0-9_-]+)?):(?P<did>[1-9A-HJ-NP-Za-km-z]{21,22})(?P<path>/.*)?$").unwrap()
+});
+
+pub static DID_INDY_NAMESPACE: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^[a-z0-9_-]+(:[a-z0-9_-]+)?$").unwrap());
+
+const ANONCREDS_PATH: &str = "/anoncreds/v0/";
+
+pub const SCHEMA_OBJECT: &str = "SCHEMA";
+pub const CRED_DEF_OBJECT: &str = "CLAIM_DEF";
+pub const REV_REG_DEF_OBJECT: &str = "REV_REG_DEF";
+
+/// Parts of a `did:indy` identifier
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DidIndy<'a> {
+    pub namespace: &'a str,
+    pub did: &'a str,
+    /// Object type and path segments of an AnonCreds object identifier
+    pub object: Option<(&'a str, Vec<&'a str>)>,
+}
+
+pub fn parse(id: &str) -> Result<DidIndy<'_>, ValidationError> {
+    let caps = DID_INDY_IDENTIFIER
+        .captures(id)
+        .ok_or_else(|| invalid!("{} is not a did:indy identifier", id))?;
+    let object = match caps.name("path") {
+        Some(path) => {
+            let mut segments = path
+                .as_str()
+                .strip_prefix(ANONCREDS_PATH)
+                .ok_or_else(|| invalid!("{} is not an AnonCreds object identifier", id))?
+                .split('/');
+            let object_type = segments.next().unwrap_or_default();
+            Some((object_type, segments.collect()))
+        }
+        None => None,
+    };
+    Ok(DidIndy {
+        namespace: caps.name("namespace").map_or("", |ns| ns.as_str()),
+        did: caps.name("did").map_or("", |did| did.as_str()),
+        object,
+    })
+}
+
+/// Parse a `did:indy` identifier of the given object type with `count` path segments
+pub fn parse_object<'a>(
+    id: &'a str,
+    object_type: &str,
+    count: usize,
+) -> Result<DidIndy<'a>, ValidationError> {
+    let parsed = parse(id)?;
+    match &parsed.object {
+        Some((found, segments)) if *found == object_type && segments.len() == count => Ok(parsed),
+        _ => Err(invalid!(
+            "{} is not a did:indy {} identifier",
+            id,
+            object_type
+        )),
+    }
+}
+
+pub fn format(
+    namespace: &str,
+    did: &str,
+    object: Option<(&str, &[&str])>,
+) -> Result<String, ValidationError> {
+    if DID_INDY_NAMESPACE.captures(namespace).is_none() {
+        return Err(invalid!("Invalid did:indy namespace {}", namespace));
+    }
+    let mut id = format!("did:indy:{namespace}:{did}");
+    if let Some((object_type, segments)) = object {
+        if let Some(segment) = segments.iter().find(|s| s.is_empty() || s.contains('/')) {
+            return Err(invalid!(
+                "{:?} cannot be used as a did:indy path segment",
+                segment
+            ));
+        }
+        id.push_str(ANONCREDS_PATH);
+        id.push_str(object_type);
+        for segment in segments {
+            id.push('/');
+            id.push_str(segment);
+        }
+    }
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_did_indy_identifiers() {
+        let parsed = parse("did:indy:sovrin:staging:DXoTtQJNtXtiwWaZAK3rB1").unwrap();
+        assert_eq!(parsed.namespace, "sovrin:staging");
+        assert_eq!(parsed.did, "DXoTtQJNtXtiwWaZAK3rB1");
+        assert_eq!(parsed.object, None);
+
+        let parsed =
+            parse("did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/gvt/1.0").unwrap();
+        assert_eq!(parsed.namespace, "sovrin");
+        assert_eq!(parsed.object, Some((SCHEMA_OBJECT, vec!["gvt", "1.0"])));
+
+        assert!(parse("did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/other").is_err());
+        assert!(parse("did:web:example.com").is_err());
+        assert!(parse_object(
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/gvt/1.0",
+            CRED_DEF_OBJECT,
+            2
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_format_did_indy_identifiers() {
+        assert_eq!(
+            format(
+                "sovrin",
+                "DXoTtQJNtXtiwWaZAK3rB1",
+                Some((CRED_DEF_OBJECT, &["98153", "default"]))
+            )
+            .unwrap(),
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/CLAIM_DEF/98153/default"
+        );
+        assert!(format("Sovrin", "DXoTtQJNtXtiwWaZAK3rB1", None).is_err());
+        assert!(format(
+            "sovrin",
+            "DXoTtQJNtXtiwWaZAK3rB1",
+            Some((SCHEMA_OBJECT, &["a/b", "1.0"]))
+        )
+        .is_err());
+    }
+}
@@ -37,6 +37,7 @@ macro_rules! hash_type {
 hash_type!(SHA256, sha2::Sha256, "Sha256 hash");
 
 /// A trait for producing hashes of merkle tree leaves and nodes
+#[allow(dead_code)]
 pub trait TreeHash {
     fn hash_leaf<T>(leaf: &T) -> Result<Vec<u8>, ValidationError>
     where
@@ -90,6 +91,7 @@ impl<H: Digest> TreeHash for H {
 ///     }
 /// }
 /// ```
+#[allow(dead_code)]
 pub trait Hashable {
     /// Update the given `context` with `self`.
     ///
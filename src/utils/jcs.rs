@@ -0,0 +1,175 @@
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+use crate::error::ConversionError;
+
+/// Largest integer which is exactly representable as an IEEE 754 double
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Serialize a value following the JSON Canonicalization Scheme
+/// ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785))
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, ConversionError> {
+    let value = serde_json::to_value(value)?;
+    let mut out = Vec::new();
+    write_value(&value, &mut out)?;
+    Ok(out)
+}
+
+fn write_value(value: &Value, out: &mut Vec<u8>) -> Result<(), ConversionError> {
+    match value {
+        Value::Null => out.extend_from_slice(b"null"),
+        Value::Bool(b) => out.extend_from_slice(if *b { b"true" } else { b"false" }),
+        Value::Number(n) => out.extend_from_slice(format_number(n)?.as_bytes()),
+        // serde_json escapes strings the same way as ECMAScript `JSON.stringify`
+        Value::String(s) => out.extend_from_slice(serde_json::to_string(s)?.as_bytes()),
+        Value::Array(items) => {
+            out.push(b'[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    out.push(b',');
+                }
+                write_value(item, out)?;
+            }
+            out.push(b']');
+        }
+        Value::Object(map) => {
+            // Properties are sorted by their UTF-16 code units
+            let mut entries = map
+                .iter()
+                .map(|(key, value)| (key.encode_utf16().collect::<Vec<u16>>(), key, value))
+                .collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            out.push(b'{');
+            for (idx, (_, key, value)) in entries.into_iter().enumerate() {
+                if idx > 0 {
+                    out.push(b',');
+                }
+                out.extend_from_slice(serde_json::to_string(key)?.as_bytes());
+                out.push(b':');
+                write_value(value, out)?;
+            }
+            out.push(b'}');
+        }
+    }
+    Ok(())
+}
+
+fn format_number(n: &Number) -> Result<String, ConversionError> {
+    if let Some(u) = n.as_u64() {
+        if u > MAX_SAFE_INTEGER {
+            return Err(format!("Integer {u} cannot be represented in canonical JSON").into());
+        }
+        return Ok(u.to_string());
+    }
+    if let Some(i) = n.as_i64() {
+        if i.unsigned_abs() > MAX_SAFE_INTEGER {
+            return Err(format!("Integer {i} cannot be represented in canonical JSON").into());
+        }
+        return Ok(i.to_string());
+    }
+    let f = n
+        .as_f64()
+        .ok_or_else(|| ConversionError::from_msg("Invalid number"))?;
+    format_f64(f)
+}
+
+/// Format a double as ECMAScript `Number.prototype.toString` does
+fn format_f64(f: f64) -> Result<String, ConversionError> {
+    if !f.is_finite() {
+        return Err("Non-finite numbers cannot be represented in JSON".into());
+    }
+    if f == 0.0 {
+        return Ok("0".to_owned());
+    }
+    // Shortest round-trip representation as `d.ddde[-]x`
+    let sci = format!("{:e}", f.abs());
+    let (mantissa, exponent) = sci
+        .split_once('e')
+        .ok_or_else(|| ConversionError::from_msg("Invalid number"))?;
+    let digits = mantissa.replace('.', "");
+    let exponent: i32 = exponent
+        .parse()
+        .map_err(|_| ConversionError::from_msg("Invalid number"))?;
+    let k = digits.len() as i32;
+    let n = exponent + 1;
+
+    let mut s = String::new();
+    if f < 0.0 {
+        s.push('-');
+    }
+    if k <= n && n <= 21 {
+        s.push_str(&digits);
+        s.push_str(&"0".repeat((n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        s.push_str(&digits[..n as usize]);
+        s.push('.');
+        s.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        s.push_str("0.");
+        s.push_str(&"0".repeat((-n) as usize));
+        s.push_str(&digits);
+    } else {
+        s.push_str(&digits[..1]);
+        if k > 1 {
+            s.push('.');
+            s.push_str(&digits[1..]);
+        }
+        s.push('e');
+        s.push(if n > 0 { '+' } else { '-' });
+        s.push_str(&(n - 1).abs().to_string());
+    }
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_structure() {
+        // Example of RFC 8785, section 3.2.2, except for 333333333.33333329 which serde_json does
+        // not parse to the closest double
+        let value: Value = serde_json::from_str(
+            r#"{
+                "numbers": [333333333.3333333, 1E30, 4.50, 2e-3, 0.000000000000000000000000001, -0.0, 100, -7],
+                "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+                "literals": [null, true, false],
+                "\u20ac": "Euro Sign",
+                "\ud83d\ude00": "Emoji: Grinning Face",
+                "\ufb33": "Hebrew Letter Dalet With Dagesh",
+                "1": "One"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(to_vec(&value).unwrap()).unwrap(),
+            concat!(
+                r#"{"1":"One","literals":[null,true,false],"#,
+                r#""numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27,0,100,-7],"#,
+                r#""string":"€$\u000f\nA'B\"\\\\\"/","#,
+                "\"\u{20ac}\":\"Euro Sign\",\"\u{1F600}\":\"Emoji: Grinning Face\",",
+                "\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_numbers() {
+        assert_eq!(
+            format_f64(333_333_333.333_333_3).unwrap(),
+            "333333333.3333333"
+        );
+        assert_eq!(format_f64(1e21).unwrap(), "1e+21");
+        assert_eq!(format_f64(1e20).unwrap(), "100000000000000000000");
+        assert_eq!(format_f64(-1.5e-7).unwrap(), "-1.5e-7");
+        assert_eq!(format_f64(0.000001).unwrap(), "0.000001");
+        assert!(format_f64(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_reject_unsafe_integers() {
+        assert!(to_vec(&json!({ "n": MAX_SAFE_INTEGER })).is_ok());
+        assert!(to_vec(&json!({ "n": MAX_SAFE_INTEGER + 1 })).is_err());
+        assert!(to_vec(&json!({ "n": i64::MIN })).is_err());
+    }
+}
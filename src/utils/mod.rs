@@ -3,8 +3,14 @@ pub mod validation;
 
 pub mod base58;
 
+/// Conversion between legacy identifiers and `did:indy` identifiers
+pub mod did_indy;
+
 pub mod hash;
 
+/// JSON Canonicalization Scheme
+pub mod jcs;
+
 pub mod query;
 
 #[macro_use]
@@ -1,7 +1,7 @@
 use serde::ser::{Serialize, Serializer};
 use serde::{de, Deserialize, Deserializer};
 use serde_json::{self, json, Value as JsonValue};
-use std::string;
+use std::fmt;
 
 /// An abstract query representation over a key and value type
 #[derive(Debug, Hash, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -280,9 +280,9 @@ where
     }
 }
 
-impl string::ToString for Query {
-    fn to_string(&self) -> String {
-        self.to_value().to_string()
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_value())
     }
 }
 
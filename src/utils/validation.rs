@@ -1,6 +1,8 @@
 use crate::error::ValidationError;
+use crate::invalid;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::collections::HashSet;
 
 // TODO: stricten the URI regex.
 // Right now everything after the first colon is allowed,
@@ -8,6 +10,35 @@ use regex::Regex;
 pub static URI_IDENTIFIER: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[a-zA-Z0-9\+\-\.]+:.+$").unwrap());
 
+/// `pchar` of [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-3.3)
+const PCHAR: &str = r"(?:[A-Za-z0-9\-._~!$&'()*+,;=:@]|%[0-9A-Fa-f]{2})";
+
+/// URI as defined in [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-3). An
+/// empty hierarchical part is not accepted.
+///
+/// Identifiers are only checked against this when validated with `validate_strict`, as for
+/// instance `did:indy` identifiers of schemas with spaces in their name do not match it.
+pub static STRICT_URI_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    let authority = r"(?:(?:[A-Za-z0-9\-._~!$&'()*+,;=:]|%[0-9A-Fa-f]{2})*@)?(?:\[[0-9A-Fa-f:.]+\]|(?:[A-Za-z0-9\-._~!$&'()*+,;=]|%[0-9A-Fa-f]{2})*)(?::[0-9]*)?";
+    Regex::new(&format!(
+        r"^(?P<scheme>[a-zA-Z][a-zA-Z0-9+\-.]*):(?P<hier_part>//{authority}(?:/{PCHAR}*)*|/?{PCHAR}+(?:/{PCHAR}*)*|/)(?:\?(?P<query>(?:{PCHAR}|[/?])*))?(?:#(?P<fragment>(?:{PCHAR}|[/?])*))?$"
+    ))
+    .unwrap()
+});
+
+/// DID URL as defined in the [DID Core
+/// specification](https://www.w3.org/TR/did-core/#did-syntax), i.e. a DID optionally followed by
+/// a path, query and fragment.
+pub static DID_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    let idchar = r"(?:[A-Za-z0-9._\-]|%[0-9A-Fa-f]{2})";
+    Regex::new(&format!(
+        r"^did:(?P<method>[a-z0-9]+):(?P<method_specific_id>(?:{idchar}*:)*{idchar}+)(?P<path>(?:/{PCHAR}*)*)(?:\?(?P<query>(?:{PCHAR}|[/?])*))?(?:#(?P<fragment>(?:{PCHAR}|[/?])*))?$"
+    ))
+    .unwrap()
+});
+
+static DID_METHOD_NAME: Lazy<Regex> = Lazy::new(|| Regex::new("^[a-z0-9]+$").unwrap());
+
 /// base58 alpahet as defined in the [base58
 /// specification](https://datatracker.ietf.org/doc/html/draft-msporny-base58#section-2) This is
 /// used for legacy indy identifiers that we will keep supporting for backwards compatibility. This
@@ -16,17 +47,87 @@ pub static URI_IDENTIFIER: Lazy<Regex> =
 pub static LEGACY_DID_IDENTIFIER: Lazy<Regex> =
     Lazy::new(|| Regex::new("^[1-9A-HJ-NP-Za-km-z]{21,22}$").unwrap());
 
-pub static LEGACY_SCHEMA_IDENTIFIER: Lazy<Regex> =
-    Lazy::new(|| Regex::new("^[1-9A-HJ-NP-Za-km-z]{21,22}:2:.+:[0-9.]+$").unwrap());
+/// Legacy schema identifier `<did>:2:<name>:<version>`
+pub static LEGACY_SCHEMA_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("^(?P<did>[1-9A-HJ-NP-Za-km-z]{21,22}):2:(?P<name>.+):(?P<version>[0-9.]+)$")
+        .unwrap()
+});
 
+/// Legacy credential definition identifier `<did>:3:CL:<schema seq_no or schema id>:<tag>`
 pub static LEGACY_CRED_DEF_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
-    Regex::new("^[1-9A-HJ-NP-Za-km-z]{21,22}:3:CL:(([1-9][0-9]*)|([a-zA-Z0-9]{21,22}:2:.+:[0-9.]+)):(.+)?$").unwrap()
+    Regex::new("^(?P<did>[1-9A-HJ-NP-Za-km-z]{21,22}):3:(?P<signature_type>CL):(?P<schema_ref>(?P<seq_no>[1-9][0-9]*)|(?P<schema_id>[a-zA-Z0-9]{21,22}:2:.+:[0-9.]+)):(?P<tag>.+)?$").unwrap()
+});
+
+/// Legacy revocation registry definition identifier `<did>:4:<cred def id>:CL_ACCUM:<tag>`
+pub static LEGACY_REV_REG_DEF_IDENTIFIER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("^(?P<did>[1-9A-HJ-NP-Za-km-z]{21,22}):4:(?P<cred_def_id>[1-9A-HJ-NP-Za-km-z]{21,22}:3:CL:(([1-9][0-9]*)|([a-zA-Z0-9]{21,22}:2:.+:[0-9.]+)):(.+)?):(?P<registry_type>CL_ACCUM):(?P<tag>.+)$").unwrap()
 });
 
 pub fn is_uri_identifier(id: &str) -> bool {
     URI_IDENTIFIER.captures(id).is_some()
 }
 
+pub fn is_strict_uri_identifier(id: &str) -> bool {
+    STRICT_URI_IDENTIFIER.captures(id).is_some()
+}
+
+pub fn is_did_identifier(id: &str) -> bool {
+    DID_IDENTIFIER.captures(id).is_some()
+}
+
+/// DID methods accepted by strict identifier validation
+///
+/// An empty set accepts DIDs of any method.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DidMethods(HashSet<String>);
+
+impl DidMethods {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a DID method accepted for DID identifiers
+    pub fn register(&mut self, method: &str) -> Result<(), ValidationError> {
+        if DID_METHOD_NAME.captures(method).is_none() {
+            return Err(invalid!("Invalid DID method name {}", method));
+        }
+        self.0.insert(method.to_owned());
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, method: &str) -> bool {
+        self.0.contains(method)
+    }
+}
+
+impl TryFrom<&[&str]> for DidMethods {
+    type Error = ValidationError;
+
+    fn try_from(methods: &[&str]) -> Result<Self, Self::Error> {
+        let mut did_methods = Self::new();
+        for method in methods {
+            did_methods.register(method)?;
+        }
+        Ok(did_methods)
+    }
+}
+
+/// Validate the DID syntax of an identifier and, unless `methods` is empty, its method
+pub fn validate_did(id: &str, methods: &DidMethods) -> Result<(), ValidationError> {
+    let caps = DID_IDENTIFIER
+        .captures(id)
+        .ok_or_else(|| invalid!("{} is not a valid DID", id))?;
+    let method = caps.name("method").map_or("", |m| m.as_str());
+    if !methods.is_empty() && !methods.contains(method) {
+        return Err(invalid!("DID method {} of {} is not allowed", method, id));
+    }
+    Ok(())
+}
+
 /// Macro to return a new `ValidationError` with an optional message
 #[macro_export]
 macro_rules! invalid {
@@ -53,9 +154,14 @@ mod test_identifiers {
         let valid_uri_identifier = "mock:uri";
         let valid_legacy_schema_identifier = "DXoTtQJNtXtiwWaZAK3rB1:2:example:1.0";
         let valid_legacy_cred_def_identifier = "DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default";
+        let valid_legacy_rev_reg_def_identifier =
+            "DXoTtQJNtXtiwWaZAK3rB1:4:DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default:CL_ACCUM:tag";
         let valid_legacy_did_identifier = "DXoTtQJNtXtiwWaZAK3rB1";
 
         assert!(URI_IDENTIFIER.captures(valid_uri_identifier).is_some());
+        assert!(LEGACY_REV_REG_DEF_IDENTIFIER
+            .captures(valid_legacy_rev_reg_def_identifier)
+            .is_some());
         assert!(LEGACY_SCHEMA_IDENTIFIER
             .captures(valid_legacy_schema_identifier)
             .is_some());
@@ -67,6 +173,75 @@ mod test_identifiers {
             .is_some());
     }
 
+    #[test]
+    fn should_validate_uri_syntax() {
+        for valid in [
+            "mock:uri",
+            "did:web:example.com#controller",
+            "https://user@example.com:8080/path/to%20schema?version=1.0#tag",
+            "urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            "http://[::1]/schema",
+            "did:web:xyz/resource/schema",
+        ] {
+            assert!(is_strict_uri_identifier(valid), "{valid}");
+        }
+        for invalid in [
+            "mock:",
+            "1mock:uri",
+            "mock:uri with spaces",
+            "mock:uri%zz",
+            "mock:uri#frag#ment",
+            "mock:<uri>",
+            "http://exa mple.com",
+        ] {
+            assert!(!is_strict_uri_identifier(invalid), "{invalid}");
+        }
+
+        assert!(is_uri_identifier("mock:uri with spaces"));
+        assert!(is_uri_identifier(
+            "did:indy:sovrin:DXoTtQJNtXtiwWaZAK3rB1/anoncreds/v0/SCHEMA/my schema/1.0"
+        ));
+        assert!(!is_uri_identifier("mock:"));
+    }
+
+    #[test]
+    fn should_validate_did_syntax() {
+        for valid in [
+            "did:web:example.com",
+            "did:web:example.com#controller",
+            "did:web:xyz/resource/schema",
+            "did:indy:sovrin:staging:6cgbu8ZPoWTnR5Rv5JcSMB",
+            "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK",
+            "did:example:abc%3Adef?service=files",
+        ] {
+            assert!(is_did_identifier(valid), "{valid}");
+        }
+        for invalid in [
+            "did:example",
+            "did:Web:example.com",
+            "did:web:",
+            "did:web:example.com:",
+            "did:web:exa$mple.com",
+            "mock:uri",
+        ] {
+            assert!(!is_did_identifier(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn should_check_registered_did_methods() {
+        let methods = DidMethods::try_from(&["indy"][..]).unwrap();
+        assert!(validate_did("did:indy:sovrin:abc", &methods).is_ok());
+        assert!(validate_did("did:web:example.com", &methods).is_err());
+        assert!(validate_did("did:web:example.com", &DidMethods::new()).is_ok());
+        assert!(validate_did("did:example", &DidMethods::new()).is_err());
+
+        let mut methods = DidMethods::new();
+        assert!(methods.register("Web").is_err());
+        assert!(methods.register("").is_err());
+        assert!(methods.is_empty());
+    }
+
     #[test]
     fn should_not_validate_invalid_identifiers() {
         let invalid_uri_identifier = "DXoTtQJNtXtiwWaZAK3rB1";
@@ -91,5 +266,11 @@ mod test_identifiers {
         assert!(LEGACY_CRED_DEF_IDENTIFIER
             .captures("DXoTtQJNtXtiwWaZAK3rB1:4:CL:98153:default")
             .is_none());
+        assert!(LEGACY_REV_REG_DEF_IDENTIFIER
+            .captures("DXoTtQJNtXtiwWaZAK3rB1")
+            .is_none());
+        assert!(LEGACY_REV_REG_DEF_IDENTIFIER
+            .captures("DXoTtQJNtXtiwWaZAK3rB1:4:DXoTtQJNtXtiwWaZAK3rB1:3:CL:98153:default:CL:tag")
+            .is_none());
     }
 }
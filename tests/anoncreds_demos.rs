@@ -1,11 +1,13 @@
-use anoncreds::data_types::cred_def::CredentialDefinitionId;
+use anoncreds::data_types::cred_def::{CredentialDefinitionId, RotationReason, SupersededPolicy};
 use anoncreds::data_types::rev_reg_def::RevocationRegistryDefinitionId;
 use anoncreds::data_types::schema::SchemaId;
 use anoncreds::issuer;
+use anoncreds::nonce_registry::{InMemoryNonceRegistry, NonceRegistry};
 use anoncreds::prover;
 use anoncreds::tails::TailsFileWriter;
 use anoncreds::types::{CredentialRevocationConfig, PresentCredentials};
 use anoncreds::verifier;
+use anoncreds::ErrorKind;
 use serde_json::json;
 use std::collections::{BTreeSet, HashMap};
 
@@ -46,6 +48,7 @@ fn anoncreds_demo_works_for_single_issuer_single_prover() {
     // Issuer creates a credential
     let cred_values = fixtures::credential_values("GVT");
     let issue_cred = issuer::create_credential(
+        &gvt_schema,
         &gvt_cred_def,
         &gvt_cred_def_priv,
         &cred_offer,
@@ -65,6 +68,21 @@ fn anoncreds_demo_works_for_single_issuer_single_prover() {
         None,
     )
     .expect("Error processing credential");
+
+    // Prover reads the credential attributes for display
+    assert_eq!(
+        recv_cred.attribute_names(),
+        vec!["age", "height", "name", "sex"]
+    );
+    assert_eq!(recv_cred.attribute("name").unwrap().raw, "Alex");
+    assert_eq!(recv_cred.attribute("age").unwrap().encoded, "28");
+    assert!(recv_cred.attribute("phone").is_none());
+    let summary = recv_cred.summary();
+    assert_eq!(summary.attrs["sex"], "male");
+    assert!(serde_json::to_value(&summary)
+        .unwrap()
+        .get("signature")
+        .is_none());
     prover_wallet.credentials.push(recv_cred);
 
     // Verifier creates a presentation request
@@ -128,6 +146,19 @@ fn anoncreds_demo_works_for_single_issuer_single_prover() {
     )
     .expect("Error creating presentation");
 
+    // Credential and presentation round-trip through the binary encoding
+    #[cfg(feature = "cbor")]
+    {
+        use anoncreds::data_types::cbor::{from_cbor, to_cbor};
+        use anoncreds::types::{Credential, Presentation};
+
+        let credential = &prover_wallet.credentials[0];
+        let decoded: Credential = from_cbor(&to_cbor(credential).unwrap()).unwrap();
+        assert_eq!(json!(decoded), json!(credential));
+        let decoded: Presentation = from_cbor(&to_cbor(&presentation).unwrap()).unwrap();
+        assert_eq!(json!(decoded), json!(presentation));
+    }
+
     // Verifier verifies presentation
     assert_eq!(
         "Alex",
@@ -183,6 +214,217 @@ fn anoncreds_demo_works_for_single_issuer_single_prover() {
     .expect("Error verifying presentation");
 
     assert!(valid);
+
+    // Legacy identifier namespaces are validated before verifying
+    let valid = verifier::verify_presentation_with_options(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+        verifier::VerificationOptions {
+            legacy_identifier_namespace: Some("sovrin".to_owned()),
+            ..verifier::VerificationOptions::default()
+        },
+    )
+    .expect("Error verifying presentation");
+    assert!(valid);
+    verifier::verify_presentation_with_options(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+        verifier::VerificationOptions {
+            legacy_identifier_namespace: Some("Sovrin".to_owned()),
+            ..verifier::VerificationOptions::default()
+        },
+    )
+    .expect_err("Expected an invalid namespace to be rejected");
+
+    // Verifier tracking request nonces only accepts the presentation once
+    let mut nonce_registry = InMemoryNonceRegistry::new(300);
+    nonce_registry
+        .register(&pres_request.value().nonce, 1000)
+        .expect("Error registering nonce");
+    for expected in [Ok(true), Err(ErrorKind::ProofRejected)] {
+        let mut report = verifier::VerificationReport::default();
+        let valid = verifier::verify_presentation_with_options(
+            &presentation,
+            &pres_request,
+            &schemas,
+            &cred_defs,
+            None,
+            None,
+            None,
+            verifier::VerificationOptions {
+                legacy_identifier_namespace: Some("sovrin".to_owned()),
+                now: Some(1100),
+                nonce_registry: Some(&mut nonce_registry),
+                report: Some(&mut report),
+            },
+        )
+        .map_err(|e| e.kind());
+        assert_eq!(valid, expected);
+        assert_eq!(report.valid, valid.is_ok());
+    }
+
+    // Issuer rotates the credential definition after a key compromise
+    let (_, _, _, record) = issuer::rotate_credential_definition(
+        fixtures::GVT_CRED_DEF_ID.try_into().unwrap(),
+        &gvt_cred_def,
+        &gvt_schema,
+        RotationReason::KeyCompromise,
+        None,
+    )
+    .expect("Error rotating credential definition");
+    let supersedes = vec![record];
+
+    // Verifier decides by policy whether the presentation is still acceptable
+    assert!(verifier::verify_superseded_credential_definitions(
+        &presentation,
+        &supersedes,
+        SupersededPolicy::Accept,
+    ));
+    assert!(!verifier::verify_superseded_credential_definitions(
+        &presentation,
+        &supersedes,
+        SupersededPolicy::RejectCompromised,
+    ));
+}
+
+#[test]
+fn anoncreds_demo_works_for_range_predicate() {
+    // Create Prover pseudo wallet and link secret
+    let mut prover_wallet = ProverWallet::default();
+
+    // Create schema and credential definition
+    let (gvt_schema, gvt_schema_id) = fixtures::create_schema("GVT");
+    let ((gvt_cred_def, gvt_cred_def_priv, gvt_cred_key_correctness_proof), gvt_cred_def_id) =
+        fixtures::create_cred_def(&gvt_schema, false);
+
+    // Issuer issues a credential to the prover
+    let cred_offer = issuer::create_credential_offer(
+        gvt_schema_id.try_into().unwrap(),
+        gvt_cred_def_id.try_into().unwrap(),
+        &gvt_cred_key_correctness_proof,
+    )
+    .expect("Error creating credential offer");
+    let (cred_request, cred_request_metadata) = prover::create_credential_request(
+        Some("entropy"),
+        None,
+        &gvt_cred_def,
+        &prover_wallet.link_secret,
+        "default",
+        &cred_offer,
+    )
+    .expect("Error creating credential request");
+    let mut recv_cred = issuer::create_credential(
+        &gvt_schema,
+        &gvt_cred_def,
+        &gvt_cred_def_priv,
+        &cred_offer,
+        &cred_request,
+        fixtures::credential_values("GVT").into(),
+        None,
+    )
+    .expect("Error creating credential");
+    prover::process_credential(
+        &mut recv_cred,
+        &cred_request_metadata,
+        &prover_wallet.link_secret,
+        &gvt_cred_def,
+        None,
+    )
+    .expect("Error processing credential");
+    prover_wallet.credentials.push(recv_cred);
+
+    let mut schemas = HashMap::new();
+    schemas.insert(SchemaId::new_unchecked(gvt_schema_id), gvt_schema.clone());
+    let mut cred_defs = HashMap::new();
+    cred_defs.insert(
+        CredentialDefinitionId::new_unchecked(gvt_cred_def_id),
+        gvt_cred_def.try_clone().unwrap(),
+    );
+
+    // Verifier asks for 18 <= age < 65 as a single referent
+    let nonce = verifier::generate_nonce().expect("Error generating presentation request nonce");
+    let pres_request = serde_json::from_value(json!({
+        "nonce": nonce,
+        "name":"pres_req_1",
+        "version":"0.1",
+        "ver":"2.0",
+        "requested_attributes":{},
+        "requested_ranges":{
+            "range1_referent":{
+                "name":"age",
+                "lower":{"p_type":">=","p_value":18},
+                "upper":{"p_type":"<","p_value":65}
+            }
+        }
+    }))
+    .expect("Error creating proof request");
+
+    let mut present = PresentCredentials::default();
+    present
+        .add_credential(&prover_wallet.credentials[0], None, None)
+        .add_requested_predicate("range1_referent");
+    let presentation = prover::create_presentation(
+        &pres_request,
+        present,
+        None,
+        &prover_wallet.link_secret,
+        &schemas,
+        &cred_defs,
+    )
+    .expect("Error creating presentation");
+
+    assert_eq!(presentation.requested_proof.predicates.len(), 1);
+    assert!(verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect("Error verifying presentation"));
+
+    // The prover cannot prove a range the attribute is outside of
+    let pres_request = serde_json::from_value(json!({
+        "nonce": nonce,
+        "name":"pres_req_1",
+        "version":"0.1",
+        "ver":"2.0",
+        "requested_attributes":{},
+        "requested_ranges":{
+            "range1_referent":{
+                "name":"age",
+                "lower":{"p_type":">","p_value":30},
+                "upper":{"p_type":"<=","p_value":65}
+            }
+        }
+    }))
+    .expect("Error creating proof request");
+
+    let mut present = PresentCredentials::default();
+    present
+        .add_credential(&prover_wallet.credentials[0], None, None)
+        .add_requested_predicate("range1_referent");
+    prover::create_presentation(
+        &pres_request,
+        present,
+        None,
+        &prover_wallet.link_secret,
+        &schemas,
+        &cred_defs,
+    )
+    .expect_err("Expected an unsatisfied range to fail");
 }
 
 #[test]
@@ -241,6 +483,7 @@ fn anoncreds_demo_works_with_revocation_for_single_issuer_single_prover() {
     let tails_location = gvt_rev_reg_def.value.tails_location.clone();
 
     let issue_cred = issuer::create_credential(
+        &gvt_schema,
         &gvt_cred_def,
         &gvt_cred_def_priv,
         &cred_offer,
@@ -267,6 +510,18 @@ fn anoncreds_demo_works_with_revocation_for_single_issuer_single_prover() {
     )
     .unwrap();
 
+    // Prover refuses a credential whose raw values do not match the signed encoded values
+    let mut tampered_cred = issue_cred.try_clone().unwrap();
+    tampered_cred.values.0.get_mut("name").unwrap().raw = "Bob".to_owned();
+    assert!(prover::process_credential(
+        &mut tampered_cred,
+        &cred_request_metadata,
+        &prover_wallet.link_secret,
+        &gvt_cred_def,
+        Some(&gvt_rev_reg_def),
+    )
+    .is_err());
+
     // Prover receives the credential and processes it
     let mut recv_cred = issue_cred;
     prover::process_credential(
@@ -277,6 +532,22 @@ fn anoncreds_demo_works_with_revocation_for_single_issuer_single_prover() {
         Some(&gvt_rev_reg_def),
     )
     .expect("Error processing credential");
+    prover::validate_credential(
+        &recv_cred,
+        &gvt_schema,
+        &gvt_cred_def,
+        Some(&gvt_rev_reg_def_id),
+        Some(&gvt_rev_reg_def),
+    )
+    .expect("Error validating credential");
+    assert!(prover::validate_credential(
+        &recv_cred,
+        &gvt_schema,
+        &gvt_cred_def,
+        Some(&fixtures::EMP_REV_REG_DEF_ID.try_into().unwrap()),
+        Some(&gvt_rev_reg_def),
+    )
+    .is_err());
     prover_wallet.credentials.push(recv_cred);
 
     // Verifier creates a presentation request
@@ -441,6 +712,7 @@ fn anoncreds_demo_works_for_multiple_issuer_single_prover() {
     let gvt_cred_values = fixtures::credential_values("GVT");
 
     let gvt_issue_cred = issuer::create_credential(
+        &gvt_schema,
         &gvt_cred_def,
         &gvt_cred_def_priv,
         &gvt_cred_offer,
@@ -481,6 +753,7 @@ fn anoncreds_demo_works_for_multiple_issuer_single_prover() {
     let emp_cred_values = fixtures::credential_values("EMP");
 
     let emp_issue_cred = issuer::create_credential(
+        &emp_schema,
         &emp_cred_def,
         &emp_cred_def_priv,
         &emp_cred_offer,
@@ -575,6 +848,609 @@ fn anoncreds_demo_works_for_multiple_issuer_single_prover() {
     assert!(valid);
 }
 
+#[test]
+fn anoncreds_demo_works_for_common_attribute_across_credentials() {
+    let mut prover_wallet = ProverWallet::default();
+
+    let (gvt_schema, gvt_schema_id) = fixtures::create_schema("GVT");
+    let ((gvt_cred_def, gvt_cred_def_priv, gvt_cred_key_correctness_proof), gvt_cred_def_id) =
+        fixtures::create_cred_def(&gvt_schema, false);
+    let (emp_schema, emp_schema_id) = fixtures::create_schema("EMP");
+    let ((emp_cred_def, emp_cred_def_priv, emp_cred_key_correctness_proof), emp_cred_def_id) =
+        fixtures::create_cred_def(&emp_schema, false);
+
+    // The GVT credential is issued to "Alex", the first EMP credential as well,
+    // the second EMP credential to "John"
+    let mut alex_emp_cred_values = fixtures::credential_values("EMP");
+    alex_emp_cred_values
+        .add_raw("name", "Alex")
+        .expect("Error encoding attribute");
+    let issuance = [
+        (
+            &gvt_schema,
+            gvt_schema_id,
+            gvt_cred_def_id,
+            &gvt_cred_def,
+            &gvt_cred_def_priv,
+            &gvt_cred_key_correctness_proof,
+            fixtures::credential_values("GVT"),
+        ),
+        (
+            &emp_schema,
+            emp_schema_id,
+            emp_cred_def_id,
+            &emp_cred_def,
+            &emp_cred_def_priv,
+            &emp_cred_key_correctness_proof,
+            alex_emp_cred_values,
+        ),
+        (
+            &emp_schema,
+            emp_schema_id,
+            emp_cred_def_id,
+            &emp_cred_def,
+            &emp_cred_def_priv,
+            &emp_cred_key_correctness_proof,
+            fixtures::credential_values("EMP"),
+        ),
+    ];
+    for (schema, schema_id, cred_def_id, cred_def, cred_def_priv, correctness_proof, values) in
+        issuance
+    {
+        let cred_offer = issuer::create_credential_offer(
+            schema_id.try_into().unwrap(),
+            cred_def_id.try_into().unwrap(),
+            correctness_proof,
+        )
+        .expect("Error creating credential offer");
+        let (cred_request, cred_request_metadata) = prover::create_credential_request(
+            Some("entropy"),
+            None,
+            cred_def,
+            &prover_wallet.link_secret,
+            "default",
+            &cred_offer,
+        )
+        .expect("Error creating credential request");
+        let mut recv_cred = issuer::create_credential(
+            schema,
+            cred_def,
+            cred_def_priv,
+            &cred_offer,
+            &cred_request,
+            values.into(),
+            None,
+        )
+        .expect("Error creating credential");
+        prover::process_credential(
+            &mut recv_cred,
+            &cred_request_metadata,
+            &prover_wallet.link_secret,
+            cred_def,
+            None,
+        )
+        .expect("Error processing credential");
+        prover_wallet.credentials.push(recv_cred);
+    }
+
+    let mut schemas = HashMap::new();
+    schemas.insert(SchemaId::new_unchecked(gvt_schema_id), gvt_schema.clone());
+    schemas.insert(SchemaId::new_unchecked(emp_schema_id), emp_schema.clone());
+    let mut cred_defs = HashMap::new();
+    cred_defs.insert(
+        CredentialDefinitionId::new_unchecked(gvt_cred_def_id),
+        gvt_cred_def.try_clone().unwrap(),
+    );
+    cred_defs.insert(
+        CredentialDefinitionId::new_unchecked(emp_cred_def_id),
+        emp_cred_def.try_clone().unwrap(),
+    );
+
+    // Verifier asks for the age and the role, and for the hidden names to be equal
+    let nonce = verifier::generate_nonce().expect("Error generating presentation request nonce");
+    let pres_request = serde_json::from_value(json!({
+        "nonce": nonce,
+        "name":"pres_req_1",
+        "version":"0.1",
+        "ver":"2.0",
+        "requested_attributes":{
+            "attr1_referent":{"name":"role", "restrictions": { "cred_def_id": emp_cred_def_id }}
+        },
+        "requested_predicates":{
+            "predicate1_referent":{
+                "name":"age", "p_type":">=", "p_value":18,
+                "restrictions": { "cred_def_id": gvt_cred_def_id }
+            }
+        },
+        "common_attributes": ["name"]
+    }))
+    .expect("Error creating proof request");
+
+    let present_with = |emp_cred_index: usize| {
+        let mut present = PresentCredentials::default();
+        present
+            .add_credential(&prover_wallet.credentials[0], None, None)
+            .add_requested_predicate("predicate1_referent");
+        present
+            .add_credential(&prover_wallet.credentials[emp_cred_index], None, None)
+            .add_requested_attribute("attr1_referent", true);
+        prover::create_presentation(
+            &pres_request,
+            present,
+            None,
+            &prover_wallet.link_secret,
+            &schemas,
+            &cred_defs,
+        )
+        .expect("Error creating presentation")
+    };
+
+    let presentation = present_with(1);
+    assert!(verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect("Error verifying presentation"));
+
+    // The names differ, so the proof is rejected
+    let presentation = present_with(2);
+    let err = verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect_err("Expected the presentation to be rejected");
+    assert_eq!(err.kind(), ErrorKind::ProofRejected);
+
+    // A common attribute cannot be revealed
+    let mut present = PresentCredentials::default();
+    present
+        .add_credential(&prover_wallet.credentials[0], None, None)
+        .add_requested_predicate("predicate1_referent");
+    let mut emp_cred = present.add_credential(&prover_wallet.credentials[1], None, None);
+    emp_cred.add_requested_attribute("attr1_referent", true);
+    emp_cred.add_requested_attribute("attr2_referent", true);
+    let pres_request = serde_json::from_value(json!({
+        "nonce": nonce,
+        "name":"pres_req_1",
+        "version":"0.1",
+        "ver":"2.0",
+        "requested_attributes":{
+            "attr1_referent":{"name":"role"},
+            "attr2_referent":{"name":"name"}
+        },
+        "requested_predicates":{
+            "predicate1_referent":{"name":"age", "p_type":">=", "p_value":18}
+        },
+        "common_attributes": ["name"]
+    }))
+    .expect("Error creating proof request");
+    prover::create_presentation(
+        &pres_request,
+        present,
+        None,
+        &prover_wallet.link_secret,
+        &schemas,
+        &cred_defs,
+    )
+    .expect_err("Expected a revealed common attribute to fail");
+}
+
+#[test]
+fn anoncreds_demo_works_for_presentation_request_v2_features() {
+    let mut prover_wallet = ProverWallet::default();
+
+    let (gvt_schema, gvt_schema_id) = fixtures::create_schema("GVT");
+    let ((gvt_cred_def, gvt_cred_def_priv, gvt_cred_key_correctness_proof), gvt_cred_def_id) =
+        fixtures::create_cred_def(&gvt_schema, false);
+
+    let cred_offer = issuer::create_credential_offer(
+        gvt_schema_id.try_into().unwrap(),
+        gvt_cred_def_id.try_into().unwrap(),
+        &gvt_cred_key_correctness_proof,
+    )
+    .expect("Error creating credential offer");
+    let (cred_request, cred_request_metadata) = prover::create_credential_request(
+        Some("entropy"),
+        None,
+        &gvt_cred_def,
+        &prover_wallet.link_secret,
+        "default",
+        &cred_offer,
+    )
+    .expect("Error creating credential request");
+    let mut recv_cred = issuer::create_credential(
+        &gvt_schema,
+        &gvt_cred_def,
+        &gvt_cred_def_priv,
+        &cred_offer,
+        &cred_request,
+        fixtures::credential_values("GVT").into(),
+        None,
+    )
+    .expect("Error creating credential");
+    prover::process_credential(
+        &mut recv_cred,
+        &cred_request_metadata,
+        &prover_wallet.link_secret,
+        &gvt_cred_def,
+        None,
+    )
+    .expect("Error processing credential");
+    prover_wallet.credentials.push(recv_cred);
+
+    let mut schemas = HashMap::new();
+    schemas.insert(SchemaId::new_unchecked(gvt_schema_id), gvt_schema.clone());
+    let mut cred_defs = HashMap::new();
+    cred_defs.insert(
+        CredentialDefinitionId::new_unchecked(gvt_cred_def_id),
+        gvt_cred_def.try_clone().unwrap(),
+    );
+
+    let nonce = verifier::generate_nonce().expect("Error generating presentation request nonce");
+    let pres_request_with = |extra: serde_json::Value| {
+        let mut pres_request = json!({
+            "nonce": nonce,
+            "name":"pres_req_1",
+            "version":"0.1",
+            "ver":"2.0",
+            "requested_attributes":{
+                "attr1_referent":{
+                    "names":["name", "sex"],
+                    "optional_names":["height", "middle_name"]
+                },
+                "attr2_referent":{"name":"age"}
+            },
+            "expires_at": u64::MAX,
+            "verifier": {"id": "did:example:verifier", "purpose": "Proof of identity"}
+        });
+        pres_request
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(pres_request).expect("Error creating proof request")
+    };
+    let present_with = |pres_request: &_, reveal_age: bool| {
+        let mut present = PresentCredentials::default();
+        let mut cred = present.add_credential(&prover_wallet.credentials[0], None, None);
+        cred.add_requested_attribute("attr1_referent", true);
+        cred.add_requested_attribute("attr2_referent", reveal_age);
+        prover::create_presentation(
+            pres_request,
+            present,
+            None,
+            &prover_wallet.link_secret,
+            &schemas,
+            &cred_defs,
+        )
+    };
+
+    // Optional group members are revealed when the credential has them
+    let pres_request = pres_request_with(json!({"min_credentials": 1}));
+    let presentation = present_with(&pres_request, false).expect("Error creating presentation");
+    let group = &presentation.requested_proof.revealed_attr_groups["attr1_referent"];
+    assert_eq!(group.values["height"].raw, "175");
+    assert!(!group.values.contains_key("middle_name"));
+    assert!(verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect("Error verifying presentation"));
+
+    // A hidden attribute does not satisfy a reveal-all disclosure policy
+    let strict_request = pres_request_with(json!({"disclosure_policy": "reveal_all"}));
+    present_with(&strict_request, false)
+        .expect_err("Expected the prover to refuse to hide an attribute");
+    let err = verifier::verify_presentation(
+        &presentation,
+        &strict_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect_err("Expected the presentation to be rejected");
+    assert_eq!(err.kind(), ErrorKind::ProofRejected);
+    let presentation = present_with(&strict_request, true).expect("Error creating presentation");
+    assert!(verifier::verify_presentation(
+        &presentation,
+        &strict_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect("Error verifying presentation"));
+
+    // Not enough credentials
+    let pres_request = pres_request_with(json!({"min_credentials": 2}));
+    present_with(&pres_request, true).expect_err("Expected too few credentials to fail");
+
+    // Expired request
+    let pres_request = pres_request_with(json!({"expires_at": 1}));
+    present_with(&pres_request, true).expect_err("Expected an expired request to fail");
+    let expires_at = 4_000_000_000;
+    let pres_request = pres_request_with(json!({ "expires_at": expires_at }));
+    for (now, expired) in [(expires_at + 1, true), (expires_at, false)] {
+        let mut present = PresentCredentials::default();
+        let mut cred = present.add_credential(&prover_wallet.credentials[0], None, None);
+        cred.add_requested_attribute("attr1_referent", true);
+        cred.add_requested_attribute("attr2_referent", true);
+        let presentation = prover::create_presentation_with_options(
+            &pres_request,
+            present,
+            None,
+            &prover_wallet.link_secret,
+            &schemas,
+            &cred_defs,
+            &prover::PresentationOptions { now: Some(now) },
+        );
+        assert_eq!(presentation.is_err(), expired);
+    }
+
+    // Verifier checks the expiry against its own clock
+    let presentation = present_with(&pres_request, true).expect("Error creating presentation");
+    for (now, expected) in [
+        (expires_at + 1, Err(ErrorKind::ProofRejected)),
+        (expires_at, Ok(true)),
+    ] {
+        let valid = verifier::verify_presentation_with_options(
+            &presentation,
+            &pres_request,
+            &schemas,
+            &cred_defs,
+            None,
+            None,
+            None,
+            verifier::VerificationOptions {
+                now: Some(now),
+                ..verifier::VerificationOptions::default()
+            },
+        )
+        .map_err(|e| e.kind());
+        assert_eq!(valid, expected);
+    }
+    let mut nonce_registry = InMemoryNonceRegistry::new(300);
+    nonce_registry
+        .register(&pres_request.value().nonce, expires_at - 100)
+        .expect("Error registering nonce");
+    for (now, expected) in [
+        (expires_at + 1, Err(ErrorKind::ProofRejected)),
+        (expires_at, Ok(true)),
+    ] {
+        let valid = verifier::verify_presentation_with_options(
+            &presentation,
+            &pres_request,
+            &schemas,
+            &cred_defs,
+            None,
+            None,
+            None,
+            verifier::VerificationOptions {
+                now: Some(now),
+                nonce_registry: Some(&mut nonce_registry),
+                ..verifier::VerificationOptions::default()
+            },
+        )
+        .map_err(|e| e.kind());
+        assert_eq!(valid, expected);
+    }
+}
+
+#[test]
+fn anoncreds_demo_works_for_optional_referents() {
+    let mut prover_wallet = ProverWallet::default();
+
+    let (gvt_schema, gvt_schema_id) = fixtures::create_schema("GVT");
+    let ((gvt_cred_def, gvt_cred_def_priv, gvt_cred_key_correctness_proof), gvt_cred_def_id) =
+        fixtures::create_cred_def(&gvt_schema, false);
+
+    let cred_offer = issuer::create_credential_offer(
+        gvt_schema_id.try_into().unwrap(),
+        gvt_cred_def_id.try_into().unwrap(),
+        &gvt_cred_key_correctness_proof,
+    )
+    .expect("Error creating credential offer");
+    let (cred_request, cred_request_metadata) = prover::create_credential_request(
+        Some("entropy"),
+        None,
+        &gvt_cred_def,
+        &prover_wallet.link_secret,
+        "default",
+        &cred_offer,
+    )
+    .expect("Error creating credential request");
+    let mut recv_cred = issuer::create_credential(
+        &gvt_schema,
+        &gvt_cred_def,
+        &gvt_cred_def_priv,
+        &cred_offer,
+        &cred_request,
+        fixtures::credential_values("GVT").into(),
+        None,
+    )
+    .expect("Error creating credential");
+    prover::process_credential(
+        &mut recv_cred,
+        &cred_request_metadata,
+        &prover_wallet.link_secret,
+        &gvt_cred_def,
+        None,
+    )
+    .expect("Error processing credential");
+    prover_wallet.credentials.push(recv_cred);
+
+    let mut schemas = HashMap::new();
+    schemas.insert(SchemaId::new_unchecked(gvt_schema_id), gvt_schema.clone());
+    let mut cred_defs = HashMap::new();
+    cred_defs.insert(
+        CredentialDefinitionId::new_unchecked(gvt_cred_def_id),
+        gvt_cred_def.try_clone().unwrap(),
+    );
+
+    // The prover holds no credential with a middle name or a salary
+    let nonce = verifier::generate_nonce().expect("Error generating presentation request nonce");
+    let pres_request = serde_json::from_value(json!({
+        "nonce": nonce,
+        "name":"pres_req_1",
+        "version":"0.1",
+        "ver":"2.0",
+        "requested_attributes":{
+            "attr1_referent":{"name":"name"},
+            "attr2_referent":{"name":"middle_name", "optional": true,
+                "restrictions": { "cred_def_id": gvt_cred_def_id }}
+        },
+        "requested_predicates":{
+            "predicate1_referent":{"name":"age", "p_type":">=", "p_value":18},
+            "predicate2_referent":{"name":"salary", "p_type":">=", "p_value":1000, "optional": true}
+        }
+    }))
+    .expect("Error creating proof request");
+
+    let mut present = PresentCredentials::default();
+    let mut cred = present.add_credential(&prover_wallet.credentials[0], None, None);
+    cred.add_requested_attribute("attr1_referent", true);
+    cred.add_requested_predicate("predicate1_referent");
+    let presentation = prover::create_presentation(
+        &pres_request,
+        present,
+        None,
+        &prover_wallet.link_secret,
+        &schemas,
+        &cred_defs,
+    )
+    .expect("Error creating presentation");
+
+    let mut report = verifier::VerificationReport::default();
+    let valid = verifier::verify_presentation_with_options(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+        verifier::VerificationOptions {
+            report: Some(&mut report),
+            ..verifier::VerificationOptions::default()
+        },
+    )
+    .expect("Error verifying presentation");
+    assert!(valid);
+    assert!(report.valid);
+    assert_eq!(
+        report.omitted_referents,
+        vec!["attr2_referent", "predicate2_referent"]
+    );
+
+    // Mandatory referents cannot be left out
+    let mut present = PresentCredentials::default();
+    present
+        .add_credential(&prover_wallet.credentials[0], None, None)
+        .add_requested_attribute("attr1_referent", true);
+    let presentation = prover::create_presentation(
+        &pres_request,
+        present,
+        None,
+        &prover_wallet.link_secret,
+        &schemas,
+        &cred_defs,
+    )
+    .expect("Error creating presentation");
+    verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect_err("Expected a missing predicate to fail");
+}
+
+#[test]
+fn anoncreds_demo_works_for_self_attested_policies() {
+    let prover_wallet = ProverWallet::default();
+    let schemas = HashMap::new();
+    let cred_defs = HashMap::new();
+
+    let nonce = verifier::generate_nonce().expect("Error generating presentation request nonce");
+    let pres_request_with = |phone_policy: serde_json::Value| {
+        serde_json::from_value(json!({
+            "nonce": nonce,
+            "name":"pres_req_1",
+            "version":"0.1",
+            "ver":"2.0",
+            "requested_attributes":{
+                "attr1_referent":{"name":"phone", "self_attested": phone_policy},
+                "attr2_referent":{"name":"newsletter", "self_attested": {"values": ["yes", "no"]}}
+            }
+        }))
+        .expect("Error creating proof request")
+    };
+    let present_with = |pres_request: &_, phone: &str| {
+        let self_attested = HashMap::from([
+            ("attr1_referent".to_string(), phone.to_string()),
+            ("attr2_referent".to_string(), "no".to_string()),
+        ]);
+        prover::create_presentation(
+            pres_request,
+            PresentCredentials::default(),
+            Some(self_attested),
+            &prover_wallet.link_secret,
+            &schemas,
+            &cred_defs,
+        )
+    };
+
+    let pres_request = pres_request_with(json!({"pattern": "\\+?[0-9 -]+"}));
+    let presentation =
+        present_with(&pres_request, "+1 555-0100").expect("Error creating presentation");
+    assert!(verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect("Error verifying presentation"));
+    present_with(&pres_request, "call me").expect_err("Expected the prover to refuse the value");
+
+    // The verifier enforces the policy as well
+    let pres_request = pres_request_with(json!({"allowed": false}));
+    present_with(&pres_request, "+1 555-0100")
+        .expect_err("Expected the prover to refuse to self-attest");
+    verifier::verify_presentation(
+        &presentation,
+        &pres_request,
+        &schemas,
+        &cred_defs,
+        None,
+        None,
+        None,
+    )
+    .expect_err("Expected a forbidden self-attested value to be rejected");
+}
+
 #[test]
 fn anoncreds_demo_proof_does_not_verify_with_wrong_attr_and_predicates() {
     // Create Prover pseudo wallet and link secret
@@ -609,6 +1485,7 @@ fn anoncreds_demo_proof_does_not_verify_with_wrong_attr_and_predicates() {
     // Issuer creates a credential
     let cred_values = fixtures::credential_values("GVT");
     let issue_cred = issuer::create_credential(
+        &gvt_schema,
         &gvt_cred_def,
         &gvt_cred_def_priv,
         &cred_offer,
@@ -736,6 +1613,7 @@ fn anoncreds_demo_works_for_requested_attribute_in_upper_case() {
     // Issuer creates a credential
     let cred_values = fixtures::credential_values("GVT");
     let issue_cred = issuer::create_credential(
+        &gvt_schema,
         &gvt_cred_def,
         &gvt_cred_def_priv,
         &cred_offer,
@@ -912,6 +1790,7 @@ fn anoncreds_demo_works_for_twice_entry_of_attribute_from_different_credential()
     let gvt_cred_values = fixtures::credential_values("GVT");
 
     let gvt_issue_cred = issuer::create_credential(
+        &gvt_schema,
         &gvt_cred_def,
         &gvt_cred_def_priv,
         &gvt_cred_offer,
@@ -952,6 +1831,7 @@ fn anoncreds_demo_works_for_twice_entry_of_attribute_from_different_credential()
     let emp_cred_values = fixtures::credential_values("EMP");
 
     let emp_issue_cred = issuer::create_credential(
+        &emp_schema,
         &emp_cred_def,
         &emp_cred_def_priv,
         &emp_cred_offer,
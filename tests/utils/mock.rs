@@ -233,6 +233,7 @@ impl<'a> Mock<'a> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn issuer_create_credential(
         &self,
         issuer_wallet: &IssuerWallet,
@@ -282,6 +283,7 @@ impl<'a> Mock<'a> {
             .expect("Error creating revocation config");
 
         let issue_cred = issuer::create_credential(
+            schema,
             ledger
                 .cred_defs
                 .get(&CredentialDefinitionId::new_unchecked(cred_def_id))
@@ -4,6 +4,11 @@ import type {
   Anoncreds,
   NativeCredentialRevocationConfig,
   NativeNonRevokedIntervalOverride,
+  NativeProgressOptions,
+  NativeLiveObject,
+  NativePresentationIdentifier,
+  NativeCredentialSummary,
+  NativeTailsChunkCallback,
   AnoncredsErrorObject
 } from '@hyperledger/anoncreds-shared'
 import type { TypedArray } from 'ref-array-di'
@@ -23,12 +28,18 @@ import {
   CredentialEntryListStruct,
   CredentialProveListStruct,
   allocateInt8Buffer,
+  allocateInt64Buffer,
   CredRevInfoStruct,
   allocateByteBuffer,
   ObjectHandleListStruct,
   ObjectHandleArray,
   NonRevokedIntervalOverrideStruct,
-  NonRevokedIntervalOverrideListStruct
+  NonRevokedIntervalOverrideListStruct,
+  allocateCancellationHandle,
+  allocateScopeHandle,
+  allocateSizeBuffer,
+  toNativeProgressCallback,
+  toNativeTailsWriteCallback
 } from './ffi'
 import { getNativeAnoncreds } from './library'
 
@@ -82,6 +93,13 @@ export class NodeJSAnoncreds implements Anoncreds {
     return new ObjectHandle(handleReturnPointer<number>(ret))
   }
 
+  public validateIssuerId(options: { issuerId: string; didMethods?: string[] }): void {
+    const { issuerId, didMethods } = serializeArguments({ ...options, didMethods: options.didMethods ?? [] })
+
+    this.nativeAnoncreds.anoncreds_validate_issuer_id(issuerId, didMethods)
+    this.handleError()
+  }
+
   public revocationRegistryDefinitionGetAttribute(options: { objectHandle: ObjectHandle; name: string }) {
     const { objectHandle, name } = serializeArguments(options)
 
@@ -102,6 +120,76 @@ export class NodeJSAnoncreds implements Anoncreds {
     return handleReturnPointer<string>(ret)
   }
 
+  public credentialGetAttributeNames(options: { objectHandle: ObjectHandle }): string[] {
+    const { objectHandle } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_credential_get_attribute_names(objectHandle, ret)
+    this.handleError()
+
+    return JSON.parse(handleReturnPointer<string>(ret)) as string[]
+  }
+
+  public credentialGetAttributeValue(options: { objectHandle: ObjectHandle; name: string; encoded?: boolean }): string {
+    const { objectHandle, name } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_credential_get_attribute_value(objectHandle, name, options.encoded ? 1 : 0, ret)
+    this.handleError()
+
+    return handleReturnPointer<string>(ret)
+  }
+
+  public credentialGetSummary(options: { objectHandle: ObjectHandle }): NativeCredentialSummary {
+    const { objectHandle } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_credential_get_summary(objectHandle, ret)
+    this.handleError()
+
+    return JSON.parse(handleReturnPointer<string>(ret)) as NativeCredentialSummary
+  }
+
+  public schemaGetAttrNames(options: { objectHandle: ObjectHandle }): string[] {
+    const { objectHandle } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_schema_get_attr_names(objectHandle, ret)
+    this.handleError()
+
+    return JSON.parse(handleReturnPointer<string>(ret)) as string[]
+  }
+
+  public credentialDefinitionGetIssuerId(options: { objectHandle: ObjectHandle }): string {
+    const { objectHandle } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_credential_definition_get_issuer_id(objectHandle, ret)
+    this.handleError()
+
+    return handleReturnPointer<string>(ret)
+  }
+
+  public revocationStatusListGetTimestamp(options: { objectHandle: ObjectHandle }): number {
+    const { objectHandle } = serializeArguments(options)
+
+    const ret = allocateInt64Buffer()
+    this.nativeAnoncreds.anoncreds_revocation_status_list_get_timestamp(objectHandle, ret)
+    this.handleError()
+
+    return handleReturnPointer<number>(ret)
+  }
+
+  public presentationGetIdentifiers(options: { objectHandle: ObjectHandle }): NativePresentationIdentifier[] {
+    const { objectHandle } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_presentation_get_identifiers(objectHandle, ret)
+    this.handleError()
+
+    return JSON.parse(handleReturnPointer<string>(ret)) as NativePresentationIdentifier[]
+  }
+
   public createCredentialDefinition(options: {
     schemaId: string
     schema: ObjectHandle
@@ -141,6 +229,7 @@ export class NodeJSAnoncreds implements Anoncreds {
   }
 
   public createCredential(options: {
+    schema: ObjectHandle
     credentialDefinition: ObjectHandle
     credentialDefinitionPrivate: ObjectHandle
     credentialOffer: ObjectHandle
@@ -149,7 +238,7 @@ export class NodeJSAnoncreds implements Anoncreds {
     attributeEncodedValues?: Record<string, string>
     revocationConfiguration?: NativeCredentialRevocationConfig
   }): ObjectHandle {
-    const { credentialDefinition, credentialDefinitionPrivate, credentialOffer, credentialRequest } =
+    const { schema, credentialDefinition, credentialDefinitionPrivate, credentialOffer, credentialRequest } =
       serializeArguments(options)
 
     const attributeNames = StringListStruct({
@@ -184,6 +273,7 @@ export class NodeJSAnoncreds implements Anoncreds {
 
     const credentialPtr = allocatePointer()
     this.nativeAnoncreds.anoncreds_create_credential(
+      schema,
       credentialDefinition,
       credentialDefinitionPrivate,
       credentialOffer,
@@ -374,7 +464,7 @@ export class NodeJSAnoncreds implements Anoncreds {
 
     const ret = allocatePointer()
 
-    this.nativeAnoncreds.anoncreds_create_presentation(
+    this.nativeAnoncreds.anoncreds_create_presentation_v2(
       presentationRequest,
       credentialEntryList as unknown as Buffer,
       credentialProveList as unknown as Buffer,
@@ -439,7 +529,7 @@ export class NodeJSAnoncreds implements Anoncreds {
 
     const ret = allocateInt8Buffer()
 
-    this.nativeAnoncreds.anoncreds_verify_presentation(
+    this.nativeAnoncreds.anoncreds_verify_presentation_v2(
       presentation,
       presentationRequest,
       schemas,
@@ -493,6 +583,48 @@ export class NodeJSAnoncreds implements Anoncreds {
     return new ObjectHandle(handleReturnPointer<number>(ret))
   }
 
+  public createRevocationStatusListWithProgress(
+    options: {
+      credentialDefinition: ObjectHandle
+      revocationRegistryDefinitionId: string
+      revocationRegistryDefinition: ObjectHandle
+      revocationRegistryDefinitionPrivate: ObjectHandle
+      issuerId: string
+      issuanceByDefault: boolean
+      timestamp?: number
+    } & NativeProgressOptions
+  ): ObjectHandle {
+    const { onProgress, cancellationToken, ...rest } = options
+    const {
+      credentialDefinition,
+      revocationRegistryDefinitionId,
+      revocationRegistryDefinition,
+      revocationRegistryDefinitionPrivate,
+      issuerId,
+      issuanceByDefault,
+      timestamp
+    } = serializeArguments(rest)
+
+    const ret = allocatePointer()
+
+    this.nativeAnoncreds.anoncreds_create_revocation_status_list_with_progress(
+      credentialDefinition,
+      revocationRegistryDefinitionId,
+      revocationRegistryDefinition,
+      revocationRegistryDefinitionPrivate,
+      issuerId,
+      issuanceByDefault,
+      timestamp ?? -1,
+      toNativeProgressCallback(onProgress),
+      0,
+      cancellationToken ?? 0,
+      ret
+    )
+    this.handleError()
+
+    return new ObjectHandle(handleReturnPointer<number>(ret))
+  }
+
   public updateRevocationStatusListTimestampOnly(options: {
     timestamp: number
     currentRevocationStatusList: ObjectHandle
@@ -588,6 +720,162 @@ export class NodeJSAnoncreds implements Anoncreds {
     }
   }
 
+  public createRevocationRegistryDefinitionWithProgress(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      issuerId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsDirectoryPath?: string
+    } & NativeProgressOptions
+  ) {
+    const { onProgress, cancellationToken, ...rest } = options
+    const {
+      credentialDefinition,
+      credentialDefinitionId,
+      tag,
+      revocationRegistryType,
+      issuerId,
+      maximumCredentialNumber,
+      tailsDirectoryPath
+    } = serializeArguments(rest)
+
+    const revocationRegistryDefinitionPtr = allocatePointer()
+    const revocationRegistryDefinitionPrivate = allocatePointer()
+
+    this.nativeAnoncreds.anoncreds_create_revocation_registry_def_with_progress(
+      credentialDefinition,
+      credentialDefinitionId,
+      issuerId,
+      tag,
+      revocationRegistryType,
+      maximumCredentialNumber,
+      tailsDirectoryPath,
+      toNativeProgressCallback(onProgress),
+      0,
+      cancellationToken ?? 0,
+      revocationRegistryDefinitionPtr,
+      revocationRegistryDefinitionPrivate
+    )
+    this.handleError()
+
+    return {
+      revocationRegistryDefinition: new ObjectHandle(handleReturnPointer<number>(revocationRegistryDefinitionPtr)),
+      revocationRegistryDefinitionPrivate: new ObjectHandle(
+        handleReturnPointer<number>(revocationRegistryDefinitionPrivate)
+      )
+    }
+  }
+
+  public createRevocationRegistryDefinitionInMemory(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+    } & NativeProgressOptions
+  ) {
+    const { onProgress, cancellationToken, ...rest } = options
+    const {
+      credentialDefinition,
+      credentialDefinitionId,
+      tag,
+      revocationRegistryType,
+      maximumCredentialNumber,
+      tailsLocation
+    } = serializeArguments(rest)
+
+    const revocationRegistryDefinitionPtr = allocatePointer()
+    const revocationRegistryDefinitionPrivate = allocatePointer()
+    const tails = allocateByteBuffer()
+
+    this.nativeAnoncreds.anoncreds_create_revocation_registry_def_in_memory(
+      credentialDefinition,
+      credentialDefinitionId,
+      tag,
+      revocationRegistryType,
+      maximumCredentialNumber,
+      tailsLocation,
+      toNativeProgressCallback(onProgress),
+      0,
+      cancellationToken ?? 0,
+      revocationRegistryDefinitionPtr,
+      revocationRegistryDefinitionPrivate,
+      tails
+    )
+    this.handleError()
+
+    const tailsBuffer = handleReturnPointer<{ data: Buffer; len: number }>(tails)
+    const tailsData = new Uint8Array(byteBufferToBuffer(tailsBuffer))
+    this.nativeAnoncreds.anoncreds_buffer_free(tailsBuffer.data)
+
+    return {
+      revocationRegistryDefinition: new ObjectHandle(handleReturnPointer<number>(revocationRegistryDefinitionPtr)),
+      revocationRegistryDefinitionPrivate: new ObjectHandle(
+        handleReturnPointer<number>(revocationRegistryDefinitionPrivate)
+      ),
+      tails: tailsData
+    }
+  }
+
+  public createRevocationRegistryDefinitionWithSink(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+      onTailsChunk: NativeTailsChunkCallback
+    } & NativeProgressOptions
+  ) {
+    const { onTailsChunk, onProgress, cancellationToken, ...rest } = options
+    const {
+      credentialDefinition,
+      credentialDefinitionId,
+      tag,
+      revocationRegistryType,
+      maximumCredentialNumber,
+      tailsLocation
+    } = serializeArguments(rest)
+
+    const revocationRegistryDefinitionPtr = allocatePointer()
+    const revocationRegistryDefinitionPrivate = allocatePointer()
+
+    let chunkError: unknown
+    this.nativeAnoncreds.anoncreds_create_revocation_registry_def_with_sink(
+      credentialDefinition,
+      credentialDefinitionId,
+      tag,
+      revocationRegistryType,
+      maximumCredentialNumber,
+      tailsLocation,
+      toNativeTailsWriteCallback(onTailsChunk, (error) => {
+        chunkError = error
+      }),
+      0,
+      toNativeProgressCallback(onProgress),
+      0,
+      cancellationToken ?? 0,
+      revocationRegistryDefinitionPtr,
+      revocationRegistryDefinitionPrivate
+    )
+    // Rethrow the error from the chunk handler rather than the generic write failure
+    if (chunkError !== undefined) throw chunkError
+    this.handleError()
+
+    return {
+      revocationRegistryDefinition: new ObjectHandle(handleReturnPointer<number>(revocationRegistryDefinitionPtr)),
+      revocationRegistryDefinitionPrivate: new ObjectHandle(
+        handleReturnPointer<number>(revocationRegistryDefinitionPrivate)
+      )
+    }
+  }
+
   public createOrUpdateRevocationState(options: {
     revocationRegistryDefinition: ObjectHandle
     revocationStatusList: ObjectHandle
@@ -617,6 +905,60 @@ export class NodeJSAnoncreds implements Anoncreds {
     return new ObjectHandle(handleReturnPointer<number>(ret))
   }
 
+  public createOrUpdateRevocationStateWithProgress(
+    options: {
+      revocationRegistryDefinition: ObjectHandle
+      revocationStatusList: ObjectHandle
+      revocationRegistryIndex: number
+      tailsPath: string
+      oldRevocationState?: ObjectHandle
+      oldRevocationStatusList?: ObjectHandle
+    } & NativeProgressOptions
+  ): ObjectHandle {
+    const { onProgress, cancellationToken, ...rest } = options
+    const { revocationRegistryDefinition, revocationStatusList, revocationRegistryIndex, tailsPath } =
+      serializeArguments(rest)
+
+    const oldRevocationState = rest.oldRevocationState ?? new ObjectHandle(0)
+    const oldRevocationStatusList = rest.oldRevocationStatusList ?? new ObjectHandle(0)
+    const ret = allocatePointer()
+
+    this.nativeAnoncreds.anoncreds_create_or_update_revocation_state_with_progress(
+      revocationRegistryDefinition,
+      revocationStatusList,
+      revocationRegistryIndex,
+      tailsPath,
+      oldRevocationState.handle,
+      oldRevocationStatusList.handle,
+      toNativeProgressCallback(onProgress),
+      0,
+      cancellationToken ?? 0,
+      ret
+    )
+    this.handleError()
+
+    return new ObjectHandle(handleReturnPointer<number>(ret))
+  }
+
+  public cancellationTokenCreate(): number {
+    const ret = allocateCancellationHandle()
+
+    this.nativeAnoncreds.anoncreds_cancellation_token_create(ret)
+    this.handleError()
+
+    return handleReturnPointer<number>(ret)
+  }
+
+  public cancellationTokenCancel(options: { cancellationToken: number }): void {
+    this.nativeAnoncreds.anoncreds_cancellation_token_cancel(options.cancellationToken)
+    this.handleError()
+  }
+
+  public cancellationTokenFree(options: { cancellationToken: number }): void {
+    this.nativeAnoncreds.anoncreds_cancellation_token_free(options.cancellationToken)
+    this.handleError()
+  }
+
   public version(): string {
     return this.nativeAnoncreds.anoncreds_version()
   }
@@ -738,4 +1080,68 @@ export class NodeJSAnoncreds implements Anoncreds {
     this.nativeAnoncreds.anoncreds_object_free(options.objectHandle.handle)
     this.handleError()
   }
+
+  public objectCount(options: { typeName?: string }): number {
+    const { typeName } = serializeArguments(options)
+
+    const ret = allocateSizeBuffer()
+    this.nativeAnoncreds.anoncreds_object_count(typeName, ret)
+    this.handleError()
+
+    return handleReturnPointer<number>(ret)
+  }
+
+  public objectGetCounts(): Record<string, number> {
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_object_get_counts(ret)
+    this.handleError()
+
+    return JSON.parse(handleReturnPointer<string>(ret)) as Record<string, number>
+  }
+
+  public objectListHandles(options: { typeName?: string }): NativeLiveObject[] {
+    const { typeName } = serializeArguments(options)
+
+    const ret = allocateStringBuffer()
+    this.nativeAnoncreds.anoncreds_object_list_handles(typeName, ret)
+    this.handleError()
+
+    return JSON.parse(handleReturnPointer<string>(ret)) as NativeLiveObject[]
+  }
+
+  public objectScopeBegin(): number {
+    const ret = allocateScopeHandle()
+    this.nativeAnoncreds.anoncreds_object_scope_begin(ret)
+    this.handleError()
+
+    return handleReturnPointer<number>(ret)
+  }
+
+  public objectScopeEnd(options: { scope: number }): void {
+    this.nativeAnoncreds.anoncreds_object_scope_end(options.scope)
+    this.handleError()
+  }
+
+  public objectFreeScope(options: { scope: number }): number {
+    const ret = allocateSizeBuffer()
+    this.nativeAnoncreds.anoncreds_object_free_scope(options.scope, ret)
+    this.handleError()
+
+    return handleReturnPointer<number>(ret)
+  }
+
+  public objectSetLeakDebug(options: { enabled: boolean }): void {
+    const { enabled } = serializeArguments(options)
+
+    this.nativeAnoncreds.anoncreds_object_set_leak_debug(enabled)
+    this.handleError()
+  }
+
+  public objectReportLeaks(): number {
+    const ret = allocateSizeBuffer()
+    this.nativeAnoncreds.anoncreds_object_report_leaks(ret)
+    this.handleError()
+
+    return handleReturnPointer<number>(ret)
+  }
 }
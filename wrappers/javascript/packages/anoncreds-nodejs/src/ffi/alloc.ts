@@ -1,6 +1,14 @@
 import { alloc } from '@2060.io/ref-napi'
 
-import { FFI_STRING, FFI_OBJECT_HANDLE, FFI_INT8 } from '../ffi/primitives'
+import {
+  FFI_STRING,
+  FFI_OBJECT_HANDLE,
+  FFI_INT8,
+  FFI_INT64,
+  FFI_ISIZE,
+  FFI_SCOPE_HANDLE,
+  FFI_CANCELLATION_HANDLE
+} from '../ffi/primitives'
 
 import { ByteBufferStruct, CredRevInfoStruct } from './structures'
 
@@ -10,8 +18,16 @@ export const allocateStringBuffer = (): Buffer => alloc(FFI_STRING)
 
 export const allocatePointer = (): Buffer => alloc(FFI_OBJECT_HANDLE)
 
+export const allocateSizeBuffer = (): Buffer => alloc(FFI_ISIZE)
+
+export const allocateScopeHandle = (): Buffer => alloc(FFI_SCOPE_HANDLE)
+
+export const allocateCancellationHandle = (): Buffer => alloc(FFI_CANCELLATION_HANDLE)
+
 export const allocateInt8Buffer = (): Buffer => alloc(FFI_INT8)
 
+export const allocateInt64Buffer = (): Buffer => alloc(FFI_INT64)
+
 export const allocateCredRevInfoStructPointer = (): Buffer => alloc(CredRevInfoStruct)
 
 export const allocateByteBuffer = (): Buffer => alloc(ByteBufferStruct)
@@ -0,0 +1,30 @@
+import type { NativeProgressCallback, NativeTailsChunkCallback } from '@hyperledger/anoncreds-shared'
+
+import { Callback } from '@2060.io/ffi-napi'
+import { NULL, reinterpret } from '@2060.io/ref-napi'
+
+import { FFI_INT8, FFI_INT64, FFI_ISIZE, FFI_UINT8_PTR, FFI_UINT64, FFI_VOID } from './primitives'
+
+// The callback id is unused as the callback is created per call
+export const toNativeProgressCallback = (onProgress?: NativeProgressCallback): Buffer =>
+  onProgress
+    ? Callback(FFI_VOID, [FFI_INT64, FFI_UINT64, FFI_UINT64], (_cbId: number, done: number, total: number) =>
+        onProgress(Number(done), Number(total))
+      )
+    : NULL
+
+// The chunk is copied as the native buffer is only valid during the call. An error thrown by
+// `onTailsChunk` is passed to `onError` and reported to the library as a failed write
+export const toNativeTailsWriteCallback = (
+  onTailsChunk: NativeTailsChunkCallback,
+  onError: (error: unknown) => void
+): Buffer =>
+  Callback(FFI_INT8, [FFI_INT64, FFI_UINT8_PTR, FFI_ISIZE], (_cbId: number, data: Buffer, len: number) => {
+    try {
+      onTailsChunk(new Uint8Array(reinterpret(data, Number(len))))
+      return 0
+    } catch (error) {
+      onError(error)
+      return 1
+    }
+  })
@@ -1,4 +1,5 @@
 export * from './alloc'
+export * from './callback'
 export * from './conversion'
 export * from './structures'
 export * from './primitives'
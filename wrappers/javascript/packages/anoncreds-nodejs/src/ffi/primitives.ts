@@ -8,14 +8,22 @@ export const FFI_INT32 = 'int32'
 export const FFI_INT64 = 'int64'
 export const FFI_UINT = 'uint'
 export const FFI_UINT8 = 'uint8'
+export const FFI_UINT64 = 'uint64'
 export const FFI_ERRORCODE = FFI_UINT
 export const FFI_OBJECT_HANDLE = FFI_ISIZE
+export const FFI_SCOPE_HANDLE = FFI_ISIZE
+export const FFI_CANCELLATION_HANDLE = FFI_ISIZE
 export const FFI_VOID = types.void
 export const FFI_STRING = 'string'
+export const FFI_CALLBACK_PTR = 'pointer'
 
 // Pointers
 
 export const FFI_ISIZE_PTR = refType(FFI_ISIZE)
 export const FFI_INT8_PTR = refType(FFI_INT8)
+export const FFI_INT64_PTR = refType(FFI_INT64)
+export const FFI_UINT8_PTR = refType(FFI_UINT8)
 export const FFI_OBJECT_HANDLE_PTR = refType(FFI_OBJECT_HANDLE)
+export const FFI_SCOPE_HANDLE_PTR = refType(FFI_SCOPE_HANDLE)
+export const FFI_CANCELLATION_HANDLE_PTR = refType(FFI_CANCELLATION_HANDLE)
 export const FFI_STRING_PTR = refType(FFI_STRING)
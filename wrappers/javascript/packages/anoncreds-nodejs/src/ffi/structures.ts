@@ -2,7 +2,7 @@ import * as ref from '@2060.io/ref-napi'
 import RefArray from 'ref-array-di'
 import RefStruct from 'ref-struct-di'
 
-import { FFI_INT64, FFI_INT8, FFI_ISIZE, FFI_OBJECT_HANDLE, FFI_STRING, FFI_INT32 } from './primitives'
+import { FFI_INT64, FFI_INT8, FFI_ISIZE, FFI_OBJECT_HANDLE, FFI_STRING } from './primitives'
 
 const CStruct = RefStruct(ref)
 const CArray = RefArray(ref)
@@ -101,8 +101,8 @@ export const RevocationEntryListStruct = CStruct({
 
 export const NonRevokedIntervalOverrideStruct = CStruct({
   rev_reg_def_id: FFI_STRING,
-  requested_from_ts: FFI_INT32,
-  override_rev_status_list_ts: FFI_INT32
+  requested_from_ts: FFI_INT64,
+  override_rev_status_list_ts: FFI_INT64
 })
 
 export const NonRevokedIntervalOverrideArray = CArray(NonRevokedIntervalOverrideStruct)
@@ -13,14 +13,24 @@ import {
   FFI_STRING_PTR,
   ByteBufferStructPtr,
   FFI_INT8_PTR,
+  FFI_INT64_PTR,
   FFI_VOID,
   I32ListStruct,
-  NonRevokedIntervalOverrideListStruct
+  NonRevokedIntervalOverrideListStruct,
+  FFI_CALLBACK_PTR,
+  FFI_CANCELLATION_HANDLE,
+  FFI_CANCELLATION_HANDLE_PTR,
+  FFI_ISIZE_PTR,
+  FFI_SCOPE_HANDLE,
+  FFI_SCOPE_HANDLE_PTR
 } from '../ffi'
 
 export const nativeBindings = {
   // first element is method return type, second element is list of method argument types
   anoncreds_buffer_free: [FFI_VOID, [ByteBufferStruct]],
+  anoncreds_cancellation_token_cancel: [FFI_ERRORCODE, [FFI_CANCELLATION_HANDLE]],
+  anoncreds_cancellation_token_create: [FFI_ERRORCODE, [FFI_CANCELLATION_HANDLE_PTR]],
+  anoncreds_cancellation_token_free: [FFI_VOID, [FFI_CANCELLATION_HANDLE]],
   anoncreds_create_credential: [
     FFI_ERRORCODE,
     [
@@ -28,6 +38,7 @@ export const nativeBindings = {
       FFI_OBJECT_HANDLE,
       FFI_OBJECT_HANDLE,
       FFI_OBJECT_HANDLE,
+      FFI_OBJECT_HANDLE,
       StringListStruct,
       StringListStruct,
       StringListStruct,
@@ -79,7 +90,22 @@ export const nativeBindings = {
       FFI_OBJECT_HANDLE_PTR
     ]
   ],
-  anoncreds_create_presentation: [
+  anoncreds_create_or_update_revocation_state_with_progress: [
+    FFI_ERRORCODE,
+    [
+      FFI_OBJECT_HANDLE,
+      FFI_OBJECT_HANDLE,
+      FFI_INT64,
+      FFI_STRING,
+      FFI_OBJECT_HANDLE,
+      FFI_OBJECT_HANDLE,
+      FFI_CALLBACK_PTR,
+      FFI_INT64,
+      FFI_CANCELLATION_HANDLE,
+      FFI_OBJECT_HANDLE_PTR
+    ]
+  ],
+  anoncreds_create_presentation_v2: [
     FFI_ERRORCODE,
     [
       FFI_OBJECT_HANDLE,
@@ -109,18 +135,83 @@ export const nativeBindings = {
       FFI_OBJECT_HANDLE_PTR
     ]
   ],
+  anoncreds_create_revocation_registry_def_with_progress: [
+    FFI_ERRORCODE,
+    [
+      FFI_OBJECT_HANDLE,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_INT64,
+      FFI_STRING,
+      FFI_CALLBACK_PTR,
+      FFI_INT64,
+      FFI_CANCELLATION_HANDLE,
+      FFI_OBJECT_HANDLE_PTR,
+      FFI_OBJECT_HANDLE_PTR
+    ]
+  ],
+  anoncreds_create_revocation_registry_def_in_memory: [
+    FFI_ERRORCODE,
+    [
+      FFI_OBJECT_HANDLE,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_INT64,
+      FFI_STRING,
+      FFI_CALLBACK_PTR,
+      FFI_INT64,
+      FFI_CANCELLATION_HANDLE,
+      FFI_OBJECT_HANDLE_PTR,
+      FFI_OBJECT_HANDLE_PTR,
+      ByteBufferStructPtr
+    ]
+  ],
+  anoncreds_create_revocation_registry_def_with_sink: [
+    FFI_ERRORCODE,
+    [
+      FFI_OBJECT_HANDLE,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_STRING,
+      FFI_INT64,
+      FFI_STRING,
+      FFI_CALLBACK_PTR,
+      FFI_INT64,
+      FFI_CALLBACK_PTR,
+      FFI_INT64,
+      FFI_CANCELLATION_HANDLE,
+      FFI_OBJECT_HANDLE_PTR,
+      FFI_OBJECT_HANDLE_PTR
+    ]
+  ],
   anoncreds_create_schema: [
     FFI_ERRORCODE,
     [FFI_STRING, FFI_STRING, FFI_STRING, StringListStruct, FFI_OBJECT_HANDLE_PTR]
   ],
+  anoncreds_credential_definition_get_issuer_id: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING_PTR]],
   anoncreds_credential_get_attribute: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING, FFI_STRING_PTR]],
+  anoncreds_credential_get_attribute_names: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING_PTR]],
+  anoncreds_credential_get_attribute_value: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING, FFI_INT8, FFI_STRING_PTR]],
+  anoncreds_credential_get_summary: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING_PTR]],
   anoncreds_encode_credential_attributes: [FFI_ERRORCODE, [StringListStruct, FFI_STRING_PTR]],
   anoncreds_generate_nonce: [FFI_ERRORCODE, [FFI_STRING_PTR]],
   anoncreds_get_current_error: [FFI_ERRORCODE, [FFI_STRING_PTR]],
+  anoncreds_object_count: [FFI_ERRORCODE, [FFI_STRING, FFI_ISIZE_PTR]],
   anoncreds_object_free: [FFI_VOID, [FFI_OBJECT_HANDLE]],
+  anoncreds_object_free_scope: [FFI_ERRORCODE, [FFI_SCOPE_HANDLE, FFI_ISIZE_PTR]],
+  anoncreds_object_get_counts: [FFI_ERRORCODE, [FFI_STRING_PTR]],
+  anoncreds_object_list_handles: [FFI_ERRORCODE, [FFI_STRING, FFI_STRING_PTR]],
+  anoncreds_object_report_leaks: [FFI_ERRORCODE, [FFI_ISIZE_PTR]],
+  anoncreds_object_scope_begin: [FFI_ERRORCODE, [FFI_SCOPE_HANDLE_PTR]],
+  anoncreds_object_scope_end: [FFI_ERRORCODE, [FFI_SCOPE_HANDLE]],
+  anoncreds_object_set_leak_debug: [FFI_ERRORCODE, [FFI_INT8]],
   anoncreds_string_free: [FFI_VOID, [FFI_STRING_PTR]],
   anoncreds_object_get_json: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, ByteBufferStructPtr]],
   anoncreds_object_get_type_name: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING_PTR]],
+  anoncreds_presentation_get_identifiers: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING_PTR]],
   anoncreds_presentation_request_from_json: [FFI_ERRORCODE, [ByteBufferStruct, FFI_STRING_PTR]],
   anoncreds_process_credential: [
     FFI_ERRORCODE,
@@ -130,8 +221,11 @@ export const nativeBindings = {
     FFI_ERRORCODE,
     [FFI_OBJECT_HANDLE, FFI_STRING, FFI_STRING_PTR]
   ],
+  anoncreds_revocation_status_list_get_timestamp: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_INT64_PTR]],
+  anoncreds_schema_get_attr_names: [FFI_ERRORCODE, [FFI_OBJECT_HANDLE, FFI_STRING_PTR]],
   anoncreds_set_default_logger: [FFI_ERRORCODE, []],
-  anoncreds_verify_presentation: [
+  anoncreds_validate_issuer_id: [FFI_ERRORCODE, [FFI_STRING, StringListStruct]],
+  anoncreds_verify_presentation_v2: [
     FFI_ERRORCODE,
     [
       FFI_OBJECT_HANDLE,
@@ -161,6 +255,22 @@ export const nativeBindings = {
       FFI_OBJECT_HANDLE_PTR
     ]
   ],
+  anoncreds_create_revocation_status_list_with_progress: [
+    FFI_ERRORCODE,
+    [
+      FFI_OBJECT_HANDLE,
+      FFI_STRING,
+      FFI_OBJECT_HANDLE,
+      FFI_OBJECT_HANDLE,
+      FFI_STRING,
+      FFI_INT8,
+      FFI_INT64,
+      FFI_CALLBACK_PTR,
+      FFI_INT64,
+      FFI_CANCELLATION_HANDLE,
+      FFI_OBJECT_HANDLE_PTR
+    ]
+  ],
   anoncreds_update_revocation_status_list_timestamp_only: [
     FFI_ERRORCODE,
     [FFI_INT64, FFI_OBJECT_HANDLE, FFI_OBJECT_HANDLE_PTR]
@@ -103,6 +103,7 @@ describe('API', () => {
     })
 
     const credential = Credential.create({
+      schema,
       credentialDefinition,
       credentialDefinitionPrivate,
       credentialOffer,
@@ -239,6 +240,7 @@ describe('API', () => {
     })
 
     const credential = Credential.create({
+      schema,
       credentialDefinition,
       credentialDefinitionPrivate,
       credentialOffer,
@@ -425,6 +427,7 @@ test('create and verify presentation passing only JSON objects as parameters', (
   })
 
   const credential = Credential.create({
+    schema: schema.toJson(),
     credentialDefinition: credentialDefinition.toJson(),
     credentialDefinitionPrivate: credentialDefinitionPrivate.toJson(),
     credentialOffer: credentialOffer.toJson(),
@@ -132,6 +132,53 @@ describe('bindings', () => {
     )
   })
 
+  test('create revocation registry in memory', () => {
+    const schemaObj = anoncreds.createSchema({
+      name: 'schema-1',
+      issuerId: 'mock:uri',
+      version: '1',
+      attributeNames: ['attr-1']
+    })
+
+    const { credentialDefinition } = anoncreds.createCredentialDefinition({
+      schemaId: 'mock:uri',
+      issuerId: 'mock:uri',
+      schema: schemaObj,
+      signatureType: 'CL',
+      supportRevocation: true,
+      tag: 'TAG'
+    })
+
+    const { revocationRegistryDefinition, tails } = anoncreds.createRevocationRegistryDefinitionInMemory({
+      credentialDefinitionId: 'mock:uri',
+      credentialDefinition,
+      tag: 'default',
+      revocationRegistryType: 'CL_ACCUM',
+      maximumCredentialNumber: 10,
+      tailsLocation: 'https://tails.example/tails'
+    })
+
+    expect(
+      anoncreds.revocationRegistryDefinitionGetAttribute({
+        objectHandle: revocationRegistryDefinition,
+        name: 'tails_location'
+      })
+    ).toEqual('https://tails.example/tails')
+    expect(tails.length).toBeGreaterThan(0)
+
+    const chunks: Uint8Array[] = []
+    anoncreds.createRevocationRegistryDefinitionWithSink({
+      credentialDefinitionId: 'mock:uri',
+      credentialDefinition,
+      tag: 'default',
+      revocationRegistryType: 'CL_ACCUM',
+      maximumCredentialNumber: 10,
+      onTailsChunk: (chunk) => chunks.push(chunk)
+    })
+
+    expect(chunks.reduce((total, chunk) => total + chunk.length, 0)).toEqual(tails.length)
+  })
+
   test('create link secret', () => {
     const linkSecret = anoncreds.createLinkSecret()
     expect(typeof linkSecret).toBe('string')
@@ -287,6 +334,7 @@ describe('bindings', () => {
     })
 
     const credential = anoncreds.createCredential({
+      schema: schemaObj,
       credentialDefinition,
       credentialDefinitionPrivate,
       credentialOffer,
@@ -327,6 +375,19 @@ describe('bindings', () => {
     )
     expect(JSON.parse(credReceivedJson)).toHaveProperty('signature')
     expect(JSON.parse(credReceivedJson)).toHaveProperty('witness')
+
+    expect(anoncreds.credentialGetAttributeNames({ objectHandle: credReceived })).toEqual(['attr-1'])
+    expect(anoncreds.credentialGetAttributeValue({ objectHandle: credReceived, name: 'attr-1' })).toEqual('test')
+    expect(
+      anoncreds.credentialGetAttributeValue({ objectHandle: credReceived, name: 'attr-1', encoded: true })
+    ).toMatch(/^[0-9]+$/)
+    expect(anoncreds.credentialGetSummary({ objectHandle: credReceived })).toEqual({
+      schema_id: 'mock:uri',
+      cred_def_id: 'mock:uri',
+      rev_reg_id: 'mock:uri',
+      rev_reg_index: 9,
+      attrs: { 'attr-1': 'test' }
+    })
   })
 
   test('create and verify presentation', () => {
@@ -422,6 +483,7 @@ describe('bindings', () => {
     })
 
     const credential = anoncreds.createCredential({
+      schema: schemaObj,
       credentialDefinition,
       credentialDefinitionPrivate,
       credentialOffer,
@@ -551,6 +613,7 @@ describe('bindings', () => {
     })
 
     const credential = anoncreds.createCredential({
+      schema: schemaObj,
       credentialDefinition,
       credentialDefinitionPrivate,
       credentialOffer,
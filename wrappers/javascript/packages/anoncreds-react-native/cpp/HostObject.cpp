@@ -30,20 +30,36 @@ FunctionMap AnoncredsTurboModuleHostObject::functionMapping(jsi::Runtime &rt) {
   fMap.insert(std::make_tuple("createRevocationRegistryDefinition",
                               &anoncreds::createRevocationRegistryDefinition));
   fMap.insert(std::make_tuple("createSchema", &anoncreds::createSchema));
+  fMap.insert(std::make_tuple("credentialDefinitionGetIssuerId",
+                              &anoncreds::credentialDefinitionGetIssuerId));
   fMap.insert(std::make_tuple("credentialGetAttribute",
                               &anoncreds::credentialGetAttribute));
+  fMap.insert(std::make_tuple("credentialGetAttributeNames",
+                              &anoncreds::credentialGetAttributeNames));
+  fMap.insert(std::make_tuple("credentialGetAttributeValue",
+                              &anoncreds::credentialGetAttributeValue));
+  fMap.insert(std::make_tuple("credentialGetSummary",
+                              &anoncreds::credentialGetSummary));
   fMap.insert(std::make_tuple("encodeCredentialAttributes",
                               &anoncreds::encodeCredentialAttributes));
   fMap.insert(std::make_tuple("generateNonce", &anoncreds::generateNonce));
   fMap.insert(std::make_tuple("getJson", &anoncreds::getJson));
   fMap.insert(std::make_tuple("getTypeName", &anoncreds::getTypeName));
+  fMap.insert(std::make_tuple("presentationGetIdentifiers",
+                              &anoncreds::presentationGetIdentifiers));
   fMap.insert(
       std::make_tuple("processCredential", &anoncreds::processCredential));
   fMap.insert(
       std::make_tuple("revocationRegistryDefinitionGetAttribute",
                       &anoncreds::revocationRegistryDefinitionGetAttribute));
+  fMap.insert(std::make_tuple("revocationStatusListGetTimestamp",
+                              &anoncreds::revocationStatusListGetTimestamp));
+  fMap.insert(
+      std::make_tuple("schemaGetAttrNames", &anoncreds::schemaGetAttrNames));
   fMap.insert(
       std::make_tuple("setDefaultLogger", &anoncreds::setDefaultLogger));
+  fMap.insert(
+      std::make_tuple("validateIssuerId", &anoncreds::validateIssuerId));
   fMap.insert(
       std::make_tuple("verifyPresentation", &anoncreds::verifyPresentation));
 fMap.insert(std::make_tuple("createRevocationStatusList",
@@ -51,6 +67,39 @@ fMap.insert(std::make_tuple("createRevocationStatusList",
   fMap.insert(std::make_tuple("updateRevocationStatusList",
                               &anoncreds::updateRevocationStatusList));
   fMap.insert(std::make_tuple("objectFree", &anoncreds::objectFree));
+  fMap.insert(std::make_tuple("objectCount", &anoncreds::objectCount));
+  fMap.insert(std::make_tuple("objectGetCounts", &anoncreds::objectGetCounts));
+  fMap.insert(
+      std::make_tuple("objectListHandles", &anoncreds::objectListHandles));
+  fMap.insert(
+      std::make_tuple("objectScopeBegin", &anoncreds::objectScopeBegin));
+  fMap.insert(std::make_tuple("objectScopeEnd", &anoncreds::objectScopeEnd));
+  fMap.insert(std::make_tuple("objectFreeScope", &anoncreds::objectFreeScope));
+  fMap.insert(
+      std::make_tuple("objectSetLeakDebug", &anoncreds::objectSetLeakDebug));
+  fMap.insert(
+      std::make_tuple("objectReportLeaks", &anoncreds::objectReportLeaks));
+  fMap.insert(
+      std::make_tuple("createOrUpdateRevocationStateWithProgress",
+                      &anoncreds::createOrUpdateRevocationStateWithProgress));
+  fMap.insert(
+      std::make_tuple("createRevocationStatusListWithProgress",
+                      &anoncreds::createRevocationStatusListWithProgress));
+  fMap.insert(std::make_tuple(
+      "createRevocationRegistryDefinitionWithProgress",
+      &anoncreds::createRevocationRegistryDefinitionWithProgress));
+  fMap.insert(
+      std::make_tuple("createRevocationRegistryDefinitionInMemory",
+                      &anoncreds::createRevocationRegistryDefinitionInMemory));
+  fMap.insert(
+      std::make_tuple("createRevocationRegistryDefinitionWithSink",
+                      &anoncreds::createRevocationRegistryDefinitionWithSink));
+  fMap.insert(std::make_tuple("cancellationTokenCreate",
+                              &anoncreds::cancellationTokenCreate));
+  fMap.insert(std::make_tuple("cancellationTokenCancel",
+                              &anoncreds::cancellationTokenCancel));
+  fMap.insert(std::make_tuple("cancellationTokenFree",
+                              &anoncreds::cancellationTokenFree));
 
   fMap.insert(std::make_tuple("credentialDefinitionFromJson",
                               &anoncreds::credentialDefinitionFromJson));
@@ -52,6 +52,78 @@ jsi::Value objectFree(jsi::Runtime &rt, jsi::Object options) {
   return createReturnValue(rt, ErrorCode::Success, nullptr);
 };
 
+jsi::Value objectCount(jsi::Runtime &rt, jsi::Object options) {
+  auto typeName = jsiToValue<std::string>(rt, options, "typeName", true);
+
+  size_t out;
+
+  ErrorCode code = anoncreds_object_count(
+      typeName.length() > 0 ? typeName.c_str() : nullptr, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value objectGetCounts(jsi::Runtime &rt, jsi::Object options) {
+  const char *out;
+
+  ErrorCode code = anoncreds_object_get_counts(&out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value objectListHandles(jsi::Runtime &rt, jsi::Object options) {
+  auto typeName = jsiToValue<std::string>(rt, options, "typeName", true);
+
+  const char *out;
+
+  ErrorCode code = anoncreds_object_list_handles(
+      typeName.length() > 0 ? typeName.c_str() : nullptr, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value objectScopeBegin(jsi::Runtime &rt, jsi::Object options) {
+  ScopeHandle out;
+
+  ErrorCode code = anoncreds_object_scope_begin(&out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value objectScopeEnd(jsi::Runtime &rt, jsi::Object options) {
+  auto scope = (ScopeHandle)jsiToValue<int64_t>(rt, options, "scope");
+
+  ErrorCode code = anoncreds_object_scope_end(scope);
+
+  return createReturnValue(rt, code, nullptr);
+};
+
+jsi::Value objectFreeScope(jsi::Runtime &rt, jsi::Object options) {
+  auto scope = (ScopeHandle)jsiToValue<int64_t>(rt, options, "scope");
+
+  size_t out;
+
+  ErrorCode code = anoncreds_object_free_scope(scope, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value objectSetLeakDebug(jsi::Runtime &rt, jsi::Object options) {
+  auto enabled = jsiToValue<int8_t>(rt, options, "enabled");
+
+  ErrorCode code = anoncreds_object_set_leak_debug(enabled);
+
+  return createReturnValue(rt, code, nullptr);
+};
+
+jsi::Value objectReportLeaks(jsi::Runtime &rt, jsi::Object options) {
+  size_t out;
+
+  ErrorCode code = anoncreds_object_report_leaks(&out);
+
+  return createReturnValue(rt, code, &out);
+};
+
 // ===== META =====
 
 jsi::Value createLinkSecret(jsi::Runtime &rt, jsi::Object options) {
@@ -86,6 +158,15 @@ jsi::Value createSchema(jsi::Runtime &rt, jsi::Object options) {
   return createReturnValue(rt, code, &out);
 };
 
+jsi::Value validateIssuerId(jsi::Runtime &rt, jsi::Object options) {
+  auto issuerId = jsiToValue<std::string>(rt, options, "issuerId");
+  auto didMethods = jsiToValue<FfiStrList>(rt, options, "didMethods", true);
+
+  ErrorCode code = anoncreds_validate_issuer_id(issuerId.c_str(), didMethods);
+
+  return createReturnValue(rt, code, nullptr);
+};
+
 jsi::Value createCredentialDefinition(jsi::Runtime &rt, jsi::Object options) {
   auto schemaId = jsiToValue<std::string>(rt, options, "schemaId");
   auto schema = jsiToValue<ObjectHandle>(rt, options, "schema");
@@ -358,7 +439,7 @@ jsi::Value createPresentation(jsi::Runtime &rt, jsi::Object options) {
   auto presentationRequest =
       jsiToValue<ObjectHandle>(rt, options, "presentationRequest");
   auto credentials =
-      jsiToValue<FfiList_FfiCredentialEntry>(rt, options, "credentials");
+      jsiToValue<FfiList_FfiCredentialEntryV2>(rt, options, "credentials");
   auto credentialsProve =
       jsiToValue<FfiList_FfiCredentialProve>(rt, options, "credentialsProve");
   auto selfAttestedNames =
@@ -375,7 +456,7 @@ jsi::Value createPresentation(jsi::Runtime &rt, jsi::Object options) {
 
   ObjectHandle out;
 
-  ErrorCode code = anoncreds_create_presentation(
+  ErrorCode code = anoncreds_create_presentation_v2(
       presentationRequest, credentials, credentialsProve, selfAttestedNames,
       selfAttestedValues, linkSecret.c_str(), schemas, schemaIds,
       credentialDefinitions, credentialDefinitionIds, &out);
@@ -422,12 +503,12 @@ jsi::Value verifyPresentation(jsi::Runtime &rt, jsi::Object options) {
   auto revocationStatusLists = jsiToValue<FfiList_ObjectHandle>(
       rt, options, "revocationStatusLists", true);
   auto nonRevokedIntervalOverrides =
-      jsiToValue<FfiList_FfiNonrevokedIntervalOverride>(
+      jsiToValue<FfiList_FfiNonrevokedIntervalOverrideV2>(
           rt, options, "nonRevokedIntervalOverrides", true);
 
   int8_t out;
 
-  ErrorCode code = anoncreds_verify_presentation(
+  ErrorCode code = anoncreds_verify_presentation_v2(
       presentation, presentationRequest, schemas, schemaIds,
       credentialDefinitions, credentialDefinitionIds,
       revocationRegistryDefinitions, revocationRegistryDefinitionIds,
@@ -451,6 +532,7 @@ jsi::Value verifyPresentation(jsi::Runtime &rt, jsi::Object options) {
 // ===== CREDENTIALS =====
 
 jsi::Value createCredential(jsi::Runtime &rt, jsi::Object options) {
+  auto schema = jsiToValue<ObjectHandle>(rt, options, "schema");
   auto credentialDefinition =
       jsiToValue<ObjectHandle>(rt, options, "credentialDefinition");
   auto credentialDefinitionPrivate =
@@ -470,8 +552,8 @@ jsi::Value createCredential(jsi::Runtime &rt, jsi::Object options) {
   ObjectHandle out;
 
   ErrorCode code = anoncreds_create_credential(
-      credentialDefinition, credentialDefinitionPrivate, credentialOffer,
-      credentialRequest, attributeNames, attributeRawValues,
+      schema, credentialDefinition, credentialDefinitionPrivate,
+      credentialOffer, credentialRequest, attributeNames, attributeRawValues,
       attributeEncodedValues, revocation.reg_def ? &revocation : 0, &out);
 
   return createReturnValue(rt, code, &out);
@@ -526,6 +608,82 @@ jsi::Value credentialGetAttribute(jsi::Runtime &rt, jsi::Object options) {
   return createReturnValue(rt, code, &out);
 };
 
+jsi::Value credentialGetAttributeNames(jsi::Runtime &rt, jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+
+  const char *out;
+
+  ErrorCode code = anoncreds_credential_get_attribute_names(handle, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value credentialGetAttributeValue(jsi::Runtime &rt, jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+  auto name = jsiToValue<std::string>(rt, options, "name");
+  auto encoded = jsiToValue<int8_t>(rt, options, "encoded", true);
+
+  const char *out;
+
+  ErrorCode code = anoncreds_credential_get_attribute_value(
+      handle, name.c_str(), encoded, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value credentialGetSummary(jsi::Runtime &rt, jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+
+  const char *out;
+
+  ErrorCode code = anoncreds_credential_get_summary(handle, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value schemaGetAttrNames(jsi::Runtime &rt, jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+
+  const char *out;
+
+  ErrorCode code = anoncreds_schema_get_attr_names(handle, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value credentialDefinitionGetIssuerId(jsi::Runtime &rt,
+                                           jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+
+  const char *out;
+
+  ErrorCode code = anoncreds_credential_definition_get_issuer_id(handle, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value revocationStatusListGetTimestamp(jsi::Runtime &rt,
+                                            jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+
+  int64_t out;
+
+  ErrorCode code =
+      anoncreds_revocation_status_list_get_timestamp(handle, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value presentationGetIdentifiers(jsi::Runtime &rt, jsi::Object options) {
+  auto handle = jsiToValue<ObjectHandle>(rt, options, "objectHandle");
+
+  const char *out;
+
+  ErrorCode code = anoncreds_presentation_get_identifiers(handle, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
 jsi::Value encodeCredentialAttributes(jsi::Runtime &rt, jsi::Object options) {
   auto attributeRawValues =
       jsiToValue<FfiList_FfiStr>(rt, options, "attributeRawValues");
@@ -682,4 +840,188 @@ jsi::Value revocationRegistryDefinitionGetAttribute(jsi::Runtime &rt,
   return createReturnValue(rt, code, &out);
 };
 
+// ===== Progress =====
+
+jsi::Value createOrUpdateRevocationStateWithProgress(jsi::Runtime &rt,
+                                                     jsi::Object options) {
+  auto revocationRegistryDefinition =
+      jsiToValue<ObjectHandle>(rt, options, "revocationRegistryDefinition");
+  auto revocationStatusList =
+      jsiToValue<ObjectHandle>(rt, options, "revocationStatusList");
+  auto revocationRegistryIndex =
+      jsiToValue<int64_t>(rt, options, "revocationRegistryIndex");
+  auto tailsPath = jsiToValue<std::string>(rt, options, "tailsPath");
+  auto oldRevocationState =
+      jsiToValue<ObjectHandle>(rt, options, "oldRevocationState", true);
+  auto oldRevocationStatusList =
+      jsiToValue<ObjectHandle>(rt, options, "oldRevocationStatusList", true);
+  auto onProgress =
+      jsiToValue<std::unique_ptr<State>>(rt, options, "onProgress", true);
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken", true);
+
+  ObjectHandle out;
+
+  ErrorCode code = anoncreds_create_or_update_revocation_state_with_progress(
+      revocationRegistryDefinition, revocationStatusList,
+      revocationRegistryIndex, tailsPath.c_str(), oldRevocationState,
+      oldRevocationStatusList, onProgress ? progressCallback : nullptr,
+      reinterpret_cast<int64_t>(onProgress.get()), cancellationToken, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value createRevocationStatusListWithProgress(jsi::Runtime &rt,
+                                                  jsi::Object options) {
+  auto credentialDefinition =
+      jsiToValue<ObjectHandle>(rt, options, "credentialDefinition");
+  auto revocationRegistryDefinitionId =
+      jsiToValue<std::string>(rt, options, "revocationRegistryDefinitionId");
+  auto revocationRegistryDefinition =
+      jsiToValue<ObjectHandle>(rt, options, "revocationRegistryDefinition");
+  auto revocationRegistryDefinitionPrivate = jsiToValue<ObjectHandle>(
+      rt, options, "revocationRegistryDefinitionPrivate");
+  auto issuerId = jsiToValue<std::string>(rt, options, "issuerId");
+  auto issuanceByDefault = jsiToValue<int8_t>(rt, options, "issuanceByDefault");
+  auto timestamp = jsiToValue<int64_t>(rt, options, "timestamp");
+  auto onProgress =
+      jsiToValue<std::unique_ptr<State>>(rt, options, "onProgress", true);
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken", true);
+
+  ObjectHandle out;
+
+  ErrorCode code = anoncreds_create_revocation_status_list_with_progress(
+      credentialDefinition, revocationRegistryDefinitionId.c_str(),
+      revocationRegistryDefinition, revocationRegistryDefinitionPrivate,
+      issuerId.c_str(), issuanceByDefault, timestamp,
+      onProgress ? progressCallback : nullptr,
+      reinterpret_cast<int64_t>(onProgress.get()), cancellationToken, &out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value createRevocationRegistryDefinitionWithProgress(jsi::Runtime &rt,
+                                                          jsi::Object options) {
+  auto credentialDefinition =
+      jsiToValue<ObjectHandle>(rt, options, "credentialDefinition");
+  auto credentialDefinitionId =
+      jsiToValue<std::string>(rt, options, "credentialDefinitionId");
+  auto issuerId = jsiToValue<std::string>(rt, options, "issuerId");
+  auto tag = jsiToValue<std::string>(rt, options, "tag");
+  auto revocationRegistryType =
+      jsiToValue<std::string>(rt, options, "revocationRegistryType");
+  auto maxCredNum = jsiToValue<int64_t>(rt, options, "maximumCredentialNumber");
+  auto tailsDirPath =
+      jsiToValue<std::string>(rt, options, "tailsDirectoryPath", true);
+  auto onProgress =
+      jsiToValue<std::unique_ptr<State>>(rt, options, "onProgress", true);
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken", true);
+
+  RevocationRegistryDefinitionReturn out;
+
+  ErrorCode code = anoncreds_create_revocation_registry_def_with_progress(
+      credentialDefinition, credentialDefinitionId.c_str(), issuerId.c_str(),
+      tag.c_str(), revocationRegistryType.c_str(), maxCredNum,
+      tailsDirPath.length() > 0 ? tailsDirPath.c_str() : nullptr,
+      onProgress ? progressCallback : nullptr,
+      reinterpret_cast<int64_t>(onProgress.get()), cancellationToken,
+      &out.revocationRegistryDefinition,
+      &out.revocationRegistryDefinitionPrivate);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value createRevocationRegistryDefinitionInMemory(jsi::Runtime &rt,
+                                                      jsi::Object options) {
+  auto credentialDefinition =
+      jsiToValue<ObjectHandle>(rt, options, "credentialDefinition");
+  auto credentialDefinitionId =
+      jsiToValue<std::string>(rt, options, "credentialDefinitionId");
+  auto tag = jsiToValue<std::string>(rt, options, "tag");
+  auto revocationRegistryType =
+      jsiToValue<std::string>(rt, options, "revocationRegistryType");
+  auto maxCredNum = jsiToValue<int64_t>(rt, options, "maximumCredentialNumber");
+  auto tailsLocation =
+      jsiToValue<std::string>(rt, options, "tailsLocation", true);
+  auto onProgress =
+      jsiToValue<std::unique_ptr<State>>(rt, options, "onProgress", true);
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken", true);
+
+  RevocationRegistryDefinitionInMemoryReturn out;
+
+  ErrorCode code = anoncreds_create_revocation_registry_def_in_memory(
+      credentialDefinition, credentialDefinitionId.c_str(), tag.c_str(),
+      revocationRegistryType.c_str(), maxCredNum,
+      tailsLocation.length() > 0 ? tailsLocation.c_str() : nullptr,
+      onProgress ? progressCallback : nullptr,
+      reinterpret_cast<int64_t>(onProgress.get()), cancellationToken,
+      &out.revocationRegistryDefinition,
+      &out.revocationRegistryDefinitionPrivate, &out.tails);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value createRevocationRegistryDefinitionWithSink(jsi::Runtime &rt,
+                                                      jsi::Object options) {
+  auto credentialDefinition =
+      jsiToValue<ObjectHandle>(rt, options, "credentialDefinition");
+  auto credentialDefinitionId =
+      jsiToValue<std::string>(rt, options, "credentialDefinitionId");
+  auto tag = jsiToValue<std::string>(rt, options, "tag");
+  auto revocationRegistryType =
+      jsiToValue<std::string>(rt, options, "revocationRegistryType");
+  auto maxCredNum = jsiToValue<int64_t>(rt, options, "maximumCredentialNumber");
+  auto tailsLocation =
+      jsiToValue<std::string>(rt, options, "tailsLocation", true);
+  auto onTailsChunk =
+      jsiToValue<std::unique_ptr<State>>(rt, options, "onTailsChunk");
+  auto onProgress =
+      jsiToValue<std::unique_ptr<State>>(rt, options, "onProgress", true);
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken", true);
+
+  RevocationRegistryDefinitionReturn out;
+
+  ErrorCode code = anoncreds_create_revocation_registry_def_with_sink(
+      credentialDefinition, credentialDefinitionId.c_str(), tag.c_str(),
+      revocationRegistryType.c_str(), maxCredNum,
+      tailsLocation.length() > 0 ? tailsLocation.c_str() : nullptr,
+      tailsWriteCallback, reinterpret_cast<int64_t>(onTailsChunk.get()),
+      onProgress ? progressCallback : nullptr,
+      reinterpret_cast<int64_t>(onProgress.get()), cancellationToken,
+      &out.revocationRegistryDefinition,
+      &out.revocationRegistryDefinitionPrivate);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value cancellationTokenCreate(jsi::Runtime &rt, jsi::Object options) {
+  CancellationHandle out;
+
+  ErrorCode code = anoncreds_cancellation_token_create(&out);
+
+  return createReturnValue(rt, code, &out);
+};
+
+jsi::Value cancellationTokenCancel(jsi::Runtime &rt, jsi::Object options) {
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken");
+
+  ErrorCode code = anoncreds_cancellation_token_cancel(cancellationToken);
+
+  return createReturnValue(rt, code, nullptr);
+};
+
+jsi::Value cancellationTokenFree(jsi::Runtime &rt, jsi::Object options) {
+  auto cancellationToken = (CancellationHandle)jsiToValue<int64_t>(
+      rt, options, "cancellationToken");
+
+  anoncreds_cancellation_token_free(cancellationToken);
+
+  return createReturnValue(rt, ErrorCode::Success, nullptr);
+};
+
 } // namespace anoncreds
@@ -25,6 +25,12 @@ struct RevocationRegistryDefinitionReturn {
   ObjectHandle revocationRegistryDefinitionPrivate;
 };
 
+struct RevocationRegistryDefinitionInMemoryReturn {
+  ObjectHandle revocationRegistryDefinition;
+  ObjectHandle revocationRegistryDefinitionPrivate;
+  ByteBuffer tails;
+};
+
 // General
 jsi::Value version(jsi::Runtime &rt, jsi::Object options);
 jsi::Value getCurrentError(jsi::Runtime &rt, jsi::Object options);
@@ -32,6 +38,14 @@ jsi::Value getJson(jsi::Runtime &rt, jsi::Object options);
 jsi::Value getTypeName(jsi::Runtime &rt, jsi::Object options);
 jsi::Value setDefaultLogger(jsi::Runtime &rt, jsi::Object options);
 jsi::Value objectFree(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectCount(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectGetCounts(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectListHandles(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectScopeBegin(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectScopeEnd(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectFreeScope(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectSetLeakDebug(jsi::Runtime &rt, jsi::Object options);
+jsi::Value objectReportLeaks(jsi::Runtime &rt, jsi::Object options);
 
 // Meta
 jsi::Value createLinkSecret(jsi::Runtime &rt, jsi::Object options);
@@ -39,6 +53,7 @@ jsi::Value generateNonce(jsi::Runtime &rt, jsi::Object options);
 
 // Anoncreds Objects
 jsi::Value createSchema(jsi::Runtime &rt, jsi::Object options);
+jsi::Value validateIssuerId(jsi::Runtime &rt, jsi::Object options);
 jsi::Value createCredentialDefinition(jsi::Runtime &rt, jsi::Object options);
 
 // Anoncreds Objects from JSON
@@ -62,6 +77,14 @@ jsi::Value credentialDefinitionPrivateFromJson(jsi::Runtime &rt,
                                                jsi::Object options);
 jsi::Value keyCorrectnessProofFromJson(jsi::Runtime &rt, jsi::Object options);
 
+// Object fields
+jsi::Value schemaGetAttrNames(jsi::Runtime &rt, jsi::Object options);
+jsi::Value credentialDefinitionGetIssuerId(jsi::Runtime &rt,
+                                           jsi::Object options);
+jsi::Value revocationStatusListGetTimestamp(jsi::Runtime &rt,
+                                            jsi::Object options);
+jsi::Value presentationGetIdentifiers(jsi::Runtime &rt, jsi::Object options);
+
 // Proofs
 jsi::Value createPresentation(jsi::Runtime &rt, jsi::Object options);
 jsi::Value verifyPresentation(jsi::Runtime &rt, jsi::Object options);
@@ -71,6 +94,9 @@ jsi::Value createCredential(jsi::Runtime &rt, jsi::Object options);
 jsi::Value createCredentialOffer(jsi::Runtime &rt, jsi::Object options);
 jsi::Value createCredentialRequest(jsi::Runtime &rt, jsi::Object options);
 jsi::Value credentialGetAttribute(jsi::Runtime &rt, jsi::Object options);
+jsi::Value credentialGetAttributeNames(jsi::Runtime &rt, jsi::Object options);
+jsi::Value credentialGetAttributeValue(jsi::Runtime &rt, jsi::Object options);
+jsi::Value credentialGetSummary(jsi::Runtime &rt, jsi::Object options);
 jsi::Value encodeCredentialAttributes(jsi::Runtime &rt, jsi::Object options);
 jsi::Value processCredential(jsi::Runtime &rt, jsi::Object options);
 
@@ -85,4 +111,19 @@ jsi::Value createRevocationRegistryDefinition(jsi::Runtime &rt,
 jsi::Value revocationRegistryDefinitionGetAttribute(jsi::Runtime &rt,
                                                     jsi::Object options);
 
+// Progress
+jsi::Value createOrUpdateRevocationStateWithProgress(jsi::Runtime &rt,
+                                                     jsi::Object options);
+jsi::Value createRevocationStatusListWithProgress(jsi::Runtime &rt,
+                                                  jsi::Object options);
+jsi::Value createRevocationRegistryDefinitionWithProgress(jsi::Runtime &rt,
+                                                          jsi::Object options);
+jsi::Value createRevocationRegistryDefinitionInMemory(jsi::Runtime &rt,
+                                                      jsi::Object options);
+jsi::Value createRevocationRegistryDefinitionWithSink(jsi::Runtime &rt,
+                                                      jsi::Object options);
+jsi::Value cancellationTokenCreate(jsi::Runtime &rt, jsi::Object options);
+jsi::Value cancellationTokenCancel(jsi::Runtime &rt, jsi::Object options);
+jsi::Value cancellationTokenFree(jsi::Runtime &rt, jsi::Object options);
+
 } // namespace anoncreds
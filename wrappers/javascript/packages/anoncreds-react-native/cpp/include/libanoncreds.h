@@ -26,6 +26,7 @@ enum ErrorCode
   InvalidUserRevocId = 6,
   ProofRejected = 7,
   RevocationRegistryFull = 8,
+  Cancelled = 9,
 };
 #ifndef __cplusplus
 typedef size_t ErrorCode;
@@ -119,6 +120,15 @@ typedef struct ByteBuffer {
 
 typedef size_t ObjectHandle;
 
+typedef size_t ScopeHandle;
+
+typedef size_t CancellationHandle;
+
+/**
+ * Called with the callback id, the number of completed steps and the total number of steps
+ */
+typedef void (*ProgressCallback)(int64_t cb_id, uint64_t done, uint64_t total);
+
 /**
  * `FfiStr<'a>` is a safe (`#[repr(transparent)]`) wrapper around a
  * nul-terminated `*const c_char` (e.g. a C string). Conceptually, it is
@@ -180,6 +190,9 @@ typedef struct FfiCredRevInfo {
   int64_t reg_idx;
 } FfiCredRevInfo;
 
+/**
+ * Deprecated in favour of [`FfiCredentialEntryV2`], which has a 64-bit timestamp
+ */
 typedef struct FfiCredentialEntry {
   ObjectHandle credential;
   int32_t timestamp;
@@ -191,6 +204,20 @@ typedef struct FfiList_FfiCredentialEntry {
   const struct FfiCredentialEntry *data;
 } FfiList_FfiCredentialEntry;
 
+typedef struct FfiCredentialEntryV2 {
+  ObjectHandle credential;
+  /**
+   * Timestamp of the revocation state, negative when there is none
+   */
+  int64_t timestamp;
+  ObjectHandle rev_state;
+} FfiCredentialEntryV2;
+
+typedef struct FfiList_FfiCredentialEntryV2 {
+  size_t count;
+  const struct FfiCredentialEntryV2 *data;
+} FfiList_FfiCredentialEntryV2;
+
 typedef struct FfiCredentialProve {
   int64_t entry_idx;
   FfiStr referent;
@@ -233,16 +260,30 @@ typedef struct FfiList_i32 {
  * List100.
  *
  */
-typedef struct FfiNonrevokedIntervalOverride {
+typedef struct FfiNonrevokedIntervalOverrideV2 {
   FfiStr rev_reg_def_id;
   /**
    * Timestamp in the `PresentationRequest`
    */
-  int32_t requested_from_ts;
+  int64_t requested_from_ts;
   /**
    * Timestamp from which verifier accepts,
    * should be less than `req_timestamp`
    */
+  int64_t override_rev_status_list_ts;
+} FfiNonrevokedIntervalOverrideV2;
+
+typedef struct FfiList_FfiNonrevokedIntervalOverrideV2 {
+  size_t count;
+  const struct FfiNonrevokedIntervalOverrideV2 *data;
+} FfiList_FfiNonrevokedIntervalOverrideV2;
+
+/**
+ * Deprecated in favour of [`FfiNonrevokedIntervalOverrideV2`], which has 64-bit timestamps
+ */
+typedef struct FfiNonrevokedIntervalOverride {
+  FfiStr rev_reg_def_id;
+  int32_t requested_from_ts;
   int32_t override_rev_status_list_ts;
 } FfiNonrevokedIntervalOverride;
 
@@ -251,13 +292,28 @@ typedef struct FfiList_FfiNonrevokedIntervalOverride {
   const struct FfiNonrevokedIntervalOverride *data;
 } FfiList_FfiNonrevokedIntervalOverride;
 
+/**
+ * Called with the callback id and a chunk of the tails, returns zero when the chunk was written
+ */
+typedef int8_t (*TailsWriteCallback)(int64_t cb_id, const uint8_t *data, size_t len);
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
 
 void anoncreds_buffer_free(struct ByteBuffer buffer);
 
-ErrorCode anoncreds_create_credential(ObjectHandle cred_def,
+/**
+ * Cancel the operations observing the token. Can be called from any thread.
+ */
+ErrorCode anoncreds_cancellation_token_cancel(CancellationHandle handle);
+
+ErrorCode anoncreds_cancellation_token_create(CancellationHandle *result_p);
+
+void anoncreds_cancellation_token_free(CancellationHandle handle);
+
+ErrorCode anoncreds_create_credential(ObjectHandle schema,
+                                      ObjectHandle cred_def,
                                       ObjectHandle cred_def_private,
                                       ObjectHandle cred_offer,
                                       ObjectHandle cred_request,
@@ -301,6 +357,20 @@ ErrorCode anoncreds_create_or_update_revocation_state(ObjectHandle rev_reg_def,
                                                       ObjectHandle old_rev_status_list,
                                                       ObjectHandle *rev_state_p);
 
+ErrorCode anoncreds_create_or_update_revocation_state_with_progress(ObjectHandle rev_reg_def,
+                                                                    ObjectHandle rev_status_list,
+                                                                    int64_t rev_reg_index,
+                                                                    FfiStr tails_path,
+                                                                    ObjectHandle rev_state,
+                                                                    ObjectHandle old_rev_status_list,
+                                                                    ProgressCallback progress_cb,
+                                                                    int64_t progress_cb_id,
+                                                                    CancellationHandle cancellation,
+                                                                    ObjectHandle *rev_state_p);
+
+/**
+ * Deprecated in favour of [`anoncreds_create_presentation_v2`], which takes 64-bit timestamps
+ */
 ErrorCode anoncreds_create_presentation(ObjectHandle pres_req,
                                         struct FfiList_FfiCredentialEntry credentials,
                                         struct FfiList_FfiCredentialProve credentials_prove,
@@ -313,6 +383,18 @@ ErrorCode anoncreds_create_presentation(ObjectHandle pres_req,
                                         FfiStrList cred_def_ids,
                                         ObjectHandle *presentation_p);
 
+ErrorCode anoncreds_create_presentation_v2(ObjectHandle pres_req,
+                                           struct FfiList_FfiCredentialEntryV2 credentials,
+                                           struct FfiList_FfiCredentialProve credentials_prove,
+                                           FfiStrList self_attest_names,
+                                           FfiStrList self_attest_values,
+                                           FfiStr link_secret,
+                                           struct FfiList_ObjectHandle schemas,
+                                           FfiStrList schema_ids,
+                                           struct FfiList_ObjectHandle cred_defs,
+                                           FfiStrList cred_def_ids,
+                                           ObjectHandle *presentation_p);
+
 ErrorCode anoncreds_create_revocation_registry_def(ObjectHandle cred_def,
                                                    FfiStr cred_def_id,
                                                    FfiStr _issuer_id,
@@ -323,6 +405,57 @@ ErrorCode anoncreds_create_revocation_registry_def(ObjectHandle cred_def,
                                                    ObjectHandle *reg_def_p,
                                                    ObjectHandle *reg_def_private_p);
 
+/**
+ * Create a revocation registry definition, returning the tails in `tails_p` instead of writing
+ * them to a file
+ *
+ * The tails location defaults to the tails hash.
+ */
+ErrorCode anoncreds_create_revocation_registry_def_in_memory(ObjectHandle cred_def,
+                                                             FfiStr cred_def_id,
+                                                             FfiStr tag,
+                                                             FfiStr rev_reg_type,
+                                                             int64_t max_cred_num,
+                                                             FfiStr tails_location,
+                                                             ProgressCallback progress_cb,
+                                                             int64_t progress_cb_id,
+                                                             CancellationHandle cancellation,
+                                                             ObjectHandle *reg_def_p,
+                                                             ObjectHandle *reg_def_private_p,
+                                                             struct ByteBuffer *tails_p);
+
+ErrorCode anoncreds_create_revocation_registry_def_with_progress(ObjectHandle cred_def,
+                                                                 FfiStr cred_def_id,
+                                                                 FfiStr _issuer_id,
+                                                                 FfiStr tag,
+                                                                 FfiStr rev_reg_type,
+                                                                 int64_t max_cred_num,
+                                                                 FfiStr tails_dir_path,
+                                                                 ProgressCallback progress_cb,
+                                                                 int64_t progress_cb_id,
+                                                                 CancellationHandle cancellation,
+                                                                 ObjectHandle *reg_def_p,
+                                                                 ObjectHandle *reg_def_private_p);
+
+/**
+ * Create a revocation registry definition, streaming the tails to `write_cb`
+ *
+ * The tails location defaults to the tails hash.
+ */
+ErrorCode anoncreds_create_revocation_registry_def_with_sink(ObjectHandle cred_def,
+                                                             FfiStr cred_def_id,
+                                                             FfiStr tag,
+                                                             FfiStr rev_reg_type,
+                                                             int64_t max_cred_num,
+                                                             FfiStr tails_location,
+                                                             TailsWriteCallback write_cb,
+                                                             int64_t write_cb_id,
+                                                             ProgressCallback progress_cb,
+                                                             int64_t progress_cb_id,
+                                                             CancellationHandle cancellation,
+                                                             ObjectHandle *reg_def_p,
+                                                             ObjectHandle *reg_def_private_p);
+
 ErrorCode anoncreds_create_revocation_status_list(ObjectHandle cred_def,
                                                   FfiStr rev_reg_def_id,
                                                   ObjectHandle rev_reg_def,
@@ -332,27 +465,89 @@ ErrorCode anoncreds_create_revocation_status_list(ObjectHandle cred_def,
                                                   int64_t timestamp,
                                                   ObjectHandle *rev_status_list_p);
 
+ErrorCode anoncreds_create_revocation_status_list_with_progress(ObjectHandle cred_def,
+                                                                FfiStr rev_reg_def_id,
+                                                                ObjectHandle rev_reg_def,
+                                                                ObjectHandle reg_rev_priv,
+                                                                FfiStr _issuer_id,
+                                                                int8_t issuance_by_default,
+                                                                int64_t timestamp,
+                                                                ProgressCallback progress_cb,
+                                                                int64_t progress_cb_id,
+                                                                CancellationHandle cancellation,
+                                                                ObjectHandle *rev_status_list_p);
+
 ErrorCode anoncreds_create_schema(FfiStr schema_name,
                                   FfiStr schema_version,
                                   FfiStr issuer_id,
                                   FfiStrList attr_names,
                                   ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_credential_definition_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_credential_definition_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+/**
+ * Get the issuer identifier of a credential definition
+ */
+ErrorCode anoncreds_credential_definition_get_issuer_id(ObjectHandle handle, const char **result_p);
+
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_credential_definition_private_from_cbor(struct ByteBuffer cbor,
+                                                            ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_credential_definition_private_from_json(struct ByteBuffer json,
                                                             ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_credential_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_credential_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
 ErrorCode anoncreds_credential_get_attribute(ObjectHandle handle,
                                              FfiStr name,
                                              const char **result_p);
 
+/**
+ * Get the attribute names of a credential as a JSON array
+ */
+ErrorCode anoncreds_credential_get_attribute_names(ObjectHandle handle, const char **result_p);
+
+/**
+ * Get the raw value of a credential attribute, or the encoded value when `encoded` is non-zero
+ */
+ErrorCode anoncreds_credential_get_attribute_value(ObjectHandle handle,
+                                                   FfiStr name,
+                                                   int8_t encoded,
+                                                   const char **result_p);
+
+/**
+ * Get a JSON summary of a credential with its identifiers and raw attribute values, omitting
+ * the signature
+ */
+ErrorCode anoncreds_credential_get_summary(ObjectHandle handle, const char **result_p);
+
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_credential_offer_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_credential_offer_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_credential_request_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_credential_request_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_credential_request_metadata_from_cbor(struct ByteBuffer cbor,
+                                                          ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_credential_request_metadata_from_json(struct ByteBuffer json,
                                                           ObjectHandle *result_p);
 
@@ -362,16 +557,100 @@ ErrorCode anoncreds_generate_nonce(const char **nonce_p);
 
 ErrorCode anoncreds_get_current_error(const char **error_json_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_key_correctness_proof_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_key_correctness_proof_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+/**
+ * Count the live objects of a type, or of all types when `type_name` is null
+ */
+ErrorCode anoncreds_object_count(FfiStr type_name, size_t *result_p);
+
 void anoncreds_object_free(ObjectHandle handle);
 
+/**
+ * Free all objects created in a scope, returning the number of freed objects
+ */
+ErrorCode anoncreds_object_free_scope(ScopeHandle scope, size_t *result_p);
+
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_object_get_cbor(ObjectHandle handle, struct ByteBuffer *result_p);
+#endif
+
+/**
+ * Get the number of live objects by type name as a JSON object
+ */
+ErrorCode anoncreds_object_get_counts(const char **result_p);
+
+/**
+ * Get a field of an object by JSON pointer as a JSON string, or null when it is not present
+ */
+ErrorCode anoncreds_object_get_field(ObjectHandle handle, FfiStr path, const char **result_p);
+
+/**
+ * Get an integer field of an object by JSON pointer
+ */
+ErrorCode anoncreds_object_get_field_i64(ObjectHandle handle, FfiStr path, int64_t *result_p);
+
+/**
+ * Get a string field of an object by JSON pointer, or null when it is not present or null
+ */
+ErrorCode anoncreds_object_get_field_str(ObjectHandle handle, FfiStr path, const char **result_p);
+
 ErrorCode anoncreds_object_get_json(ObjectHandle handle, struct ByteBuffer *result_p);
 
 ErrorCode anoncreds_object_get_type_name(ObjectHandle handle, const char **result_p);
 
+/**
+ * List the live objects of a type, or of all types when `type_name` is null, as a JSON array
+ * of `{"handle", "typeName", "scope"}` entries
+ */
+ErrorCode anoncreds_object_list_handles(FfiStr type_name, const char **result_p);
+
+/**
+ * Report the objects left alive, to be called on shutdown. Every live object is logged as a
+ * warning when leak debugging is enabled.
+ */
+ErrorCode anoncreds_object_report_leaks(size_t *result_p);
+
+/**
+ * Open a scope on the calling thread. Objects created on this thread until the scope is ended
+ * belong to it and can be released together with [`anoncreds_object_free_scope`].
+ */
+ErrorCode anoncreds_object_scope_begin(ScopeHandle *result_p);
+
+ErrorCode anoncreds_object_scope_end(ScopeHandle scope);
+
+/**
+ * Enable or disable logging of object lifetimes
+ */
+ErrorCode anoncreds_object_set_leak_debug(int8_t enabled);
+
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_presentation_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_presentation_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+/**
+ * Get the identifiers of a presentation as a JSON array
+ */
+ErrorCode anoncreds_presentation_get_identifiers(ObjectHandle handle, const char **result_p);
+
+/**
+ * Get the optional referents of a presentation request left out of a presentation as a JSON
+ * array
+ */
+ErrorCode anoncreds_presentation_get_omitted_referents(ObjectHandle presentation,
+                                                       ObjectHandle pres_req,
+                                                       const char **result_p);
+
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_presentation_request_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_presentation_request_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
 ErrorCode anoncreds_process_credential(ObjectHandle cred,
@@ -381,6 +660,11 @@ ErrorCode anoncreds_process_credential(ObjectHandle cred,
                                        ObjectHandle rev_reg_def,
                                        ObjectHandle *cred_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_revocation_registry_definition_from_cbor(struct ByteBuffer cbor,
+                                                             ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_revocation_registry_definition_from_json(struct ByteBuffer json,
                                                              ObjectHandle *result_p);
 
@@ -388,18 +672,52 @@ ErrorCode anoncreds_revocation_registry_definition_get_attribute(ObjectHandle ha
                                                                  FfiStr name,
                                                                  const char **result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_revocation_registry_definition_private_from_cbor(struct ByteBuffer cbor,
+                                                                     ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_revocation_registry_definition_private_from_json(struct ByteBuffer json,
                                                                      ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_revocation_registry_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_revocation_registry_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_revocation_state_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_revocation_state_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_revocation_status_list_from_cbor(struct ByteBuffer cbor,
+                                                     ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_revocation_status_list_from_json(struct ByteBuffer json,
                                                      ObjectHandle *result_p);
 
+/**
+ * Get the timestamp of a revocation status list
+ *
+ * Fails when the status list has not been given a timestamp.
+ */
+ErrorCode anoncreds_revocation_status_list_get_timestamp(ObjectHandle handle, int64_t *result_p);
+
+#if defined(ANONCREDS_CBOR)
+ErrorCode anoncreds_schema_from_cbor(struct ByteBuffer cbor, ObjectHandle *result_p);
+#endif
+
 ErrorCode anoncreds_schema_from_json(struct ByteBuffer json, ObjectHandle *result_p);
 
+/**
+ * Get the attribute names of a schema as a JSON array
+ */
+ErrorCode anoncreds_schema_get_attr_names(ObjectHandle handle, const char **result_p);
+
 ErrorCode anoncreds_set_default_logger(void);
 
 /**
@@ -428,6 +746,15 @@ ErrorCode anoncreds_update_revocation_status_list_timestamp_only(int64_t timesta
                                                                  ObjectHandle rev_current_list,
                                                                  ObjectHandle *rev_status_list_p);
 
+/**
+ * Strictly validate an issuer identifier, requiring DIDs to use one of `did_methods` unless the
+ * list is empty
+ */
+ErrorCode anoncreds_validate_issuer_id(FfiStr issuer_id, FfiStrList did_methods);
+
+/**
+ * Deprecated in favour of [`anoncreds_verify_presentation_v2`], which takes 64-bit timestamps
+ */
 ErrorCode anoncreds_verify_presentation(ObjectHandle presentation,
                                         ObjectHandle pres_req,
                                         struct FfiList_ObjectHandle schemas,
@@ -440,6 +767,18 @@ ErrorCode anoncreds_verify_presentation(ObjectHandle presentation,
                                         struct FfiList_FfiNonrevokedIntervalOverride nonrevoked_interval_override,
                                         int8_t *result_p);
 
+ErrorCode anoncreds_verify_presentation_v2(ObjectHandle presentation,
+                                           ObjectHandle pres_req,
+                                           struct FfiList_ObjectHandle schemas,
+                                           FfiStrList schema_ids,
+                                           struct FfiList_ObjectHandle cred_defs,
+                                           FfiStrList cred_def_ids,
+                                           struct FfiList_ObjectHandle rev_reg_defs,
+                                           FfiStrList rev_reg_def_ids,
+                                           struct FfiList_ObjectHandle rev_status_list,
+                                           struct FfiList_FfiNonrevokedIntervalOverrideV2 nonrevoked_interval_override,
+                                           int8_t *result_p);
+
 char *anoncreds_version(void);
 
 #ifdef __cplusplus
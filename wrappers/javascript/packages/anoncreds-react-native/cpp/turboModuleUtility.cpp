@@ -1,3 +1,4 @@
+#include <cstring>
 #include <vector>
 
 #include "turboModuleUtility.h"
@@ -24,6 +25,35 @@ void assertValueIsObject(jsi::Runtime &rt, const jsi::Value *val) {
   val->asObject(rt);
 }
 
+void progressCallback(int64_t cb_id, uint64_t done, uint64_t total) {
+  State *state = reinterpret_cast<State *>(cb_id);
+  state->cb.call(*state->rt, jsi::Value(double(done)),
+                 jsi::Value(double(total)));
+}
+
+int8_t tailsWriteCallback(int64_t cb_id, const uint8_t *data, size_t len) {
+  State *state = reinterpret_cast<State *>(cb_id);
+  try {
+    auto chunk = createArrayBuffer(*state->rt, data, len);
+    state->cb.call(*state->rt, jsi::Value(*state->rt, chunk));
+    return 0;
+  } catch (...) {
+    return 1;
+  }
+}
+
+jsi::ArrayBuffer createArrayBuffer(jsi::Runtime &rt, const uint8_t *data,
+                                   size_t len) {
+  auto arrayBuffer = rt.global()
+                         .getPropertyAsFunction(rt, "ArrayBuffer")
+                         .callAsConstructor(rt, double(len))
+                         .getObject(rt)
+                         .getArrayBuffer(rt);
+  if (len > 0)
+    memcpy(arrayBuffer.data(rt), data, len);
+  return arrayBuffer;
+}
+
 template <>
 jsi::Value createReturnValue(jsi::Runtime &rt, ErrorCode code,
                              nullptr_t value) {
@@ -73,6 +103,22 @@ jsi::Value createReturnValue(jsi::Runtime &rt, ErrorCode code, int8_t *value) {
   return object;
 }
 
+template <>
+jsi::Value createReturnValue(jsi::Runtime &rt, ErrorCode code,
+                             int64_t *value) {
+  auto object = jsi::Object(rt);
+
+  if (code == ErrorCode::Success) {
+    auto valueWithoutNullptr =
+        value == nullptr ? jsi::Value::null() : jsi::Value(double(*value));
+    object.setProperty(rt, "value", valueWithoutNullptr);
+  }
+
+  object.setProperty(rt, "errorCode", int(code));
+
+  return object;
+}
+
 template <>
 jsi::Value createReturnValue(jsi::Runtime &rt, ErrorCode code,
                              ObjectHandle *value) {
@@ -181,6 +227,35 @@ createReturnValue(jsi::Runtime &rt, ErrorCode code,
   return object;
 }
 
+template <>
+jsi::Value createReturnValue(
+    jsi::Runtime &rt, ErrorCode code,
+    anoncreds::RevocationRegistryDefinitionInMemoryReturn *value) {
+  auto object = jsi::Object(rt);
+
+  if (code == ErrorCode::Success) {
+    if (value == nullptr) {
+      object.setProperty(rt, "value", jsi::Value::null());
+    } else {
+      auto objectValue = jsi::Object(rt);
+      objectValue.setProperty(rt, "revocationRegistryDefinition",
+                              int(value->revocationRegistryDefinition));
+      objectValue.setProperty(rt, "revocationRegistryDefinitionPrivate",
+                              int(value->revocationRegistryDefinitionPrivate));
+      objectValue.setProperty(
+          rt, "tails",
+          createArrayBuffer(rt, value->tails.data, size_t(value->tails.len)));
+      object.setProperty(rt, "value", objectValue);
+
+      anoncreds_buffer_free(value->tails);
+    }
+  }
+
+  object.setProperty(rt, "errorCode", int(code));
+
+  return object;
+}
+
 template <>
 uint8_t jsiToValue(jsi::Runtime &rt, jsi::Object &options, const char *name,
                    bool optional) {
@@ -279,6 +354,24 @@ jsiToValue<std::vector<int32_t>>(jsi::Runtime &rt, jsi::Object &options,
   throw jsi::JSError(rt, errorPrefix + name + errorInfix + "Array<number>");
 }
 
+template <>
+std::unique_ptr<State>
+jsiToValue<std::unique_ptr<State>>(jsi::Runtime &rt, jsi::Object &options,
+                                   const char *name, bool optional) {
+  jsi::Value value = options.getProperty(rt, name);
+  if ((value.isNull() || value.isUndefined()) && optional)
+    return nullptr;
+
+  if (value.isObject() && value.asObject(rt).isFunction(rt)) {
+    jsi::Function cb = value.asObject(rt).asFunction(rt);
+    auto state = std::make_unique<State>(&cb);
+    state->rt = &rt;
+    return state;
+  }
+
+  throw jsi::JSError(rt, errorPrefix + name + errorInfix + "function");
+};
+
 template <>
 ObjectHandle jsiToValue(jsi::Runtime &rt, jsi::Object &options,
                         const char *name, bool optional) {
@@ -294,20 +387,20 @@ ObjectHandle jsiToValue(jsi::Runtime &rt, jsi::Object &options,
 };
 
 template <>
-FfiCredentialEntry jsiToValue(jsi::Runtime &rt, jsi::Object &options,
+FfiCredentialEntryV2 jsiToValue(jsi::Runtime &rt, jsi::Object &options,
                               const char *name, bool optional) {
   jsi::Value value = options.getProperty(rt, name);
   if ((value.isNull() || value.isUndefined()) && optional)
-    return FfiCredentialEntry{};
+    return FfiCredentialEntryV2{};
 
   if (value.isObject()) {
     jsi::Object valueAsObject = value.asObject(rt);
     auto credential = jsiToValue<ObjectHandle>(rt, valueAsObject, "credential");
-    auto timestamp = jsiToValue<int32_t>(rt, valueAsObject, "timestamp", true);
+    auto timestamp = jsiToValue<int64_t>(rt, valueAsObject, "timestamp", true);
     auto revocationState =
         jsiToValue<ObjectHandle>(rt, valueAsObject, "revocationState", true);
 
-    return FfiCredentialEntry{.credential = credential,
+    return FfiCredentialEntryV2{.credential = credential,
                               .timestamp = timestamp,
                               .rev_state = revocationState};
   }
@@ -339,8 +432,8 @@ FfiCredentialProve jsiToValue(jsi::Runtime &rt, jsi::Object &options,
 };
 
 template <>
-FfiList_FfiCredentialEntry
-jsiToValue<FfiList_FfiCredentialEntry>(jsi::Runtime &rt, jsi::Object &options,
+FfiList_FfiCredentialEntryV2
+jsiToValue<FfiList_FfiCredentialEntryV2>(jsi::Runtime &rt, jsi::Object &options,
                                        const char *name, bool optional) {
   jsi::Value value = options.getProperty(rt, name);
 
@@ -348,7 +441,7 @@ jsiToValue<FfiList_FfiCredentialEntry>(jsi::Runtime &rt, jsi::Object &options,
     auto arr = value.asObject(rt).asArray(rt);
     auto len = arr.length(rt);
 
-    auto credentialEntry = new FfiCredentialEntry[arrayMaxSize];
+    auto credentialEntry = new FfiCredentialEntryV2[arrayMaxSize];
 
     // TODO: error Handling
     for (int i = 0; i < len; i++) {
@@ -358,21 +451,21 @@ jsiToValue<FfiList_FfiCredentialEntry>(jsi::Runtime &rt, jsi::Object &options,
       auto credential =
           jsiToValue<ObjectHandle>(rt, valueAsObject, "credential");
       auto timestamp =
-          jsiToValue<int32_t>(rt, valueAsObject, "timestamp", true);
+          jsiToValue<int64_t>(rt, valueAsObject, "timestamp", true);
       auto revocationState =
           jsiToValue<ObjectHandle>(rt, valueAsObject, "revocationState", true);
 
-      credentialEntry[i] = *new FfiCredentialEntry[sizeof(FfiCredentialEntry)];
-      credentialEntry[i] = FfiCredentialEntry{.credential = credential,
+      credentialEntry[i] = *new FfiCredentialEntryV2[sizeof(FfiCredentialEntryV2)];
+      credentialEntry[i] = FfiCredentialEntryV2{.credential = credential,
                                               .timestamp = timestamp,
                                               .rev_state = revocationState};
     }
 
-    return FfiList_FfiCredentialEntry{.count = len, .data = credentialEntry};
+    return FfiList_FfiCredentialEntryV2{.count = len, .data = credentialEntry};
   }
 
   if (optional)
-    return FfiList_FfiCredentialEntry{};
+    return FfiList_FfiCredentialEntryV2{};
 
   throw jsi::JSError(rt, errorPrefix + name + errorInfix + "Array<number>");
 }
@@ -536,22 +629,22 @@ FfiCredRevInfo jsiToValue(jsi::Runtime &rt, jsi::Object &options,
 };
 
 template <>
-FfiNonrevokedIntervalOverride jsiToValue(jsi::Runtime &rt, jsi::Object &options,
+FfiNonrevokedIntervalOverrideV2 jsiToValue(jsi::Runtime &rt, jsi::Object &options,
                                          const char *name, bool optional) {
   jsi::Value value = options.getProperty(rt, name);
   if ((value.isNull() || value.isUndefined()) && optional)
-    return FfiNonrevokedIntervalOverride{};
+    return FfiNonrevokedIntervalOverrideV2{};
 
   if (value.isObject()) {
     jsi::Object valueAsObject = value.asObject(rt);
     auto requestedFromTimestamp =
-        jsiToValue<int32_t>(rt, valueAsObject, "requestedFromTimestamp");
-    auto overrideRevocationStatusListTimestamp = jsiToValue<int32_t>(
+        jsiToValue<int64_t>(rt, valueAsObject, "requestedFromTimestamp");
+    auto overrideRevocationStatusListTimestamp = jsiToValue<int64_t>(
         rt, valueAsObject, "overrideRevocationStatusListTimestamp");
     auto revocationRegistryDefinitionId = jsiToValue<std::string>(
         rt, valueAsObject, "revocationRegistryDefinitionId");
 
-    return FfiNonrevokedIntervalOverride{
+    return FfiNonrevokedIntervalOverrideV2{
         .rev_reg_def_id = revocationRegistryDefinitionId.c_str(),
         .requested_from_ts = requestedFromTimestamp,
         .override_rev_status_list_ts = overrideRevocationStatusListTimestamp};
@@ -562,8 +655,8 @@ FfiNonrevokedIntervalOverride jsiToValue(jsi::Runtime &rt, jsi::Object &options,
 };
 
 template <>
-FfiList_FfiNonrevokedIntervalOverride
-jsiToValue<FfiList_FfiNonrevokedIntervalOverride>(jsi::Runtime &rt,
+FfiList_FfiNonrevokedIntervalOverrideV2
+jsiToValue<FfiList_FfiNonrevokedIntervalOverrideV2>(jsi::Runtime &rt,
                                                   jsi::Object &options,
                                                   const char *name,
                                                   bool optional) {
@@ -574,7 +667,7 @@ jsiToValue<FfiList_FfiNonrevokedIntervalOverride>(jsi::Runtime &rt,
     auto len = arr.length(rt);
 
     auto nonRevokedInterValOverrides =
-        new FfiNonrevokedIntervalOverride[arrayMaxSize];
+        new FfiNonrevokedIntervalOverrideV2[arrayMaxSize];
 
     // TODO: error Handling
     for (int i = 0; i < len; i++) {
@@ -582,27 +675,27 @@ jsiToValue<FfiList_FfiNonrevokedIntervalOverride>(jsi::Runtime &rt,
       auto valueAsObject = element.asObject(rt);
 
       auto requestedFromTimestamp =
-          jsiToValue<int32_t>(rt, valueAsObject, "requestedFromTimestamp");
-      auto overrideRevocationStatusListTimestamp = jsiToValue<int32_t>(
+          jsiToValue<int64_t>(rt, valueAsObject, "requestedFromTimestamp");
+      auto overrideRevocationStatusListTimestamp = jsiToValue<int64_t>(
           rt, valueAsObject, "overrideRevocationStatusListTimestamp");
       auto revocationRegistryDefinitionId = jsiToValue<std::string>(
           rt, valueAsObject, "revocationRegistryDefinitionId");
 
       nonRevokedInterValOverrides[i] =
-          *new FfiNonrevokedIntervalOverride[sizeof(
-              FfiNonrevokedIntervalOverride)];
-      nonRevokedInterValOverrides[i] = FfiNonrevokedIntervalOverride{
+          *new FfiNonrevokedIntervalOverrideV2[sizeof(
+              FfiNonrevokedIntervalOverrideV2)];
+      nonRevokedInterValOverrides[i] = FfiNonrevokedIntervalOverrideV2{
           .rev_reg_def_id = revocationRegistryDefinitionId.c_str(),
           .requested_from_ts = requestedFromTimestamp,
           .override_rev_status_list_ts = overrideRevocationStatusListTimestamp};
     }
 
-    return FfiList_FfiNonrevokedIntervalOverride{
+    return FfiList_FfiNonrevokedIntervalOverrideV2{
         .count = len, .data = nonRevokedInterValOverrides};
   }
 
   if (optional)
-    return FfiList_FfiNonrevokedIntervalOverride{};
+    return FfiList_FfiNonrevokedIntervalOverrideV2{};
 
   throw jsi::JSError(rt, errorPrefix + name + errorInfix +
                              "Array<NonRevokedIntervalOverride>");
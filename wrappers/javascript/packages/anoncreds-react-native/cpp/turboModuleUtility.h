@@ -21,6 +21,19 @@ struct State {
   State(jsi::Function *cb_) : cb(std::move(*cb_)) {}
 };
 
+// Reports progress to the JS callback whose `State` is passed as `cb_id`. The
+// callback is invoked synchronously on the JS thread of the calling function
+void progressCallback(int64_t cb_id, uint64_t done, uint64_t total);
+
+// Passes a chunk of the tails as an `ArrayBuffer` to the JS callback whose
+// `State` is passed as `cb_id`. Returns non-zero when the callback throws, as
+// the exception cannot unwind through the library
+int8_t tailsWriteCallback(int64_t cb_id, const uint8_t *data, size_t len);
+
+// Copies bytes into a new JS `ArrayBuffer`
+jsi::ArrayBuffer createArrayBuffer(jsi::Runtime &rt, const uint8_t *data,
+                                   size_t len);
+
 // Install the Turbomodule
 void registerTurboModule(jsi::Runtime &rt,
                          std::shared_ptr<react::CallInvoker> jsCallInvoker);
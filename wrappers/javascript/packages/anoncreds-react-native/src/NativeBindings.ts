@@ -1,5 +1,9 @@
 import type { ReturnObject } from './serialize'
-import type { NativeCredentialProve, NativeNonRevokedIntervalOverride } from '@hyperledger/anoncreds-shared'
+import type {
+  NativeCredentialProve,
+  NativeNonRevokedIntervalOverride,
+  NativeProgressOptions
+} from '@hyperledger/anoncreds-shared'
 
 // Alias for _Handle.handle
 type Handle = number
@@ -17,6 +21,8 @@ export type NativeBindings = {
     attributeNames: string[]
   }): ReturnObject<Handle>
 
+  validateIssuerId(options: { issuerId: string; didMethods?: string[] }): ReturnObject<never>
+
   createRevocationStatusList(options: {
     credentialDefinition: Handle
     revocationRegistryDefinitionId: string
@@ -27,6 +33,18 @@ export type NativeBindings = {
     issuanceByDefault: number
   }): ReturnObject<Handle>
 
+  createRevocationStatusListWithProgress(
+    options: {
+      credentialDefinition: Handle
+      revocationRegistryDefinitionId: string
+      revocationRegistryDefinition: Handle
+      revocationRegistryDefinitionPrivate: Handle
+      issuerId: string
+      timestamp?: number
+      issuanceByDefault: number
+    } & NativeProgressOptions
+  ): ReturnObject<Handle>
+
   updateRevocationStatusList(options: {
     credentialDefinition: Handle
     revocationRegistryDefinition: Handle
@@ -52,6 +70,7 @@ export type NativeBindings = {
   }): ReturnObject<{ credentialDefinition: Handle; credentialDefinitionPrivate: Handle; keyCorrectnessProof: Handle }>
 
   createCredential(options: {
+    schema: number
     credentialDefinition: number
     credentialDefinitionPrivate: number
     credentialOffer: number
@@ -142,6 +161,68 @@ export type NativeBindings = {
     oldRevocationStatusList?: number
   }): ReturnObject<Handle>
 
+  createRevocationRegistryDefinitionWithProgress(
+    options: {
+      credentialDefinition: number
+      credentialDefinitionId: string
+      issuerId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsDirectoryPath?: string
+    } & NativeProgressOptions
+  ): ReturnObject<{
+    revocationRegistryDefinition: Handle
+    revocationRegistryDefinitionPrivate: Handle
+  }>
+
+  createRevocationRegistryDefinitionInMemory(
+    options: {
+      credentialDefinition: number
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+    } & NativeProgressOptions
+  ): ReturnObject<{
+    revocationRegistryDefinition: Handle
+    revocationRegistryDefinitionPrivate: Handle
+    tails: ArrayBuffer
+  }>
+
+  createRevocationRegistryDefinitionWithSink(
+    options: {
+      credentialDefinition: number
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+      onTailsChunk: (chunk: ArrayBuffer) => void
+    } & NativeProgressOptions
+  ): ReturnObject<{
+    revocationRegistryDefinition: Handle
+    revocationRegistryDefinitionPrivate: Handle
+  }>
+
+  createOrUpdateRevocationStateWithProgress(
+    options: {
+      revocationRegistryDefinition: number
+      revocationStatusList: number
+      revocationRegistryIndex: number
+      tailsPath: string
+      oldRevocationState?: number
+      oldRevocationStatusList?: number
+    } & NativeProgressOptions
+  ): ReturnObject<Handle>
+
+  cancellationTokenCreate(options: Record<never, never>): ReturnObject<number>
+
+  cancellationTokenCancel(options: { cancellationToken: number }): ReturnObject<null>
+
+  cancellationTokenFree(options: { cancellationToken: number }): ReturnObject<null>
+
   presentationRequestFromJson(options: { json: string }): ReturnObject<Handle>
 
   schemaGetAttribute(options: { objectHandle: number; name: string }): ReturnObject<string>
@@ -150,12 +231,42 @@ export type NativeBindings = {
 
   credentialGetAttribute(options: { objectHandle: number; name: string }): ReturnObject<string>
 
+  credentialGetAttributeNames(options: { objectHandle: number }): ReturnObject<string>
+
+  credentialGetAttributeValue(options: { objectHandle: number; name: string; encoded?: number }): ReturnObject<string>
+
+  credentialGetSummary(options: { objectHandle: number }): ReturnObject<string>
+
+  schemaGetAttrNames(options: { objectHandle: number }): ReturnObject<string>
+
+  credentialDefinitionGetIssuerId(options: { objectHandle: number }): ReturnObject<string>
+
+  revocationStatusListGetTimestamp(options: { objectHandle: number }): ReturnObject<number>
+
+  presentationGetIdentifiers(options: { objectHandle: number }): ReturnObject<string>
+
   getJson(options: { objectHandle: number }): ReturnObject<string>
 
   getTypeName(options: { objectHandle: number }): ReturnObject<string>
 
   objectFree(options: { objectHandle: number }): ReturnObject<never>
 
+  objectCount(options: { typeName?: string }): ReturnObject<number>
+
+  objectGetCounts(options: Record<never, never>): ReturnObject<string>
+
+  objectListHandles(options: { typeName?: string }): ReturnObject<string>
+
+  objectScopeBegin(options: Record<never, never>): ReturnObject<number>
+
+  objectScopeEnd(options: { scope: number }): ReturnObject<null>
+
+  objectFreeScope(options: { scope: number }): ReturnObject<number>
+
+  objectSetLeakDebug(options: { enabled: number }): ReturnObject<null>
+
+  objectReportLeaks(options: Record<never, never>): ReturnObject<number>
+
   credentialDefinitionGetAttribute(options: { objectHandle: number; name: string }): ReturnObject<string>
 
   revocationRegistryDefinitionFromJson(options: { json: string }): ReturnObject<Handle>
@@ -6,7 +6,12 @@ import type {
   NativeCredentialEntry,
   NativeCredentialProve,
   NativeCredentialRevocationConfig,
-  NativeNonRevokedIntervalOverride
+  NativeNonRevokedIntervalOverride,
+  NativeProgressOptions,
+  NativeLiveObject,
+  NativePresentationIdentifier,
+  NativeCredentialSummary,
+  NativeTailsChunkCallback
 } from '@hyperledger/anoncreds-shared'
 
 import { ObjectHandle, AnoncredsError } from '@hyperledger/anoncreds-shared'
@@ -43,6 +48,25 @@ export class ReactNativeAnoncreds implements Anoncreds {
     return new ObjectHandle(handle)
   }
 
+  public createRevocationStatusListWithProgress(
+    options: {
+      credentialDefinition: ObjectHandle
+      revocationRegistryDefinitionId: string
+      revocationRegistryDefinition: ObjectHandle
+      revocationRegistryDefinitionPrivate: ObjectHandle
+      issuerId: string
+      timestamp?: number
+      issuanceByDefault: boolean
+    } & NativeProgressOptions
+  ): ObjectHandle {
+    const handle = this.handleError(
+      this.anoncreds.createRevocationStatusListWithProgress(
+        serializeArguments({ ...options, timestamp: options.timestamp ?? -1 })
+      )
+    )
+    return new ObjectHandle(handle)
+  }
+
   public updateRevocationStatusListTimestampOnly(options: {
     timestamp: number
     currentRevocationStatusList: ObjectHandle
@@ -90,6 +114,10 @@ export class ReactNativeAnoncreds implements Anoncreds {
     return new ObjectHandle(handle)
   }
 
+  public validateIssuerId(options: { issuerId: string; didMethods?: string[] }): void {
+    this.handleError(this.anoncreds.validateIssuerId(serializeArguments(options)))
+  }
+
   public createCredentialDefinition(options: {
     schemaId: string
     schema: ObjectHandle
@@ -114,6 +142,7 @@ export class ReactNativeAnoncreds implements Anoncreds {
   }
 
   public createCredential(options: {
+    schema: ObjectHandle
     credentialDefinition: ObjectHandle
     credentialDefinitionPrivate: ObjectHandle
     credentialOffer: ObjectHandle
@@ -288,6 +317,121 @@ export class ReactNativeAnoncreds implements Anoncreds {
     return new ObjectHandle(handle)
   }
 
+  public createRevocationRegistryDefinitionWithProgress(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      issuerId: string
+      maximumCredentialNumber: number
+      tailsDirectoryPath?: string
+    } & NativeProgressOptions
+  ): {
+    revocationRegistryDefinition: ObjectHandle
+    revocationRegistryDefinitionPrivate: ObjectHandle
+  } {
+    const { revocationRegistryDefinition, revocationRegistryDefinitionPrivate } = this.handleError(
+      this.anoncreds.createRevocationRegistryDefinitionWithProgress(serializeArguments(options))
+    )
+
+    return {
+      revocationRegistryDefinitionPrivate: new ObjectHandle(revocationRegistryDefinitionPrivate),
+      revocationRegistryDefinition: new ObjectHandle(revocationRegistryDefinition)
+    }
+  }
+
+  public createRevocationRegistryDefinitionInMemory(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+    } & NativeProgressOptions
+  ): {
+    revocationRegistryDefinition: ObjectHandle
+    revocationRegistryDefinitionPrivate: ObjectHandle
+    tails: Uint8Array
+  } {
+    const { revocationRegistryDefinition, revocationRegistryDefinitionPrivate, tails } = this.handleError(
+      this.anoncreds.createRevocationRegistryDefinitionInMemory(serializeArguments(options))
+    )
+
+    return {
+      revocationRegistryDefinitionPrivate: new ObjectHandle(revocationRegistryDefinitionPrivate),
+      revocationRegistryDefinition: new ObjectHandle(revocationRegistryDefinition),
+      tails: new Uint8Array(tails)
+    }
+  }
+
+  public createRevocationRegistryDefinitionWithSink(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+      onTailsChunk: NativeTailsChunkCallback
+    } & NativeProgressOptions
+  ): {
+    revocationRegistryDefinition: ObjectHandle
+    revocationRegistryDefinitionPrivate: ObjectHandle
+  } {
+    const { onTailsChunk, ...rest } = options
+
+    let chunkError: unknown
+    const returnObject = this.anoncreds.createRevocationRegistryDefinitionWithSink({
+      ...serializeArguments(rest),
+      onTailsChunk: (chunk: ArrayBuffer) => {
+        try {
+          onTailsChunk(new Uint8Array(chunk))
+        } catch (error) {
+          chunkError = error
+          throw error
+        }
+      }
+    })
+    // Rethrow the error from the chunk handler rather than the generic write failure
+    if (chunkError !== undefined) throw chunkError
+    const { revocationRegistryDefinition, revocationRegistryDefinitionPrivate } = this.handleError(returnObject)
+
+    return {
+      revocationRegistryDefinitionPrivate: new ObjectHandle(revocationRegistryDefinitionPrivate),
+      revocationRegistryDefinition: new ObjectHandle(revocationRegistryDefinition)
+    }
+  }
+
+  public createOrUpdateRevocationStateWithProgress(
+    options: {
+      revocationRegistryDefinition: ObjectHandle
+      revocationStatusList: ObjectHandle
+      revocationRegistryIndex: number
+      tailsPath: string
+      oldRevocationState?: ObjectHandle
+      oldRevocationStatusList?: ObjectHandle
+    } & NativeProgressOptions
+  ): ObjectHandle {
+    const handle = this.handleError(
+      this.anoncreds.createOrUpdateRevocationStateWithProgress(serializeArguments(options))
+    )
+    return new ObjectHandle(handle)
+  }
+
+  public cancellationTokenCreate(): number {
+    return this.handleError(this.anoncreds.cancellationTokenCreate({}))
+  }
+
+  public cancellationTokenCancel(options: { cancellationToken: number }): void {
+    this.handleError(this.anoncreds.cancellationTokenCancel(options))
+  }
+
+  public cancellationTokenFree(options: { cancellationToken: number }): void {
+    this.handleError(this.anoncreds.cancellationTokenFree(options))
+  }
+
   public presentationRequestFromJson(options: { json: string }): ObjectHandle {
     const handle = this.handleError(this.anoncreds.presentationRequestFromJson(serializeArguments(options)))
     return new ObjectHandle(handle)
@@ -305,6 +449,38 @@ export class ReactNativeAnoncreds implements Anoncreds {
     return this.handleError(this.anoncreds.credentialGetAttribute(serializeArguments(options)))
   }
 
+  public credentialGetAttributeNames(options: { objectHandle: ObjectHandle }): string[] {
+    const names = this.handleError(this.anoncreds.credentialGetAttributeNames(serializeArguments(options)))
+    return JSON.parse(names) as string[]
+  }
+
+  public credentialGetAttributeValue(options: { objectHandle: ObjectHandle; name: string; encoded?: boolean }): string {
+    return this.handleError(this.anoncreds.credentialGetAttributeValue(serializeArguments(options)))
+  }
+
+  public credentialGetSummary(options: { objectHandle: ObjectHandle }): NativeCredentialSummary {
+    const summary = this.handleError(this.anoncreds.credentialGetSummary(serializeArguments(options)))
+    return JSON.parse(summary) as NativeCredentialSummary
+  }
+
+  public schemaGetAttrNames(options: { objectHandle: ObjectHandle }): string[] {
+    const names = this.handleError(this.anoncreds.schemaGetAttrNames(serializeArguments(options)))
+    return JSON.parse(names) as string[]
+  }
+
+  public credentialDefinitionGetIssuerId(options: { objectHandle: ObjectHandle }): string {
+    return this.handleError(this.anoncreds.credentialDefinitionGetIssuerId(serializeArguments(options)))
+  }
+
+  public revocationStatusListGetTimestamp(options: { objectHandle: ObjectHandle }): number {
+    return this.handleError(this.anoncreds.revocationStatusListGetTimestamp(serializeArguments(options)))
+  }
+
+  public presentationGetIdentifiers(options: { objectHandle: ObjectHandle }): NativePresentationIdentifier[] {
+    const identifiers = this.handleError(this.anoncreds.presentationGetIdentifiers(serializeArguments(options)))
+    return JSON.parse(identifiers) as NativePresentationIdentifier[]
+  }
+
   public getJson(options: { objectHandle: ObjectHandle }): string {
     return this.handleError(this.anoncreds.getJson(serializeArguments(options)))
   }
@@ -317,6 +493,40 @@ export class ReactNativeAnoncreds implements Anoncreds {
     return this.handleError(this.anoncreds.objectFree(serializeArguments(options)))
   }
 
+  public objectCount(options: { typeName?: string }): number {
+    return this.handleError(this.anoncreds.objectCount(serializeArguments(options)))
+  }
+
+  public objectGetCounts(): Record<string, number> {
+    return JSON.parse(this.handleError(this.anoncreds.objectGetCounts({}))) as Record<string, number>
+  }
+
+  public objectListHandles(options: { typeName?: string }): NativeLiveObject[] {
+    return JSON.parse(
+      this.handleError(this.anoncreds.objectListHandles(serializeArguments(options)))
+    ) as NativeLiveObject[]
+  }
+
+  public objectScopeBegin(): number {
+    return this.handleError(this.anoncreds.objectScopeBegin({}))
+  }
+
+  public objectScopeEnd(options: { scope: number }): void {
+    this.handleError(this.anoncreds.objectScopeEnd(options))
+  }
+
+  public objectFreeScope(options: { scope: number }): number {
+    return this.handleError(this.anoncreds.objectFreeScope(options))
+  }
+
+  public objectSetLeakDebug(options: { enabled: boolean }): void {
+    this.handleError(this.anoncreds.objectSetLeakDebug(serializeArguments(options)))
+  }
+
+  public objectReportLeaks(): number {
+    return this.handleError(this.anoncreds.objectReportLeaks({}))
+  }
+
   public credentialDefinitionGetAttribute(options: { objectHandle: ObjectHandle; name: string }): string {
     return this.handleError(this.anoncreds.credentialDefinitionGetAttribute(serializeArguments(options)))
   }
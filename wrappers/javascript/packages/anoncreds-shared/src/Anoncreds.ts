@@ -35,6 +35,38 @@ export type NativeCredentialRevocationConfig = {
   registryIndex: number
 }
 
+export type NativePresentationIdentifier = {
+  schema_id: string
+  cred_def_id: string
+  rev_reg_id?: string | null
+  timestamp?: number | null
+}
+
+export type NativeCredentialSummary = {
+  schema_id: string
+  cred_def_id: string
+  rev_reg_id?: string
+  rev_reg_index?: number
+  attrs: Record<string, string>
+}
+
+// Called with the number of completed steps and the total number of steps
+export type NativeProgressCallback = (done: number, total: number) => void
+
+// Called with each chunk of the tails file, throwing aborts the registry creation
+export type NativeTailsChunkCallback = (chunk: Uint8Array) => void
+
+export type NativeLiveObject = {
+  handle: number
+  typeName: string
+  scope?: number
+}
+
+export type NativeProgressOptions = {
+  onProgress?: NativeProgressCallback
+  cancellationToken?: number
+}
+
 export type Anoncreds = {
   version(): string
 
@@ -45,6 +77,8 @@ export type Anoncreds = {
 
   createSchema(options: { name: string; version: string; issuerId: string; attributeNames: string[] }): ObjectHandle
 
+  validateIssuerId(options: { issuerId: string; didMethods?: string[] }): void
+
   createCredentialDefinition(options: {
     schemaId: string
     schema: ObjectHandle
@@ -59,6 +93,7 @@ export type Anoncreds = {
   }
 
   createCredential(options: {
+    schema: ObjectHandle
     credentialDefinition: ObjectHandle
     credentialDefinitionPrivate: ObjectHandle
     credentialOffer: ObjectHandle
@@ -131,6 +166,51 @@ export type Anoncreds = {
     revocationRegistryDefinitionPrivate: ObjectHandle
   }
 
+  createRevocationRegistryDefinitionWithProgress(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      issuerId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsDirectoryPath?: string
+    } & NativeProgressOptions
+  ): {
+    revocationRegistryDefinition: ObjectHandle
+    revocationRegistryDefinitionPrivate: ObjectHandle
+  }
+
+  createRevocationRegistryDefinitionInMemory(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+    } & NativeProgressOptions
+  ): {
+    revocationRegistryDefinition: ObjectHandle
+    revocationRegistryDefinitionPrivate: ObjectHandle
+    tails: Uint8Array
+  }
+
+  createRevocationRegistryDefinitionWithSink(
+    options: {
+      credentialDefinition: ObjectHandle
+      credentialDefinitionId: string
+      tag: string
+      revocationRegistryType: string
+      maximumCredentialNumber: number
+      tailsLocation?: string
+      onTailsChunk: NativeTailsChunkCallback
+    } & NativeProgressOptions
+  ): {
+    revocationRegistryDefinition: ObjectHandle
+    revocationRegistryDefinitionPrivate: ObjectHandle
+  }
+
   createOrUpdateRevocationState(options: {
     revocationRegistryDefinition: ObjectHandle
     revocationStatusList: ObjectHandle
@@ -140,6 +220,17 @@ export type Anoncreds = {
     oldRevocationStatusList?: ObjectHandle
   }): ObjectHandle
 
+  createOrUpdateRevocationStateWithProgress(
+    options: {
+      revocationRegistryDefinition: ObjectHandle
+      revocationStatusList: ObjectHandle
+      revocationRegistryIndex: number
+      tailsPath: string
+      oldRevocationState?: ObjectHandle
+      oldRevocationStatusList?: ObjectHandle
+    } & NativeProgressOptions
+  ): ObjectHandle
+
   createRevocationStatusList(options: {
     credentialDefinition: ObjectHandle
     revocationRegistryDefinitionId: string
@@ -150,6 +241,24 @@ export type Anoncreds = {
     timestamp?: number
   }): ObjectHandle
 
+  createRevocationStatusListWithProgress(
+    options: {
+      credentialDefinition: ObjectHandle
+      revocationRegistryDefinitionId: string
+      revocationRegistryDefinition: ObjectHandle
+      revocationRegistryDefinitionPrivate: ObjectHandle
+      issuerId: string
+      issuanceByDefault: boolean
+      timestamp?: number
+    } & NativeProgressOptions
+  ): ObjectHandle
+
+  cancellationTokenCreate(): number
+
+  cancellationTokenCancel(options: { cancellationToken: number }): void
+
+  cancellationTokenFree(options: { cancellationToken: number }): void
+
   updateRevocationStatusListTimestampOnly(options: {
     timestamp: number
     currentRevocationStatusList: ObjectHandle
@@ -167,8 +276,22 @@ export type Anoncreds = {
 
   credentialGetAttribute(options: { objectHandle: ObjectHandle; name: string }): string
 
+  credentialGetAttributeNames(options: { objectHandle: ObjectHandle }): string[]
+
+  credentialGetAttributeValue(options: { objectHandle: ObjectHandle; name: string; encoded?: boolean }): string
+
+  credentialGetSummary(options: { objectHandle: ObjectHandle }): NativeCredentialSummary
+
   revocationRegistryDefinitionGetAttribute(options: { objectHandle: ObjectHandle; name: string }): string
 
+  schemaGetAttrNames(options: { objectHandle: ObjectHandle }): string[]
+
+  credentialDefinitionGetIssuerId(options: { objectHandle: ObjectHandle }): string
+
+  revocationStatusListGetTimestamp(options: { objectHandle: ObjectHandle }): number
+
+  presentationGetIdentifiers(options: { objectHandle: ObjectHandle }): NativePresentationIdentifier[]
+
   presentationRequestFromJson(options: { json: string }): ObjectHandle
 
   revocationRegistryDefinitionFromJson(options: { json: string }): ObjectHandle
@@ -204,4 +327,20 @@ export type Anoncreds = {
   getTypeName(options: { objectHandle: ObjectHandle }): string
 
   objectFree(options: { objectHandle: ObjectHandle }): void
+
+  objectCount(options: { typeName?: string }): number
+
+  objectGetCounts(): Record<string, number>
+
+  objectListHandles(options: { typeName?: string }): NativeLiveObject[]
+
+  objectScopeBegin(): number
+
+  objectScopeEnd(options: { scope: number }): void
+
+  objectFreeScope(options: { scope: number }): number
+
+  objectSetLeakDebug(options: { enabled: boolean }): void
+
+  objectReportLeaks(): number
 }
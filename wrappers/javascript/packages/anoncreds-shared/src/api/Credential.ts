@@ -12,9 +12,11 @@ import { CredentialOffer } from './CredentialOffer'
 import { CredentialRequest } from './CredentialRequest'
 import { CredentialRequestMetadata } from './CredentialRequestMetadata'
 import { RevocationRegistryDefinition } from './RevocationRegistryDefinition'
+import { Schema } from './Schema'
 import { pushToArray } from './utils'
 
 export type CreateCredentialOptions = {
+  schema: Schema | JsonObject
   credentialDefinition: CredentialDefinition | JsonObject
   credentialDefinitionPrivate: CredentialDefinitionPrivate | JsonObject
   credentialOffer: CredentialOffer | JsonObject
@@ -39,6 +41,11 @@ export class Credential extends AnoncredsObject {
     // Objects created within this method must be freed up
     const objectHandles: ObjectHandle[] = []
     try {
+      const schema =
+        options.schema instanceof Schema
+          ? options.schema.handle
+          : pushToArray(Schema.fromJson(options.schema).handle, objectHandles)
+
       const credentialDefinition =
         options.credentialDefinition instanceof CredentialDefinition
           ? options.credentialDefinition.handle
@@ -60,6 +67,7 @@ export class Credential extends AnoncredsObject {
           : pushToArray(CredentialRequest.fromJson(options.credentialRequest).handle, objectHandles)
 
       credential = anoncreds.createCredential({
+        schema,
         credentialDefinition,
         credentialDefinitionPrivate,
         credentialOffer,
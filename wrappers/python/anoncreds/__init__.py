@@ -1,6 +1,13 @@
 """Anoncreds Python wrapper library"""
 
-from .bindings import encode_credential_attributes, generate_nonce, library_version, create_link_secret
+from .bindings import (
+    CancellationToken,
+    encode_credential_attributes,
+    generate_nonce,
+    library_version,
+    create_link_secret,
+    validate_issuer_id,
+)
 from .error import AnoncredsError, AnoncredsErrorCode
 from .types import (
     Credential,
@@ -24,10 +31,12 @@ from .types import (
 )
 
 __all__ = (
+    "CancellationToken",
     "create_link_secret",
     "encode_credential_attributes",
     "generate_nonce",
     "library_version",
+    "validate_issuer_id",
     "AnoncredsError",
     "AnoncredsErrorCode",
     "Credential",
@@ -7,6 +7,7 @@ import sys
 from ctypes import (
     Array,
     CDLL,
+    CFUNCTYPE,
     POINTER,
     Structure,
     addressof,
@@ -18,7 +19,9 @@ from ctypes import (
     c_int64,
     c_int32,
     c_size_t,
+    c_uint8,
     c_ubyte,
+    c_uint64,
     c_void_p,
     pointer,
 )
@@ -90,6 +93,51 @@ class ObjectHandle(Structure):
         get_library().anoncreds_object_free(value)
 
 
+ProgressCallback = Callable[[int, int], None]
+
+PROGRESS_CB = CFUNCTYPE(None, c_int64, c_uint64, c_uint64)
+
+TAILS_WRITE_CB = CFUNCTYPE(c_int8, c_int64, POINTER(c_uint8), c_size_t)
+
+
+class CancellationToken(Structure):
+    """Token used to cancel a long running operation, possibly from another thread."""
+
+    _fields_ = [
+        ("value", c_int64),
+    ]
+
+    def __init__(self, value=0):
+        """Initializer."""
+        super().__init__(value=value)
+        finalize_struct(self, c_int64)
+
+    @classmethod
+    def create(cls) -> "CancellationToken":
+        token = cls()
+        do_call("anoncreds_cancellation_token_create", byref(token))
+        return token
+
+    def cancel(self):
+        do_call("anoncreds_cancellation_token_cancel", self)
+
+    @classmethod
+    def _cleanup(cls, value: c_int64):
+        """Destructor."""
+        get_library().anoncreds_cancellation_token_free(value)
+
+
+def _progress_args(
+    progress: Optional[ProgressCallback], cancellation: Optional[CancellationToken]
+) -> list:
+    callback = (
+        PROGRESS_CB(lambda _cb_id, done, total: progress(done, total))
+        if progress
+        else None
+    )
+    return [callback, c_int64(0), cancellation or CancellationToken()]
+
+
 class AnoncredsObject:
     """A generic Anoncreds object allocated by the library."""
 
@@ -115,6 +163,26 @@ class AnoncredsObject:
     def to_json_buffer(self) -> memoryview:
         return object_get_json(self.handle).raw
 
+    FROM_CBOR: Optional[str] = None
+
+    @classmethod
+    def from_cbor(cls, value: bytes) -> "AnoncredsObject":
+        """Decode the object from CBOR, requires the library built with the `cbor` feature."""
+        if not cls.FROM_CBOR:
+            raise AnoncredsError(
+                AnoncredsErrorCode.WRAPPER,
+                f"{cls.__name__} cannot be decoded from CBOR",
+            )
+        return cls(_object_from_cbor(cls.FROM_CBOR, value))
+
+    def to_cbor(self) -> bytes:
+        """Encode the object as CBOR, requires the library built with the `cbor` feature."""
+        return bytes(object_get_cbor(self.handle))
+
+    def get_field(self, path: str) -> Any:
+        """Get a field of the JSON form of the object by JSON pointer, like `attrNames/0`."""
+        return object_get_field(self.handle, path)
+
 
 class RawBuffer(Structure):
     """A byte buffer allocated by the library."""
@@ -368,8 +436,8 @@ class CredentialProveList(Structure):
 class NonrevokedIntervalOverride(Structure):
     _fields_ = [
         ("rev_reg_def_id", c_char_p),
-        ("requested_from_ts", c_int32),
-        ("override_rev_status_list_ts", c_int32),
+        ("requested_from_ts", c_int64),
+        ("override_rev_status_list_ts", c_int64),
     ]
 
     @classmethod
@@ -492,9 +560,12 @@ def get_current_error(expect: bool = False) -> Optional[AnoncredsError]:
             LOGGER.warning("JSON decode error for anoncreds_get_current_error")
             msg = None
         if msg and "message" in msg and "code" in msg:
-            return AnoncredsError(
-                AnoncredsErrorCode(msg["code"]), msg["message"], msg.get("extra")
-            )
+            extra = msg.get("extra")
+            if extra is None and "kind" in msg:
+                extra = {
+                    key: msg[key] for key in ("kind", "causes", "context") if key in msg
+                }
+            return AnoncredsError(AnoncredsErrorCode(msg["code"]), msg["message"], extra)
         if not expect:
             return None
     return AnoncredsError(AnoncredsErrorCode.WRAPPER, "Unknown error")
@@ -555,12 +626,80 @@ def object_get_json(handle: ObjectHandle) -> ByteBuffer:
     return result
 
 
+def object_get_cbor(handle: ObjectHandle) -> ByteBuffer:
+    result = ByteBuffer()
+    do_call("anoncreds_object_get_cbor", handle, byref(result))
+    return result
+
+
 def object_get_type_name(handle: ObjectHandle) -> StrBuffer:
     result = StrBuffer()
     do_call("anoncreds_object_get_type_name", handle, byref(result))
     return result
 
 
+def object_get_field(handle: ObjectHandle, path: str) -> Any:
+    result = StrBuffer()
+    do_call("anoncreds_object_get_field", handle, encode_str(path), byref(result))
+    return None if result.is_none() else json.loads(str(result))
+
+
+def object_get_field_str(handle: ObjectHandle, path: str) -> Optional[str]:
+    result = StrBuffer()
+    do_call("anoncreds_object_get_field_str", handle, encode_str(path), byref(result))
+    return None if result.is_none() else str(result)
+
+
+def object_get_field_int(handle: ObjectHandle, path: str) -> int:
+    result = c_int64()
+    do_call("anoncreds_object_get_field_i64", handle, encode_str(path), byref(result))
+    return result.value
+
+
+def object_count(type_name: Optional[str] = None) -> int:
+    result = c_size_t()
+    do_call("anoncreds_object_count", encode_str(type_name), byref(result))
+    return result.value
+
+
+def object_get_counts() -> dict:
+    result = StrBuffer()
+    do_call("anoncreds_object_get_counts", byref(result))
+    return json.loads(str(result))
+
+
+def object_list_handles(type_name: Optional[str] = None) -> list:
+    result = StrBuffer()
+    do_call("anoncreds_object_list_handles", encode_str(type_name), byref(result))
+    return json.loads(str(result))
+
+
+def object_scope_begin() -> int:
+    result = c_size_t()
+    do_call("anoncreds_object_scope_begin", byref(result))
+    return result.value
+
+
+def object_scope_end(scope: int):
+    do_call("anoncreds_object_scope_end", c_size_t(scope))
+
+
+def object_free_scope(scope: int) -> int:
+    result = c_size_t()
+    do_call("anoncreds_object_free_scope", c_size_t(scope), byref(result))
+    return result.value
+
+
+def object_set_leak_debug(enabled: bool):
+    do_call("anoncreds_object_set_leak_debug", c_int8(enabled))
+
+
+def object_report_leaks() -> int:
+    result = c_size_t()
+    do_call("anoncreds_object_report_leaks", byref(result))
+    return result.value
+
+
 def _object_from_json(method: str, value: Union[dict, str, bytes]) -> ObjectHandle:
     if isinstance(value, dict):
         value = json.dumps(value)
@@ -569,6 +708,12 @@ def _object_from_json(method: str, value: Union[dict, str, bytes]) -> ObjectHand
     return result
 
 
+def _object_from_cbor(method: str, value: bytes) -> ObjectHandle:
+    result = ObjectHandle()
+    do_call(method, encode_bytes(value), byref(result))
+    return result
+
+
 def _object_get_attribute(
     method: str, handle: ObjectHandle, name: str
 ) -> Optional[StrBuffer]:
@@ -579,12 +724,70 @@ def _object_get_attribute(
     return result
 
 
+def schema_get_attr_names(handle: ObjectHandle) -> Sequence[str]:
+    result = StrBuffer()
+    do_call("anoncreds_schema_get_attr_names", handle, byref(result))
+    return json.loads(str(result))
+
+
+def credential_definition_get_issuer_id(handle: ObjectHandle) -> str:
+    result = StrBuffer()
+    do_call("anoncreds_credential_definition_get_issuer_id", handle, byref(result))
+    return str(result)
+
+
+def revocation_status_list_get_timestamp(handle: ObjectHandle) -> int:
+    result = c_int64()
+    do_call("anoncreds_revocation_status_list_get_timestamp", handle, byref(result))
+    return result.value
+
+
+def presentation_get_identifiers(handle: ObjectHandle) -> Sequence[dict]:
+    result = StrBuffer()
+    do_call("anoncreds_presentation_get_identifiers", handle, byref(result))
+    return json.loads(str(result))
+
+
+def credential_get_attribute_names(handle: ObjectHandle) -> Sequence[str]:
+    result = StrBuffer()
+    do_call("anoncreds_credential_get_attribute_names", handle, byref(result))
+    return json.loads(str(result))
+
+
+def credential_get_attribute_value(
+    handle: ObjectHandle, name: str, encoded: bool = False
+) -> str:
+    result = StrBuffer()
+    do_call(
+        "anoncreds_credential_get_attribute_value",
+        handle,
+        encode_str(name),
+        c_int8(encoded),
+        byref(result),
+    )
+    return str(result)
+
+
+def credential_get_summary(handle: ObjectHandle) -> dict:
+    result = StrBuffer()
+    do_call("anoncreds_credential_get_summary", handle, byref(result))
+    return json.loads(str(result))
+
+
 def generate_nonce() -> str:
     result = StrBuffer()
     do_call("anoncreds_generate_nonce", byref(result))
     return str(result)
 
 
+def validate_issuer_id(issuer_id: str, did_methods: Optional[Sequence[str]] = None):
+    do_call(
+        "anoncreds_validate_issuer_id",
+        encode_str(issuer_id),
+        FfiStrList.create(did_methods),
+    )
+
+
 def create_schema(
     name: str,
     version: str,
@@ -629,6 +832,7 @@ def create_credential_definition(
 
 
 def create_credential(
+    schema: ObjectHandle,
     cred_def: ObjectHandle,
     cred_def_private: ObjectHandle,
     cred_offer: ObjectHandle,
@@ -650,6 +854,7 @@ def create_credential(
     enc_values_list = FfiStrList.create(enc_values_list)
     do_call(
         "anoncreds_create_credential",
+        schema,
         cred_def,
         cred_def_private,
         cred_offer,
@@ -761,7 +966,7 @@ def create_presentation(
     present = ObjectHandle()
 
     do_call(
-        "anoncreds_create_presentation",
+        "anoncreds_create_presentation_v2",
         pres_req,
         entry_list,
         prove_list,
@@ -795,11 +1000,11 @@ def verify_presentation(
     if nonrevoked_interval_overrides:
         nonrevoked_interval_overrides_list.count = len(nonrevoked_interval_overrides)
         nonrevoked_interval_overrides_list.data = (
-            NonrevokedIntervalOverride * nonrevoked_interval_overrides.count
+            NonrevokedIntervalOverride * nonrevoked_interval_overrides_list.count
         )(*nonrevoked_interval_overrides)
 
     do_call(
-        "anoncreds_verify_presentation",
+        "anoncreds_verify_presentation_v2",
         presentation,
         pres_req,
         FfiObjectHandleList.create(schemas),
@@ -815,6 +1020,19 @@ def verify_presentation(
     return bool(verify)
 
 
+def presentation_get_omitted_referents(
+    presentation: ObjectHandle, pres_req: ObjectHandle
+) -> Sequence[str]:
+    result = StrBuffer()
+    do_call(
+        "anoncreds_presentation_get_omitted_referents",
+        presentation,
+        pres_req,
+        byref(result),
+    )
+    return json.loads(str(result))
+
+
 def create_revocation_registry_definition(
     cred_def: ObjectHandle,
     cred_def_id: str,
@@ -823,11 +1041,13 @@ def create_revocation_registry_definition(
     rev_reg_type: str,
     max_cred_num: int,
     tails_dir_path: Optional[str],
+    progress: Optional[ProgressCallback] = None,
+    cancellation: Optional[CancellationToken] = None,
 ) -> Tuple[ObjectHandle, ObjectHandle]:
     reg_def = ObjectHandle()
     reg_def_private = ObjectHandle()
-    do_call(
-        "anoncreds_create_revocation_registry_def",
+    fn_name = "anoncreds_create_revocation_registry_def"
+    args = [
         cred_def,
         encode_str(cred_def_id),
         encode_str(issuer_id),
@@ -835,6 +1055,77 @@ def create_revocation_registry_definition(
         encode_str(rev_reg_type),
         c_int64(max_cred_num),
         encode_str(tails_dir_path),
+    ]
+    if progress or cancellation:
+        fn_name += "_with_progress"
+        args.extend(_progress_args(progress, cancellation))
+    do_call(fn_name, *args, byref(reg_def), byref(reg_def_private))
+    return reg_def, reg_def_private
+
+
+def create_revocation_registry_definition_in_memory(
+    cred_def: ObjectHandle,
+    cred_def_id: str,
+    tag: str,
+    rev_reg_type: str,
+    max_cred_num: int,
+    tails_location: Optional[str] = None,
+    progress: Optional[ProgressCallback] = None,
+    cancellation: Optional[CancellationToken] = None,
+) -> Tuple[ObjectHandle, ObjectHandle, ByteBuffer]:
+    reg_def = ObjectHandle()
+    reg_def_private = ObjectHandle()
+    tails = ByteBuffer()
+    do_call(
+        "anoncreds_create_revocation_registry_def_in_memory",
+        cred_def,
+        encode_str(cred_def_id),
+        encode_str(tag),
+        encode_str(rev_reg_type),
+        c_int64(max_cred_num),
+        encode_str(tails_location),
+        *_progress_args(progress, cancellation),
+        byref(reg_def),
+        byref(reg_def_private),
+        byref(tails),
+    )
+    return reg_def, reg_def_private, tails
+
+
+def create_revocation_registry_definition_to_sink(
+    cred_def: ObjectHandle,
+    cred_def_id: str,
+    tag: str,
+    rev_reg_type: str,
+    max_cred_num: int,
+    sink: Callable[[bytes], Any],
+    tails_location: Optional[str] = None,
+    progress: Optional[ProgressCallback] = None,
+    cancellation: Optional[CancellationToken] = None,
+) -> Tuple[ObjectHandle, ObjectHandle]:
+    """Stream the tails to `sink`, such as the `write` method of a binary file."""
+
+    def _write(_cb_id, data, length) -> int:
+        try:
+            sink(bytes(data[:length]))
+        except Exception:
+            LOGGER.exception("Error writing tails")
+            return 1
+        return 0
+
+    reg_def = ObjectHandle()
+    reg_def_private = ObjectHandle()
+    do_call(
+        "anoncreds_create_revocation_registry_def_with_sink",
+        cred_def,
+        encode_str(cred_def_id),
+        encode_str(tag),
+        encode_str(rev_reg_type),
+        c_int64(max_cred_num),
+        encode_str(tails_location),
+        TAILS_WRITE_CB(_write),
+        c_int64(0),
+        *_progress_args(progress, cancellation),
         byref(reg_def),
         byref(reg_def_private),
     )
@@ -849,11 +1140,13 @@ def create_revocation_status_list(
     issuer_id: str,
     issuance_by_default: bool,
     timestamp: Optional[int],
+    progress: Optional[ProgressCallback] = None,
+    cancellation: Optional[CancellationToken] = None,
 ) -> ObjectHandle:
     revocation_status_list = ObjectHandle()
 
-    do_call(
-        "anoncreds_create_revocation_status_list",
+    fn_name = "anoncreds_create_revocation_status_list"
+    args = [
         cred_def,
         encode_str(rev_reg_def_id),
         rev_reg_def,
@@ -861,8 +1154,11 @@ def create_revocation_status_list(
         encode_str(issuer_id),
         c_int8(issuance_by_default),
         c_int64(timestamp if timestamp else -1),
-        byref(revocation_status_list),
-    )
+    ]
+    if progress or cancellation:
+        fn_name += "_with_progress"
+        args.extend(_progress_args(progress, cancellation))
+    do_call(fn_name, *args, byref(revocation_status_list))
     return revocation_status_list
 
 
@@ -951,16 +1247,21 @@ def create_or_update_revocation_state(
     tails_path: str,
     rev_state: Optional[ObjectHandle],
     old_rev_status_list: Optional[ObjectHandle],
+    progress: Optional[ProgressCallback] = None,
+    cancellation: Optional[CancellationToken] = None,
 ) -> ObjectHandle:
     updated_rev_state = ObjectHandle()
-    do_call(
-        "anoncreds_create_or_update_revocation_state",
+    fn_name = "anoncreds_create_or_update_revocation_state"
+    args = [
         rev_reg_def,
         rev_status_list,
         c_int64(rev_reg_index),
         encode_str(tails_path),
         rev_state if rev_state else ObjectHandle(),
         old_rev_status_list if old_rev_status_list else ObjectHandle(),
-        byref(updated_rev_state),
-    )
+    ]
+    if progress or cancellation:
+        fn_name += "_with_progress"
+        args.extend(_progress_args(progress, cancellation))
+    do_call(fn_name, *args, byref(updated_rev_state))
     return updated_rev_state
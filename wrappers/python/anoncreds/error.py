@@ -13,11 +13,12 @@ class AnoncredsErrorCode(IntEnum):
     INVALID_USER_REVOC_ID = 6
     PROOF_REJECTED = 7
     REVOCATION_REGISTRY_FULL = 8
+    CANCELLED = 9
     WRAPPER = 99
 
 
 class AnoncredsError(Exception):
-    def __init__(self, code: AnoncredsErrorCode, message: str, extra: str = None):
+    def __init__(self, code: AnoncredsErrorCode, message: str, extra=None):
         super().__init__(message)
         self.code = code
         self.extra = extra
@@ -1,9 +1,10 @@
-from typing import Mapping, Optional, Sequence, Tuple, Union
+from typing import Any, Callable, Mapping, Optional, Sequence, Tuple, Union
 
 from . import bindings
 
 
 class CredentialDefinition(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_credential_definition_from_cbor"
     GET_ATTR = "anoncreds_credential_definition_get_attribute"
 
     @classmethod
@@ -58,8 +59,13 @@ class CredentialDefinition(bindings.AnoncredsObject):
             )
         )
 
+    @property
+    def issuer_id(self) -> str:
+        return bindings.credential_definition_get_issuer_id(self.handle)
+
 
 class CredentialDefinitionPrivate(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_credential_definition_private_from_cbor"
     @classmethod
     def load(
         cls, value: Union[dict, str, bytes, memoryview]
@@ -72,6 +78,7 @@ class CredentialDefinitionPrivate(bindings.AnoncredsObject):
 
 
 class KeyCorrectnessProof(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_key_correctness_proof_from_cbor"
     @classmethod
     def load(cls, value: Union[dict, str, bytes, memoryview]) -> "KeyCorrectnessProof":
         return KeyCorrectnessProof(
@@ -82,6 +89,7 @@ class KeyCorrectnessProof(bindings.AnoncredsObject):
 
 
 class CredentialOffer(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_credential_offer_from_cbor"
     @classmethod
     def create(
         cls,
@@ -103,6 +111,7 @@ class CredentialOffer(bindings.AnoncredsObject):
 
 
 class CredentialRequest(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_credential_request_from_cbor"
     @classmethod
     def create(
         cls,
@@ -137,6 +146,7 @@ class CredentialRequest(bindings.AnoncredsObject):
 
 
 class CredentialRequestMetadata(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_credential_request_metadata_from_cbor"
     @classmethod
     def load(
         cls, value: Union[dict, str, bytes, memoryview]
@@ -149,6 +159,7 @@ class CredentialRequestMetadata(bindings.AnoncredsObject):
 
 
 class RevocationRegistryDefinition(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_revocation_registry_definition_from_cbor"
     GET_ATTR = "anoncreds_revocation_registry_definition_get_attribute"
 
     @classmethod
@@ -162,21 +173,48 @@ class RevocationRegistryDefinition(bindings.AnoncredsObject):
         max_cred_num: int,
         *,
         tails_dir_path: str = None,
+        tails_sink: Optional[Callable[[bytes], Any]] = None,
+        tails_location: Optional[str] = None,
+        progress: Optional[bindings.ProgressCallback] = None,
+        cancellation: Optional[bindings.CancellationToken] = None,
     ) -> Tuple["RevocationRegistryDefinition", "RevocationRegistryDefinitionPrivate",]:
+        """Create a revocation registry definition.
+
+        The tails file is written to `tails_dir_path`, or streamed to `tails_sink`
+        when given, in which case `tails_location` defaults to the tails hash.
+        """
         if not isinstance(cred_def, bindings.AnoncredsObject):
             cred_def = CredentialDefinition.load(cred_def)
-        (
-            reg_def,
-            reg_def_private,
-        ) = bindings.create_revocation_registry_definition(
-            cred_def.handle,
-            cred_def_id,
-            issuer_id,
-            tag,
-            registry_type,
-            max_cred_num,
-            tails_dir_path,
-        )
+        if tails_sink:
+            (
+                reg_def,
+                reg_def_private,
+            ) = bindings.create_revocation_registry_definition_to_sink(
+                cred_def.handle,
+                cred_def_id,
+                tag,
+                registry_type,
+                max_cred_num,
+                tails_sink,
+                tails_location,
+                progress,
+                cancellation,
+            )
+        else:
+            (
+                reg_def,
+                reg_def_private,
+            ) = bindings.create_revocation_registry_definition(
+                cred_def.handle,
+                cred_def_id,
+                issuer_id,
+                tag,
+                registry_type,
+                max_cred_num,
+                tails_dir_path,
+                progress,
+                cancellation,
+            )
         return (
             RevocationRegistryDefinition(reg_def),
             RevocationRegistryDefinitionPrivate(reg_def_private),
@@ -224,8 +262,17 @@ class RevocationRegistryDefinition(bindings.AnoncredsObject):
             )
         )
 
+    @property
+    def issuer_id(self) -> str:
+        return bindings.object_get_field_str(self.handle, "issuerId")
+
+    @property
+    def cred_def_id(self) -> str:
+        return bindings.object_get_field_str(self.handle, "credDefId")
+
 
 class Schema(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_schema_from_cbor"
     @classmethod
     def create(
         cls,
@@ -240,13 +287,31 @@ class Schema(bindings.AnoncredsObject):
     def load(cls, value: Union[dict, str, bytes, memoryview]) -> "Schema":
         return Schema(bindings._object_from_json("anoncreds_schema_from_json", value))
 
+    @property
+    def name(self) -> str:
+        return bindings.object_get_field_str(self.handle, "name")
+
+    @property
+    def version(self) -> str:
+        return bindings.object_get_field_str(self.handle, "version")
+
+    @property
+    def issuer_id(self) -> str:
+        return bindings.object_get_field_str(self.handle, "issuerId")
+
+    @property
+    def attr_names(self) -> Sequence[str]:
+        return bindings.schema_get_attr_names(self.handle)
+
 
 class Credential(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_credential_from_cbor"
     GET_ATTR = "anoncreds_credential_get_attribute"
 
     @classmethod
     def create(
         cls,
+        schema: Union[str, Schema],
         cred_def: Union[str, CredentialDefinition],
         cred_def_private: Union[str, CredentialDefinitionPrivate],
         cred_offer: Union[str, CredentialOffer],
@@ -255,6 +320,8 @@ class Credential(bindings.AnoncredsObject):
         attr_enc_values: Optional[Mapping[str, str]] = None,
         revocation_config: Optional["CredentialRevocationConfig"] = None,
     ) -> "Credential":
+        if not isinstance(schema, bindings.AnoncredsObject):
+            schema = Schema.load(schema)
         if not isinstance(cred_def, bindings.AnoncredsObject):
             cred_def = CredentialDefinition.load(cred_def)
         if not isinstance(cred_def_private, bindings.AnoncredsObject):
@@ -264,6 +331,7 @@ class Credential(bindings.AnoncredsObject):
         if not isinstance(cred_request, bindings.AnoncredsObject):
             cred_request = CredentialRequest.load(cred_request)
         cred = bindings.create_credential(
+            schema.handle,
             cred_def.handle,
             cred_def_private.handle,
             cred_offer.handle,
@@ -342,8 +410,23 @@ class Credential(bindings.AnoncredsObject):
         )
         return int(str(sval)) if sval is not None else None
 
+    @property
+    def attribute_names(self) -> Sequence[str]:
+        return bindings.credential_get_attribute_names(self.handle)
+
+    def raw_value(self, name: str) -> str:
+        return bindings.credential_get_attribute_value(self.handle, name, False)
+
+    def encoded_value(self, name: str) -> str:
+        return bindings.credential_get_attribute_value(self.handle, name, True)
+
+    def summary(self) -> dict:
+        """Identifiers and raw attribute values, without the signature."""
+        return bindings.credential_get_summary(self.handle)
+
 
 class PresentationRequest(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_presentation_request_from_cbor"
     @classmethod
     def load(cls, value: Union[dict, str, bytes, memoryview]) -> "PresentationRequest":
         return PresentationRequest(
@@ -407,6 +490,7 @@ class PresentCredentials:
 
 
 class Presentation(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_presentation_from_cbor"
     @classmethod
     def create(
         cls,
@@ -473,6 +557,19 @@ class Presentation(bindings.AnoncredsObject):
             bindings._object_from_json("anoncreds_presentation_from_json", value)
         )
 
+    @property
+    def identifiers(self) -> Sequence[dict]:
+        return bindings.presentation_get_identifiers(self.handle)
+
+    def omitted_referents(
+        self, pres_req: Union[str, PresentationRequest]
+    ) -> Sequence[str]:
+        if not isinstance(pres_req, bindings.AnoncredsObject):
+            pres_req = PresentationRequest.load(pres_req)
+        return bindings.presentation_get_omitted_referents(
+            self.handle, pres_req.handle
+        )
+
     def verify(
         self,
         pres_req: Union[str, PresentationRequest],
@@ -553,6 +650,7 @@ class Presentation(bindings.AnoncredsObject):
 
 
 class RevocationRegistryDefinitionPrivate(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_revocation_registry_definition_private_from_cbor"
     @classmethod
     def load(
         cls, value: Union[dict, str, bytes, memoryview]
@@ -565,6 +663,7 @@ class RevocationRegistryDefinitionPrivate(bindings.AnoncredsObject):
 
 
 class RevocationStatusList(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_revocation_status_list_from_cbor"
     @classmethod
     def create(
         self,
@@ -577,6 +676,9 @@ class RevocationStatusList(bindings.AnoncredsObject):
         issuer_id: str,
         issuance_by_default: bool = True,
         timestamp: Optional[int] = None,
+        *,
+        progress: Optional[bindings.ProgressCallback] = None,
+        cancellation: Optional[bindings.CancellationToken] = None,
     ) -> "RevocationStatusList":
         if not isinstance(rev_reg_def, bindings.AnoncredsObject):
             rev_reg_def = RevocationRegistryDefinition.load(rev_reg_def)
@@ -590,6 +692,8 @@ class RevocationStatusList(bindings.AnoncredsObject):
                 issuer_id,
                 issuance_by_default,
                 timestamp,
+                progress,
+                cancellation,
             )
         )
 
@@ -603,6 +707,18 @@ class RevocationStatusList(bindings.AnoncredsObject):
             )
         )
 
+    @property
+    def issuer_id(self) -> str:
+        return bindings.object_get_field_str(self.handle, "issuerId")
+
+    @property
+    def rev_reg_def_id(self) -> Optional[str]:
+        return bindings.object_get_field_str(self.handle, "revRegDefId")
+
+    @property
+    def timestamp(self) -> Optional[int]:
+        return self.get_field("timestamp")
+
     def update_timestamp_only(self, timestamp: int):
         self.handle = bindings.update_revocation_status_list_timestamp_only(
             timestamp, self.handle
@@ -635,6 +751,7 @@ class RevocationStatusList(bindings.AnoncredsObject):
 
 
 class RevocationRegistry(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_revocation_registry_from_cbor"
     @classmethod
     def load(cls, value: Union[dict, str, bytes, memoryview]) -> "RevocationRegistry":
         return RevocationRegistry(
@@ -694,6 +811,7 @@ class NonrevokedIntervalOverride:
 
 
 class CredentialRevocationState(bindings.AnoncredsObject):
+    FROM_CBOR = "anoncreds_revocation_state_from_cbor"
     @classmethod
     def create(
         cls,
@@ -703,6 +821,9 @@ class CredentialRevocationState(bindings.AnoncredsObject):
         tails_path: str,
         rev_state: Optional[Union[str, "CredentialRevocationState"]] = None,
         old_rev_status_list: Optional[Union[str, RevocationStatusList]] = None,
+        *,
+        progress: Optional[bindings.ProgressCallback] = None,
+        cancellation: Optional[bindings.CancellationToken] = None,
     ) -> "CredentialRevocationState":
         if not isinstance(rev_reg_def, bindings.AnoncredsObject):
             rev_reg_def = RevocationRegistryDefinition.load(rev_reg_def)
@@ -726,6 +847,8 @@ class CredentialRevocationState(bindings.AnoncredsObject):
                 tails_path,
                 rev_state.handle if rev_state else None,
                 old_rev_status_list.handle if old_rev_status_list else None,
+                progress,
+                cancellation,
             )
         )
 
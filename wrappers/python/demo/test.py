@@ -55,6 +55,7 @@ cred_request, cred_request_metadata = CredentialRequest.create(
 )
 
 issue_cred = Credential.create(
+    schema,
     cred_def_pub,
     cred_def_priv,
     cred_offer,